@@ -1,8 +1,10 @@
 mod consonant;
 mod devanagari;
 mod normalize;
+mod stats;
 mod syllable;
 mod vowel;
+mod wrap;
 
 pub use consonant::{
     Varga, is_panchham, is_voiced, is_voiceless, panchham_of, varga, varga_position,
@@ -11,11 +13,17 @@ pub use consonant::{
 pub use devanagari::{
     CharType, DevanagariChar, classify, is_halanta, is_matra, is_svar, is_vyanjan,
 };
-pub use normalize::normalize;
-pub use syllable::{Akshara, split_aksharas};
+pub use normalize::{
+    StructureError, StructureErrorKind, normalize, normalize_digits, redundant_zero_width_offsets,
+    validate_syllable_structure,
+};
+pub use stats::{CharStats, char_stats};
+pub use syllable::{Akshara, SplitOptions, split_aksharas, split_aksharas_with_options};
 pub use vowel::{
-    SvarType, dirgha_to_hrasva, hrasva_to_dirgha, matra_to_svar, svar_to_matra, svar_type,
+    SvarType, dirgha_to_hrasva, guna_of, hrasva_to_dirgha, matra_to_svar, svar_to_matra, svar_type,
+    vriddhi_of,
 };
+pub use wrap::wrap_text;
 
 /// Error type for akshar operations.
 #[derive(Debug, thiserror::Error)]
@@ -1,13 +1,185 @@
 use unicode_normalization::UnicodeNormalization;
 
+use crate::devanagari::{CharType, classify};
+
 /// Normalize Devanagari text to a canonical form (NFC).
 ///
 /// - Applies Unicode NFC normalization
 /// - Standardizes visually identical sequences
+/// - Drops ZWJ/ZWNJ that do not affect conjunct formation
+///   (see [`redundant_zero_width_offsets`])
 ///
 /// Invariant: `normalize(normalize(s)) == normalize(s)` (idempotent)
 pub fn normalize(text: &str) -> String {
-    text.nfc().collect()
+    let nfc: String = text.nfc().collect();
+    let redundant = redundant_zero_width_offsets(&nfc);
+    if redundant.is_empty() {
+        return nfc;
+    }
+    nfc.char_indices()
+        .filter(|(offset, _)| !redundant.contains(offset))
+        .map(|(_, c)| c)
+        .collect()
+}
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// Byte offsets of ZWJ (U+200D) / ZWNJ (U+200C) that are stray in Devanagari.
+///
+/// In Devanagari these joiners only mean something after a halanta (`क्\u{200D}ष`
+/// half form, `क्\u{200C}ष` visible halanta) or as ZWJ between र and halanta (eyelash
+/// र, `र\u{200D}्य`). Anywhere else next to a Devanagari letter they are scraping
+/// debris that blocks word matching. Joiners outside Devanagari context
+/// (emoji sequences, other scripts) are not reported.
+pub fn redundant_zero_width_offsets(text: &str) -> Vec<usize> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut offsets = Vec::new();
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        if c != ZWJ && c != ZWNJ {
+            continue;
+        }
+        let prev = i.checked_sub(1).map(|j| chars[j].1);
+        let next = chars.get(i + 1).map(|&(_, n)| n);
+        let is_devanagari = |ch: Option<char>| ch.is_some_and(|ch| classify(ch).is_some());
+        if !is_devanagari(prev) && !is_devanagari(next) {
+            continue;
+        }
+        let after_halanta = prev == Some('्');
+        let eyelash_ra = c == ZWJ && prev == Some('र') && next == Some('्');
+        if !after_halanta && !eyelash_ra {
+            offsets.push(offset);
+        }
+    }
+    offsets
+}
+
+/// Kind of malformed syllable structure found by [`validate_syllable_structure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureErrorKind {
+    /// A vowel sign with no consonant to carry it (e.g. text starting with `ि`).
+    OrphanMatra,
+    /// A halanta written after a vowel or vowel sign (e.g. `का्`).
+    HalantaAfterVowel,
+    /// Two vowel signs on one consonant (e.g. `कीु`).
+    DoubleMatra,
+}
+
+/// A malformed sequence, located by the byte offset of the offending mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructureError {
+    pub kind: StructureErrorKind,
+    pub offset: usize,
+}
+
+/// Report combining marks that cannot belong to a well-formed akshara.
+///
+/// Intended for cleaning scraped text, where matras are sometimes typed
+/// before their consonant or stacked twice. Vedic accent marks are not
+/// treated as vowel signs. Well-formed text yields an empty list.
+pub fn validate_syllable_structure(text: &str) -> Vec<StructureError> {
+    let mut errors = Vec::new();
+    let mut prev: Option<CharType> = None;
+
+    for (offset, c) in text.char_indices() {
+        let current = classify(c).map(|dc| dc.char_type);
+        let kind = match current {
+            Some(CharType::Matra) if is_vedic_accent(c) => None,
+            Some(CharType::Matra) => match prev {
+                Some(CharType::Matra) => Some(StructureErrorKind::DoubleMatra),
+                Some(CharType::Vyanjan | CharType::Nukta) => None,
+                _ => Some(StructureErrorKind::OrphanMatra),
+            },
+            Some(CharType::Halanta) => match prev {
+                Some(CharType::Svar | CharType::Matra) => {
+                    Some(StructureErrorKind::HalantaAfterVowel)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            errors.push(StructureError { kind, offset });
+        }
+        // Accents ride on the syllable without changing what precedes them.
+        if !is_vedic_accent(c) {
+            prev = current;
+        }
+    }
+
+    errors
+}
+
+fn is_vedic_accent(c: char) -> bool {
+    matches!(c, '\u{0951}'..='\u{0954}')
+}
+
+/// Convert between ASCII and Devanagari digits (`2024` ↔ `२०२४`).
+///
+/// With `to_devanagari`, only digit runs in Devanagari context are
+/// converted: the whitespace-delimited token must contain no Latin letters,
+/// and it or a neighbouring token must contain Devanagari. So `सन् 2024`
+/// becomes `सन् २०२४` while URLs, version strings and all-English sentences
+/// keep their digits. Without it, every Devanagari digit becomes ASCII.
+pub fn normalize_digits(text: &str, to_devanagari: bool) -> String {
+    if !to_devanagari {
+        return text
+            .chars()
+            .map(|c| match c {
+                '०'..='९' => char::from_digit(c as u32 - '०' as u32, 10).unwrap_or(c),
+                _ => c,
+            })
+            .collect();
+    }
+
+    let tokens = whitespace_tokens(text);
+    let has_devanagari = |tok: &str| tok.chars().any(|c| classify(c).is_some());
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (i, &(start, end)) in tokens.iter().enumerate() {
+        let tok = &text[start..end];
+        if !tok.bytes().any(|b| b.is_ascii_digit()) || tok.bytes().any(|b| b.is_ascii_alphabetic())
+        {
+            continue;
+        }
+        let near_devanagari = has_devanagari(tok)
+            || i.checked_sub(1)
+                .is_some_and(|j| has_devanagari(&text[tokens[j].0..tokens[j].1]))
+            || tokens
+                .get(i + 1)
+                .is_some_and(|&(s, e)| has_devanagari(&text[s..e]));
+        if !near_devanagari {
+            continue;
+        }
+        out.push_str(&text[copied..start]);
+        out.extend(tok.chars().map(|c| match c.to_digit(10) {
+            Some(d) if c.is_ascii_digit() => char::from_u32('०' as u32 + d).unwrap_or(c),
+            _ => c,
+        }));
+        copied = end;
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Byte ranges of whitespace-separated tokens.
+fn whitespace_tokens(text: &str) -> Vec<(usize, usize)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                tokens.push((s, i));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, text.len()));
+    }
+    tokens
 }
 
 #[cfg(test)]
@@ -37,6 +209,114 @@ mod tests {
     fn test_ascii_passthrough() {
         assert_eq!(normalize("hello"), "hello");
     }
+
+    #[test]
+    fn test_digits_to_devanagari_in_context() {
+        assert_eq!(normalize_digits("सन् 2024", true), "सन् २०२४");
+        assert_eq!(normalize_digits("2024मा", true), "२०२४मा");
+        assert_eq!(normalize_digits("मूल्य रु. 1,500", true), "मूल्य रु. १,५००");
+    }
+
+    #[test]
+    fn test_digits_left_alone_outside_devanagari() {
+        for text in ["https://example.com/2024 हेर्नुहोस्", "version 2.0", "in 2024"]
+        {
+            assert_eq!(normalize_digits(text, true), text);
+        }
+    }
+
+    #[test]
+    fn test_digits_to_ascii() {
+        assert_eq!(normalize_digits("सन् २०२४", false), "सन् 2024");
+        assert_eq!(normalize_digits("abc", false), "abc");
+    }
+
+    #[test]
+    fn test_strips_stray_zero_width() {
+        // ZWNJ between consonant and halanta, ZWJ inside a word.
+        assert_eq!(normalize("क\u{200C}्षेत्र"), "क्षेत्र");
+        assert_eq!(normalize("नेपा\u{200D}ल"), "नेपाल");
+    }
+
+    #[test]
+    fn test_keeps_meaningful_zero_width() {
+        for text in [
+            "क्\u{200D}ष",   // half form
+            "क्\u{200C}ष",   // visible halanta
+            "र\u{200D}्य",   // eyelash र
+            "👩\u{200D}💻", // emoji sequence
+        ] {
+            assert_eq!(normalize(text), text);
+            assert!(redundant_zero_width_offsets(text).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_structure_well_formed() {
+        for text in [
+            "नमस्ते",
+            "काठमाडौं",
+            "विज्ञान",
+            "ज़िन्दगी",
+            "संसद्",
+            "मी॑ळे",
+            "abc १२३।",
+        ] {
+            assert!(
+                validate_syllable_structure(text).is_empty(),
+                "unexpected errors for {text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_structure_orphan_matra() {
+        let errors = validate_syllable_structure("िक");
+        assert_eq!(
+            errors,
+            vec![StructureError {
+                kind: StructureErrorKind::OrphanMatra,
+                offset: 0
+            }]
+        );
+
+        let text = "अा घर ेक";
+        let errors = validate_syllable_structure(text);
+        let offsets: Vec<usize> = errors.iter().map(|e| e.offset).collect();
+        assert!(
+            errors
+                .iter()
+                .all(|e| e.kind == StructureErrorKind::OrphanMatra)
+        );
+        assert_eq!(offsets, vec!["अ".len(), text.find('े').unwrap()]);
+    }
+
+    #[test]
+    fn test_structure_halanta_after_vowel() {
+        let errors = validate_syllable_structure("का्");
+        assert_eq!(
+            errors,
+            vec![StructureError {
+                kind: StructureErrorKind::HalantaAfterVowel,
+                offset: "का".len()
+            }]
+        );
+
+        let errors = validate_syllable_structure("इ्");
+        assert_eq!(errors[0].kind, StructureErrorKind::HalantaAfterVowel);
+    }
+
+    #[test]
+    fn test_structure_double_matra() {
+        let errors = validate_syllable_structure("कीु");
+        assert_eq!(
+            errors,
+            vec![StructureError {
+                kind: StructureErrorKind::DoubleMatra,
+                offset: "की".len()
+            }]
+        );
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,57 @@
+use crate::devanagari::{CharType, classify};
+use crate::syllable::split_aksharas;
+
+/// Character counts for a piece of Devanagari text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CharStats {
+    /// स्वर (independent vowels).
+    pub svar: usize,
+    /// व्यञ्जन (consonants).
+    pub vyanjan: usize,
+    /// मात्रा (vowel signs).
+    pub matra: usize,
+    /// हलन्त (virama).
+    pub halanta: usize,
+    /// Devanagari digits (० … ९).
+    pub numerals: usize,
+    /// Aksharas containing a vowel or consonant, as split by [`split_aksharas`].
+    pub aksharas: usize,
+}
+
+/// Count vowels, consonants, vowel signs, halantas, numerals and aksharas.
+///
+/// Characters outside the Devanagari block are ignored. Useful for
+/// readability metrics and length limits (SMS, captions).
+///
+/// # Examples
+///
+/// ```
+/// use varnavinyas_akshar::char_stats;
+///
+/// let stats = char_stats("नमस्ते");
+/// assert_eq!(stats.vyanjan, 4);
+/// assert_eq!(stats.aksharas, 3);
+/// ```
+pub fn char_stats(text: &str) -> CharStats {
+    let mut stats = CharStats::default();
+    for c in text.chars() {
+        match classify(c).map(|dc| dc.char_type) {
+            Some(CharType::Svar) => stats.svar += 1,
+            Some(CharType::Vyanjan) => stats.vyanjan += 1,
+            Some(CharType::Matra) => stats.matra += 1,
+            Some(CharType::Halanta) => stats.halanta += 1,
+            Some(CharType::Numeral) => stats.numerals += 1,
+            _ => {}
+        }
+    }
+    stats.aksharas = split_aksharas(text)
+        .iter()
+        .filter(|a| {
+            a.text.chars().any(|c| {
+                classify(c)
+                    .is_some_and(|dc| matches!(dc.char_type, CharType::Svar | CharType::Vyanjan))
+            })
+        })
+        .count();
+    stats
+}
@@ -11,6 +11,93 @@ pub struct Akshara {
     pub end: usize,
 }
 
+impl Akshara {
+    /// The consonant cluster before the vowel: `क्ष` in `क्षे`, empty for
+    /// an independent vowel.
+    ///
+    /// A dead consonant with no vowel (`क्`) is all onset.
+    pub fn onset(&self) -> &str {
+        &self.text[..self.boundaries().0]
+    }
+
+    /// The vowel: an independent vowel (`अ`) or a vowel sign (`े`), with any
+    /// nukta or accent on it. Empty for the inherent अ (`क`).
+    ///
+    /// Non-Devanagari units are all nucleus.
+    pub fn nucleus(&self) -> &str {
+        let (onset_end, nucleus_end) = self.boundaries();
+        &self.text[onset_end..nucleus_end]
+    }
+
+    /// Whatever follows the vowel: a coda consonant with halanta (`स्` in
+    /// `मस्`) and anusvara, chandrabindu or visarga.
+    pub fn coda(&self) -> &str {
+        &self.text[self.boundaries().1..]
+    }
+
+    /// Byte offsets, within `text`, of the end of the onset and the nucleus.
+    fn boundaries(&self) -> (usize, usize) {
+        let chars: Vec<(usize, char)> = self.text.char_indices().collect();
+        let offset = |idx: usize| chars.get(idx).map_or(self.text.len(), |&(b, _)| b);
+        if chars.is_empty() || devanagari::classify(chars[0].1).is_none() {
+            return (0, self.text.len());
+        }
+
+        let mut i = 0;
+        if is_vyanjan_at(&chars, 0) {
+            i = 1;
+            loop {
+                if i < chars.len() && is_char_type(&chars, i, CharType::Nukta) {
+                    i += 1;
+                }
+                if i < chars.len() && is_char_type(&chars, i, CharType::Halanta) {
+                    i += 1;
+                    // Halanta inside the cluster (प्र); otherwise the
+                    // consonant is dead (क्) and the halanta ends the onset.
+                    if i < chars.len() && is_vyanjan_at(&chars, i) {
+                        i += 1;
+                        continue;
+                    }
+                }
+                break;
+            }
+        }
+        let onset_end = i;
+
+        if i < chars.len()
+            && matches!(
+                char_type_at(&chars, i),
+                Some(CharType::Svar | CharType::Matra)
+            )
+        {
+            i += 1;
+            while i < chars.len()
+                && (is_char_type(&chars, i, CharType::Nukta) || is_vedic_accent_at(&chars, i))
+            {
+                i += 1;
+            }
+        }
+        (offset(onset_end), offset(i))
+    }
+}
+
+/// Options for [`split_aksharas_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SplitOptions {
+    /// Keep क्ष, ज्ञ, त्र and श्र whole as the onset of the next akshara,
+    /// as taught in primers (क्षत्रिय → क्ष | त्रि | य rather than
+    /// क्षत् | रि | य).
+    pub atomic_conjuncts: bool,
+}
+
+/// Conjuncts treated as single letters under [`SplitOptions::atomic_conjuncts`].
+const ATOMIC_CONJUNCTS: &[[char; 3]] = &[
+    ['क', '्', 'ष'],
+    ['ज', '्', 'ञ'],
+    ['त', '्', 'र'],
+    ['श', '्', 'र'],
+];
+
 /// Split text into akshara (syllable) units.
 ///
 /// An akshara is the minimal pronounceable unit:
@@ -19,6 +106,8 @@ pub struct Akshara {
 /// - Anusvara/chandrabindu attach to the preceding akshara
 /// - Coda consonants (C+halanta before a consonant with its own vowel)
 ///   attach to the preceding akshara
+/// - `ॐ` is always an akshara of its own
+/// - Vedic accent marks (U+0951–U+0954) stay on their syllable nucleus
 ///
 /// Non-Devanagari characters form their own akshara units.
 ///
@@ -32,6 +121,22 @@ pub struct Akshara {
 /// assert_eq!(texts, vec!["न", "मस्", "ते"]);
 /// ```
 pub fn split_aksharas(text: &str) -> Vec<Akshara> {
+    split_aksharas_with_options(text, &SplitOptions::default())
+}
+
+/// [`split_aksharas`] with configurable handling of common conjuncts.
+///
+/// # Examples
+///
+/// ```
+/// use varnavinyas_akshar::{SplitOptions, split_aksharas_with_options};
+///
+/// let options = SplitOptions { atomic_conjuncts: true };
+/// let result = split_aksharas_with_options("पत्र", &options);
+/// let texts: Vec<&str> = result.iter().map(|a| a.text.as_str()).collect();
+/// assert_eq!(texts, vec!["प", "त्र"]);
+/// ```
+pub fn split_aksharas_with_options(text: &str, options: &SplitOptions) -> Vec<Akshara> {
     let mut aksharas = Vec::new();
     let chars: Vec<(usize, char)> = text.char_indices().collect();
 
@@ -71,11 +176,17 @@ pub fn split_aksharas(text: &str) -> Vec<Akshara> {
                         i += 1;
                     }
 
+                    // Vedic accents mark the nucleus just completed (क॒, मी॑)
+                    i = skip_vedic_accents(&chars, i);
+
                     // Step 4: Coda check — absorb C+halanta when the following
                     // consonant has its own vowel (not part of a longer chain).
                     // E.g., in "नमस्ते": after 'म' (inherent vowel), 'स्' is coda
                     // because 'त' has matra 'े'.
                     while i < len && is_vyanjan_at(&chars, i) {
+                        if options.atomic_conjuncts && is_atomic_conjunct_at(&chars, i) {
+                            break;
+                        }
                         if i + 1 < len && is_char_type(&chars, i + 1, CharType::Halanta) {
                             if i + 2 < len && is_vyanjan_at(&chars, i + 2) {
                                 // Check: does C₂ continue a chain (another halanta)?
@@ -99,6 +210,7 @@ pub fn split_aksharas(text: &str) -> Vec<Akshara> {
                     }
 
                     // Step 5: Consume trailing anusvara/chandrabindu/visarga
+                    // (and any accent written after them)
                     while i < len {
                         match char_type_at(&chars, i) {
                             Some(
@@ -106,6 +218,7 @@ pub fn split_aksharas(text: &str) -> Vec<Akshara> {
                             ) => {
                                 i += 1;
                             }
+                            _ if is_vedic_accent_at(&chars, i) => i += 1,
                             _ => break,
                         }
                     }
@@ -118,10 +231,22 @@ pub fn split_aksharas(text: &str) -> Vec<Akshara> {
                     });
                 }
 
+                CharType::Svar if c == OM => {
+                    // ॐ is a complete syllable: only accents may ride on it.
+                    i = skip_vedic_accents(&chars, i + 1);
+
+                    let end_byte = if i < len { chars[i].0 } else { text.len() };
+                    aksharas.push(Akshara {
+                        text: text[start_byte..end_byte].to_string(),
+                        start: start_byte,
+                        end: end_byte,
+                    });
+                }
+
                 CharType::Svar => {
                     i += 1;
 
-                    // Consume trailing anusvara/chandrabindu/visarga
+                    // Consume trailing anusvara/chandrabindu/visarga and accents
                     while i < len {
                         match char_type_at(&chars, i) {
                             Some(
@@ -129,6 +254,7 @@ pub fn split_aksharas(text: &str) -> Vec<Akshara> {
                             ) => {
                                 i += 1;
                             }
+                            _ if is_vedic_accent_at(&chars, i) => i += 1,
                             _ => break,
                         }
                     }
@@ -142,8 +268,8 @@ pub fn split_aksharas(text: &str) -> Vec<Akshara> {
                 }
 
                 CharType::Shirbindu | CharType::Chandrabindu | CharType::Visarga => {
-                    // Attach to preceding akshara if possible
-                    if let Some(last) = aksharas.last_mut() {
+                    // Attach to preceding akshara if possible (never to ॐ)
+                    if let Some(last) = aksharas.last_mut().filter(|a| !a.text.starts_with(OM)) {
                         i += 1;
                         let end_byte = if i < len { chars[i].0 } else { text.len() };
                         last.text = text[last.start..end_byte].to_string();
@@ -185,6 +311,21 @@ pub fn split_aksharas(text: &str) -> Vec<Akshara> {
     aksharas
 }
 
+/// ॐ (U+0950), classified as a svar but never extended by following signs.
+const OM: char = '\u{0950}';
+
+/// Udatta, anudatta and the grave/acute accents (U+0951–U+0954).
+fn is_vedic_accent_at(chars: &[(usize, char)], idx: usize) -> bool {
+    matches!(chars[idx].1, '\u{0951}'..='\u{0954}')
+}
+
+fn skip_vedic_accents(chars: &[(usize, char)], mut idx: usize) -> usize {
+    while idx < chars.len() && is_vedic_accent_at(chars, idx) {
+        idx += 1;
+    }
+    idx
+}
+
 fn char_type_at(chars: &[(usize, char)], idx: usize) -> Option<CharType> {
     devanagari::classify(chars[idx].1).map(|dc| dc.char_type)
 }
@@ -197,6 +338,16 @@ fn is_vyanjan_at(chars: &[(usize, char)], idx: usize) -> bool {
     is_char_type(chars, idx, CharType::Vyanjan)
 }
 
+fn is_atomic_conjunct_at(chars: &[(usize, char)], idx: usize) -> bool {
+    ATOMIC_CONJUNCTS.iter().any(|conjunct| {
+        chars.len() >= idx + 3
+            && conjunct
+                .iter()
+                .zip(&chars[idx..idx + 3])
+                .all(|(&c, &(_, t))| c == t)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +377,64 @@ mod tests {
         assert_eq!(texts(&result), vec!["प्र", "शा", "स", "न"]);
     }
 
+    #[test]
+    fn test_onset_nucleus_coda() {
+        let parts = |a: &Akshara| {
+            (
+                a.onset().to_string(),
+                a.nucleus().to_string(),
+                a.coda().to_string(),
+            )
+        };
+        let part = |s: &str| {
+            let result = split_aksharas(s);
+            assert_eq!(result.len(), 1, "{s} should be one akshara");
+            parts(&result[0])
+        };
+
+        assert_eq!(part("क्षे"), ("क्ष".into(), "े".into(), String::new()));
+        assert_eq!(part("क"), ("क".into(), String::new(), String::new()));
+        assert_eq!(part("अं"), (String::new(), "अ".into(), "ं".into()));
+        assert_eq!(part("क्"), ("क्".into(), String::new(), String::new()));
+
+        // न | मस् | ते: the स् coda follows the inherent vowel.
+        let namaste = split_aksharas("नमस्ते");
+        assert_eq!(parts(&namaste[1]), ("म".into(), String::new(), "स्".into()));
+        // डौं: vowel sign then anusvara.
+        let kathmandu = split_aksharas("काठमाडौं");
+        assert_eq!(parts(&kathmandu[3]), ("ड".into(), "ौ".into(), "ं".into()));
+
+        for a in split_aksharas("प्रशासन विद्वान् क्षत्रिय")
+        {
+            assert_eq!(format!("{}{}{}", a.onset(), a.nucleus(), a.coda()), a.text);
+        }
+    }
+
+    #[test]
+    fn test_atomic_conjuncts_option() {
+        let atomic = SplitOptions {
+            atomic_conjuncts: true,
+        };
+        assert_eq!(texts(&split_aksharas("क्षत्रिय")), vec!["क्षत्", "रि", "य"]);
+        assert_eq!(
+            texts(&split_aksharas_with_options("क्षत्रिय", &atomic)),
+            vec!["क्ष", "त्रि", "य"]
+        );
+        assert_eq!(
+            texts(&split_aksharas_with_options("विज्ञान", &atomic)),
+            vec!["वि", "ज्ञा", "न"]
+        );
+        assert_eq!(
+            texts(&split_aksharas_with_options("पक्ष", &atomic)),
+            vec!["प", "क्ष"]
+        );
+        // Other conjuncts still split at the coda.
+        assert_eq!(
+            texts(&split_aksharas_with_options("नमस्ते", &atomic)),
+            vec!["न", "मस्", "ते"]
+        );
+    }
+
     #[test]
     fn test_standalone_vowel() {
         let result = split_aksharas("अ");
@@ -287,6 +496,28 @@ mod tests {
         assert_eq!(texts(&result), vec!["म", "ह", "त्त्व"]);
     }
 
+    #[test]
+    fn test_om_is_its_own_akshara() {
+        let result = split_aksharas("ॐनमः");
+        assert_eq!(texts(&result), vec!["ॐ", "न", "मः"]);
+
+        let result = split_aksharas("ॐं");
+        assert_eq!(texts(&result), vec!["ॐ", "ं"]);
+    }
+
+    #[test]
+    fn test_vedic_accents_stay_on_nucleus() {
+        // udatta (॑) after a matra, anudatta (॒) on an inherent vowel and a svar
+        let result = split_aksharas("मी॑ळे");
+        assert_eq!(texts(&result), vec!["मी॑", "ळे"]);
+
+        let result = split_aksharas("अ॒ग्निक॒");
+        assert_eq!(texts(&result), vec!["अ॒", "ग्नि", "क॒"]);
+
+        let result = split_aksharas("ॐ॑");
+        assert_eq!(texts(&result), vec!["ॐ॑"]);
+    }
+
     #[test]
     fn test_coda_vigyan() {
         // विज्ञान — ज् is coda of वि, ञ starts new akshara
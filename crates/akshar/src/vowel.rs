@@ -1,28 +1,72 @@
-/// Vowel length classification.
+/// Vowel grade classification.
+///
+/// Guna and vriddhi vowels are long too; use [`SvarType::is_long`] when only
+/// the length matters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SvarType {
-    /// ह्रस्व (short): अ इ उ ऋ
+    /// ह्रस्व (short): अ इ उ ऋ ऌ
     Hrasva,
-    /// दीर्घ (long): आ ई ऊ ए ऐ ओ औ
+    /// दीर्घ (long simple vowel): आ ई ऊ ॠ ॡ
     Dirgha,
+    /// गुण: ए ओ
+    Guna,
+    /// वृद्धि: ऐ औ
+    Vriddhi,
 }
 
-/// Determine the vowel length of a svar or matra.
+impl SvarType {
+    /// Whether the vowel is long (दीर्घ, गुण or वृद्धि).
+    pub fn is_long(self) -> bool {
+        !matches!(self, SvarType::Hrasva)
+    }
+}
+
+/// Determine the vowel grade of a svar or matra.
 /// Returns `None` for non-vowel characters.
 pub fn svar_type(c: char) -> Option<SvarType> {
     match c {
-        // Hrasva svars
-        'अ' | 'इ' | 'उ' | 'ऋ' | 'ऌ' => Some(SvarType::Hrasva),
-        // Dirgha svars
-        'आ' | 'ई' | 'ऊ' | 'ॠ' | 'ॡ' | 'ए' | 'ऐ' | 'ओ' | 'औ' => {
-            Some(SvarType::Dirgha)
+        // Hrasva svars and matras
+        'अ' | 'इ' | 'उ' | 'ऋ' | 'ऌ' | 'ि' | 'ु' | 'ृ' | 'ॢ' => {
+            Some(SvarType::Hrasva)
         }
-        // Hrasva matras
-        'ि' | 'ु' | 'ृ' | 'ॢ' => Some(SvarType::Hrasva),
-        // Dirgha matras (ा maps to आ which is dirgha)
-        'ा' | 'ी' | 'ू' | 'ॄ' | 'ॣ' | 'े' | 'ै' | 'ो' | 'ौ' => {
+        // Dirgha svars and matras (ा maps to आ which is dirgha)
+        'आ' | 'ई' | 'ऊ' | 'ॠ' | 'ॡ' | 'ा' | 'ी' | 'ू' | 'ॄ' | 'ॣ' => {
             Some(SvarType::Dirgha)
         }
+        // Guna diphthongs
+        'ए' | 'ओ' | 'े' | 'ो' => Some(SvarType::Guna),
+        // Vriddhi diphthongs
+        'ऐ' | 'औ' | 'ै' | 'ौ' => Some(SvarType::Vriddhi),
+        _ => None,
+    }
+}
+
+/// गुण grade of an independent vowel: इ/ई→ए, उ/ऊ→ओ, ऋ/ॠ→अर्, ऌ→अल्.
+///
+/// This is the vowel that replaces the second member in अ/आ + vowel गुण
+/// सन्धि (महा + ईश → महेश). Returns `None` for vowels without a distinct
+/// guna grade (अ, आ, and the diphthongs) and for matras.
+pub fn guna_of(c: char) -> Option<&'static str> {
+    match c {
+        'इ' | 'ई' => Some("ए"),
+        'उ' | 'ऊ' => Some("ओ"),
+        'ऋ' | 'ॠ' => Some("अर्"),
+        'ऌ' | 'ॡ' => Some("अल्"),
+        _ => None,
+    }
+}
+
+/// वृद्धि grade of an independent vowel: अ/आ→आ, इ/ई/ए/ऐ→ऐ, उ/ऊ/ओ/औ→औ,
+/// ऋ/ॠ→आर्.
+///
+/// अ/आ + ए/ऐ → ऐ and अ/आ + ओ/औ → औ are वृद्धि सन्धि. Returns `None` for
+/// matras and non-vowels.
+pub fn vriddhi_of(c: char) -> Option<&'static str> {
+    match c {
+        'अ' | 'आ' => Some("आ"),
+        'इ' | 'ई' | 'ए' | 'ऐ' => Some("ऐ"),
+        'उ' | 'ऊ' | 'ओ' | 'औ' => Some("औ"),
+        'ऋ' | 'ॠ' => Some("आर्"),
         _ => None,
     }
 }
@@ -121,10 +165,41 @@ mod tests {
         assert_eq!(svar_type('आ'), Some(SvarType::Dirgha));
         assert_eq!(svar_type('ई'), Some(SvarType::Dirgha));
         assert_eq!(svar_type('ऊ'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('ए'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('ऐ'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('ओ'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('औ'), Some(SvarType::Dirgha));
+    }
+
+    #[test]
+    fn test_svar_type_guna_vriddhi() {
+        assert_eq!(svar_type('ए'), Some(SvarType::Guna));
+        assert_eq!(svar_type('ओ'), Some(SvarType::Guna));
+        assert_eq!(svar_type('ऐ'), Some(SvarType::Vriddhi));
+        assert_eq!(svar_type('औ'), Some(SvarType::Vriddhi));
+        for c in ['आ', 'ई', 'ए', 'ऐ', 'ो', 'ौ'] {
+            assert!(svar_type(c).unwrap().is_long(), "{c}");
+        }
+        assert!(!SvarType::Hrasva.is_long());
+    }
+
+    #[test]
+    fn test_guna_of() {
+        assert_eq!(guna_of('इ'), Some("ए"));
+        assert_eq!(guna_of('ई'), Some("ए"));
+        assert_eq!(guna_of('उ'), Some("ओ"));
+        assert_eq!(guna_of('ऊ'), Some("ओ"));
+        assert_eq!(guna_of('ऋ'), Some("अर्"));
+        assert_eq!(guna_of('अ'), None);
+        assert_eq!(guna_of('ए'), None);
+        assert_eq!(guna_of('ि'), None);
+    }
+
+    #[test]
+    fn test_vriddhi_of() {
+        assert_eq!(vriddhi_of('इ'), Some("ऐ"));
+        assert_eq!(vriddhi_of('ए'), Some("ऐ"));
+        assert_eq!(vriddhi_of('उ'), Some("औ"));
+        assert_eq!(vriddhi_of('ओ'), Some("औ"));
+        assert_eq!(vriddhi_of('अ'), Some("आ"));
+        assert_eq!(vriddhi_of('ऋ'), Some("आर्"));
+        assert_eq!(vriddhi_of('क'), None);
     }
 
     #[test]
@@ -135,10 +210,10 @@ mod tests {
         assert_eq!(svar_type('ा'), Some(SvarType::Dirgha));
         assert_eq!(svar_type('ी'), Some(SvarType::Dirgha));
         assert_eq!(svar_type('ू'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('े'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('ै'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('ो'), Some(SvarType::Dirgha));
-        assert_eq!(svar_type('ौ'), Some(SvarType::Dirgha));
+        assert_eq!(svar_type('े'), Some(SvarType::Guna));
+        assert_eq!(svar_type('ै'), Some(SvarType::Vriddhi));
+        assert_eq!(svar_type('ो'), Some(SvarType::Guna));
+        assert_eq!(svar_type('ौ'), Some(SvarType::Vriddhi));
     }
 
     #[test]
@@ -184,6 +259,54 @@ mod tests {
         }
     }
 
+    /// Every hrasva/dirgha pair, independent svar and matra alike.
+    const HRASVA_DIRGHA_PAIRS: [(char, char); 8] = [
+        ('इ', 'ई'),
+        ('उ', 'ऊ'),
+        ('ऋ', 'ॠ'),
+        ('ऌ', 'ॡ'),
+        ('ि', 'ी'),
+        ('ु', 'ू'),
+        ('ृ', 'ॄ'),
+        ('ॢ', 'ॣ'),
+    ];
+
+    #[test]
+    fn test_hrasva_dirgha_pairs_exhaustive() {
+        for (hrasva, dirgha) in HRASVA_DIRGHA_PAIRS {
+            assert_eq!(hrasva_to_dirgha(hrasva), Some(dirgha), "{hrasva}");
+            assert_eq!(dirgha_to_hrasva(dirgha), Some(hrasva), "{dirgha}");
+            assert_eq!(svar_type(hrasva), Some(SvarType::Hrasva), "{hrasva}");
+            assert_eq!(svar_type(dirgha), Some(SvarType::Dirgha), "{dirgha}");
+            // A pair is either two svars or two matras, never mixed.
+            assert_eq!(
+                matra_to_svar(hrasva).is_some(),
+                matra_to_svar(dirgha).is_some()
+            );
+        }
+
+        // Each matra pair mirrors its svar pair.
+        for (hrasva, dirgha) in HRASVA_DIRGHA_PAIRS {
+            if let (Some(h), Some(d)) = (matra_to_svar(hrasva), matra_to_svar(dirgha)) {
+                assert_eq!(hrasva_to_dirgha(h), Some(d));
+            }
+        }
+
+        // Everything else in the block is not applicable.
+        for c in '\u{0900}'..='\u{097F}' {
+            if HRASVA_DIRGHA_PAIRS.iter().any(|&(h, _)| h == c) {
+                continue;
+            }
+            assert_eq!(hrasva_to_dirgha(c), None, "U+{:04X}", c as u32);
+        }
+        for c in '\u{0900}'..='\u{097F}' {
+            if HRASVA_DIRGHA_PAIRS.iter().any(|&(_, d)| d == c) {
+                continue;
+            }
+            assert_eq!(dirgha_to_hrasva(c), None, "U+{:04X}", c as u32);
+        }
+    }
+
     #[test]
     fn test_svar_to_matra() {
         assert_eq!(svar_to_matra('अ'), None); // inherent vowel
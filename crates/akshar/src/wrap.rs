@@ -0,0 +1,107 @@
+use crate::syllable::split_aksharas;
+
+/// Wrap text into lines of at most `width` aksharas.
+///
+/// Lines break at whitespace; runs of whitespace (including newlines)
+/// collapse to a single space, and a space counts as one akshara. A word
+/// longer than `width` is broken between aksharas, but never after a
+/// halanta, so a conjunct split across aksharas (मस्|ते) stays together.
+/// A unit that cannot be broken further gets a line of its own even if it
+/// exceeds `width`. A `width` of 0 is treated as 1.
+///
+/// # Examples
+///
+/// ```
+/// use varnavinyas_akshar::wrap_text;
+///
+/// // ने|पा|ल + space + राम्|रो = 6 aksharas
+/// assert_eq!(wrap_text("नेपाल राम्रो देश हो", 6), vec!["नेपाल राम्रो", "देश हो"]);
+/// ```
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_len = 0;
+
+    for word in text.split_whitespace() {
+        let units = unbreakable_units(word);
+        let word_len: usize = units.iter().map(|&(_, n)| n).sum();
+
+        if !line.is_empty() && line_len + 1 + word_len <= width {
+            line.push(' ');
+            line.push_str(word);
+            line_len += 1 + word_len;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            line_len = 0;
+        }
+        if word_len <= width {
+            line.push_str(word);
+            line_len = word_len;
+            continue;
+        }
+
+        // Over-width word: fill lines unit by unit.
+        for (unit, n) in units {
+            if !line.is_empty() && line_len + n > width {
+                lines.push(std::mem::take(&mut line));
+                line_len = 0;
+            }
+            line.push_str(unit);
+            line_len += n;
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Split a word into runs of aksharas that may not be separated, with the
+/// number of aksharas in each run.
+fn unbreakable_units(word: &str) -> Vec<(&str, usize)> {
+    let mut units: Vec<(&str, usize)> = Vec::new();
+    let mut start = 0;
+    let mut count = 0;
+    for akshara in split_aksharas(word) {
+        count += 1;
+        if !akshara.text.ends_with(['्', '\u{200C}', '\u{200D}']) {
+            units.push((&word[start..akshara.end], count));
+            start = akshara.end;
+            count = 0;
+        }
+    }
+    if start < word.len() {
+        units.push((&word[start..], count));
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_only_at_spaces_when_words_fit() {
+        let lines = wrap_text("म आज विद्यालय जान्छु", 5);
+        assert_eq!(lines, vec!["म आज", "विद्यालय", "जान्छु"]);
+    }
+
+    #[test]
+    fn long_word_is_not_broken_mid_conjunct() {
+        // न | मस् | ते — the break after मस् would split स्त.
+        assert_eq!(wrap_text("नमस्ते", 2), vec!["न", "मस्ते"]);
+        // प्र is a single akshara and is never split.
+        assert_eq!(wrap_text("प्रशासन", 1), vec!["प्र", "शा", "स", "न"]);
+    }
+
+    #[test]
+    fn collapses_whitespace_and_handles_empty_input() {
+        assert_eq!(wrap_text("घर  \n तिर", 10), vec!["घर तिर"]);
+        assert!(wrap_text("   ", 4).is_empty());
+        assert_eq!(wrap_text("क ख", 0), vec!["क", "ख"]);
+    }
+}
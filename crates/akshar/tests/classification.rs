@@ -190,6 +190,29 @@ fn a9_non_devanagari_none() {
 // lists only unicode-segmentation, unicode-normalization, thiserror, and
 // rustc-hash. This is verified by `cargo deny check`.
 
+// =============================================================================
+// Character statistics
+// =============================================================================
+
+#[test]
+fn char_stats_counts_known_word() {
+    // विद्यालय has 4 consonants, 2 matras, 1 halanta and 4 aksharas; इ adds a
+    // vowel akshara and २० two numerals.
+    let stats = char_stats("विद्यालय इ २०");
+    assert_eq!(
+        stats,
+        CharStats {
+            svar: 1,
+            vyanjan: 5,
+            matra: 2,
+            halanta: 1,
+            numerals: 2,
+            aksharas: 5,
+        }
+    );
+    assert_eq!(char_stats("hello"), CharStats::default());
+}
+
 // =============================================================================
 // Edge cases
 // =============================================================================
@@ -221,7 +244,7 @@ fn edge_svar_type_coverage() {
     // Verify svar_type returns correct classification for all known vowels
     assert_eq!(svar_type('अ'), Some(SvarType::Hrasva));
     assert_eq!(svar_type('आ'), Some(SvarType::Dirgha));
-    assert_eq!(svar_type('ए'), Some(SvarType::Dirgha));
-    assert_eq!(svar_type('ौ'), Some(SvarType::Dirgha));
+    assert_eq!(svar_type('ए'), Some(SvarType::Guna));
+    assert_eq!(svar_type('ौ'), Some(SvarType::Vriddhi));
     assert_eq!(svar_type('क'), None);
 }
@@ -44,8 +44,24 @@ char *varnavinyas_check_text_with_options(const char *text,
                                           int punctuation_mode,
                                           bool include_noop_heuristics);
 
+/**
+ * Same as `varnavinyas_check_text_with_options`, plus
+ * `promote_variants_to_errors`, which reports every variant as an error.
+ */
+char *varnavinyas_check_text_with_options2(const char *text,
+                                           bool grammar,
+                                           int punctuation_mode,
+                                           bool include_noop_heuristics,
+                                           bool promote_variants_to_errors);
+
 char *varnavinyas_check_word(const char *word);
 
+char *varnavinyas_analyze(const char *word);
+
+char *varnavinyas_sandhi_apply(const char *first, const char *second);
+
+char *varnavinyas_sandhi_split(const char *word);
+
 char *varnavinyas_transliterate(const char *input, int from, int to);
 
 Origin varnavinyas_classify(const char *word);
@@ -18,10 +18,13 @@ pub enum Origin {
 /// Pass these as `c_int` values; the function validates the discriminant.
 pub const SCHEME_DEVANAGARI: c_int = 0;
 pub const SCHEME_IAST: c_int = 1;
+pub const SCHEME_NEPALI_PHONETIC: c_int = 2;
+pub const SCHEME_ITRANS: c_int = 3;
 
 /// Punctuation mode constants for use with `varnavinyas_check_text_with_options`.
 pub const PUNCTUATION_STRICT: c_int = 0;
 pub const PUNCTUATION_NORMALIZED_EDITORIAL: c_int = 1;
+pub const PUNCTUATION_LENIENT: c_int = 2;
 
 #[derive(Serialize)]
 struct CDiagnostic {
@@ -36,6 +39,55 @@ struct CDiagnostic {
     category_code: String,
     kind: String,
     confidence: f32,
+    origin_code: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CWordAnalysis {
+    word: String,
+    origin: String,
+    origin_source: String,
+    origin_confidence: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_language: Option<String>,
+    is_correct: bool,
+    correction: Option<String>,
+    rule_notes: Vec<CRuleNote>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    samasa: Option<CSamasaCandidate>,
+}
+
+#[derive(Serialize)]
+struct CSamasaCandidate {
+    left: String,
+    right: String,
+    samasa_type: String,
+    score: f32,
+    vigraha: String,
+}
+
+#[derive(Serialize)]
+struct CRuleNote {
+    rule: String,
+    rule_code: String,
+    explanation: String,
+}
+
+#[derive(Serialize)]
+struct CSandhiResult {
+    output: String,
+    sandhi_type: String,
+    rule_citation: String,
+}
+
+#[derive(Serialize)]
+struct CSandhiSplit {
+    left: String,
+    right: String,
+    output: String,
+    sandhi_type: String,
+    rule_citation: String,
+    confidence: f32,
 }
 
 /// Helper: convert a C string pointer to a Rust &str.
@@ -61,6 +113,8 @@ fn parse_scheme(value: c_int) -> Option<varnavinyas_lipi::Scheme> {
     match value {
         SCHEME_DEVANAGARI => Some(varnavinyas_lipi::Scheme::Devanagari),
         SCHEME_IAST => Some(varnavinyas_lipi::Scheme::Iast),
+        SCHEME_NEPALI_PHONETIC => Some(varnavinyas_lipi::Scheme::NepaliPhonetic),
+        SCHEME_ITRANS => Some(varnavinyas_lipi::Scheme::Itrans),
         _ => None,
     }
 }
@@ -72,6 +126,7 @@ fn parse_punctuation_mode(value: c_int) -> Option<varnavinyas_parikshak::Punctua
         PUNCTUATION_NORMALIZED_EDITORIAL => {
             Some(varnavinyas_parikshak::PunctuationMode::NormalizedEditorial)
         }
+        PUNCTUATION_LENIENT => Some(varnavinyas_parikshak::PunctuationMode::Lenient),
         _ => None,
     }
 }
@@ -105,6 +160,7 @@ pub unsafe extern "C" fn varnavinyas_check_text(text: *const c_char) -> *mut c_c
             category_code: d.category.as_code().to_string(),
             kind: d.kind.as_code().to_string(),
             confidence: d.confidence,
+            origin_code: d.origin.map(|o| o.transliterated_label().to_string()),
         })
         .collect();
     let json = serde_json::to_string(&c_diags).unwrap_or_else(|_| "[]".to_string());
@@ -113,8 +169,8 @@ pub unsafe extern "C" fn varnavinyas_check_text(text: *const c_char) -> *mut c_c
 
 /// Check text with runtime options.
 ///
-/// `punctuation_mode` must be one of `PUNCTUATION_STRICT` or
-/// `PUNCTUATION_NORMALIZED_EDITORIAL`.
+/// `punctuation_mode` must be one of `PUNCTUATION_STRICT`,
+/// `PUNCTUATION_NORMALIZED_EDITORIAL` or `PUNCTUATION_LENIENT`.
 ///
 /// Returns a JSON array of diagnostics as a C string.
 /// The caller must free the returned pointer with `varnavinyas_free_string`.
@@ -129,6 +185,37 @@ pub unsafe extern "C" fn varnavinyas_check_text_with_options(
     grammar: bool,
     punctuation_mode: c_int,
     include_noop_heuristics: bool,
+) -> *mut c_char {
+    unsafe {
+        varnavinyas_check_text_with_options2(
+            text,
+            grammar,
+            punctuation_mode,
+            include_noop_heuristics,
+            false,
+        )
+    }
+}
+
+/// Check text with runtime options, optionally reporting variants as errors.
+///
+/// Same as `varnavinyas_check_text_with_options`, plus
+/// `promote_variants_to_errors`, which reports every variant as an error.
+///
+/// Returns a JSON array of diagnostics as a C string.
+/// The caller must free the returned pointer with `varnavinyas_free_string`.
+/// Returns NULL on null/invalid UTF-8 text or invalid punctuation mode.
+///
+/// # Safety
+///
+/// `text` must be a valid null-terminated C string or null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn varnavinyas_check_text_with_options2(
+    text: *const c_char,
+    grammar: bool,
+    punctuation_mode: c_int,
+    include_noop_heuristics: bool,
+    promote_variants_to_errors: bool,
 ) -> *mut c_char {
     let Some(text) = (unsafe { cstr_to_str(text) }) else {
         return std::ptr::null_mut();
@@ -142,6 +229,12 @@ pub unsafe extern "C" fn varnavinyas_check_text_with_options(
             grammar,
             punctuation_mode,
             include_noop_heuristics,
+            suggest: false,
+            promote_variants_to_errors,
+            check_compounds: false,
+            error_categories: None,
+            variant_spellings: false,
+            namayogi_joins: false,
         },
     );
     let c_diags: Vec<CDiagnostic> = diags
@@ -158,6 +251,7 @@ pub unsafe extern "C" fn varnavinyas_check_text_with_options(
             category_code: d.category.as_code().to_string(),
             kind: d.kind.as_code().to_string(),
             confidence: d.confidence,
+            origin_code: d.origin.map(|o| o.transliterated_label().to_string()),
         })
         .collect();
     let json = serde_json::to_string(&c_diags).unwrap_or_else(|_| "[]".to_string());
@@ -191,6 +285,7 @@ pub unsafe extern "C" fn varnavinyas_check_word(word: *const c_char) -> *mut c_c
             category_code: d.category.as_code().to_string(),
             kind: d.kind.as_code().to_string(),
             confidence: d.confidence,
+            origin_code: d.origin.map(|o| o.transliterated_label().to_string()),
         })
         .unwrap_or_else(|_| "null".to_string()),
         None => "null".to_string(),
@@ -198,9 +293,119 @@ pub unsafe extern "C" fn varnavinyas_check_word(word: *const c_char) -> *mut c_c
     string_to_c(json)
 }
 
+/// Analyze a word: origin, correction and the rules that apply to it.
+///
+/// Returns a JSON object as a C string with `word`, `origin`, `origin_source`,
+/// `origin_confidence`, `source_language` (omitted when unknown), `is_correct`,
+/// `correction`, `rule_notes` and `samasa` (the top compound reading as
+/// `{ left, right, samasa_type, score, vigraha }`, omitted when none).
+/// The caller must free the returned pointer with `varnavinyas_free_string`.
+/// Returns NULL on null input or invalid UTF-8.
+///
+/// # Safety
+///
+/// `word` must be a valid null-terminated C string or null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn varnavinyas_analyze(word: *const c_char) -> *mut c_char {
+    let Some(word) = (unsafe { cstr_to_str(word) }) else {
+        return std::ptr::null_mut();
+    };
+    let analysis = varnavinyas_prakriya::analyze(word);
+    let c_analysis = CWordAnalysis {
+        word: analysis.word,
+        origin: origin_to_string(analysis.origin).to_string(),
+        origin_source: origin_source_to_string(analysis.origin_source).to_string(),
+        origin_confidence: analysis.origin_confidence,
+        source_language: analysis.source_language,
+        is_correct: analysis.is_correct,
+        correction: analysis.correction,
+        rule_notes: analysis
+            .rule_notes
+            .into_iter()
+            .map(|n| CRuleNote {
+                rule: n.rule.to_string(),
+                rule_code: n.rule.code().to_string(),
+                explanation: n.explanation,
+            })
+            .collect(),
+        samasa: analysis.samasa.map(|c| CSamasaCandidate {
+            left: c.left,
+            right: c.right,
+            samasa_type: samasa_type_to_string(c.samasa_type).to_string(),
+            score: c.score,
+            vigraha: c.vigraha,
+        }),
+    };
+    let json = serde_json::to_string(&c_analysis).unwrap_or_else(|_| "{}".to_string());
+    string_to_c(json)
+}
+
+/// Apply sandhi: join two morphemes.
+///
+/// Returns JSON `{ output, sandhi_type, rule_citation }` as a C string, or
+/// `{ "error": "..." }` when no sandhi rule applies.
+/// The caller must free the returned pointer with `varnavinyas_free_string`.
+/// Returns NULL if either input is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `first` and `second` must be valid null-terminated C strings or null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn varnavinyas_sandhi_apply(
+    first: *const c_char,
+    second: *const c_char,
+) -> *mut c_char {
+    let (Some(first), Some(second)) = (unsafe { cstr_to_str(first) }, unsafe {
+        cstr_to_str(second)
+    }) else {
+        return std::ptr::null_mut();
+    };
+    let json = match varnavinyas_sandhi::apply(first, second) {
+        Ok(res) => serde_json::to_string(&CSandhiResult {
+            output: res.output,
+            sandhi_type: res.sandhi_type.display_label().to_string(),
+            rule_citation: res.rule_citation.to_string(),
+        })
+        .unwrap_or_else(|_| "{}".to_string()),
+        Err(e) => serde_json::json!({ "error": e.to_string() }).to_string(),
+    };
+    string_to_c(json)
+}
+
+/// Split a word at sandhi boundaries.
+///
+/// Returns a JSON array `[{ left, right, output, sandhi_type, rule_citation, confidence }, ...]`
+/// as a C string, most confident split first; the array is empty when no split is found.
+/// The caller must free the returned pointer with `varnavinyas_free_string`.
+/// Returns NULL on null input or invalid UTF-8.
+///
+/// # Safety
+///
+/// `word` must be a valid null-terminated C string or null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn varnavinyas_sandhi_split(word: *const c_char) -> *mut c_char {
+    let Some(word) = (unsafe { cstr_to_str(word) }) else {
+        return std::ptr::null_mut();
+    };
+    let splits: Vec<CSandhiSplit> = varnavinyas_sandhi::split(word)
+        .into_iter()
+        .map(|(left, right, res)| CSandhiSplit {
+            left,
+            right,
+            output: res.output,
+            sandhi_type: res.sandhi_type.display_label().to_string(),
+            rule_citation: res.rule_citation.to_string(),
+            confidence: res.confidence,
+        })
+        .collect();
+    let json = serde_json::to_string(&splits).unwrap_or_else(|_| "[]".to_string());
+    string_to_c(json)
+}
+
 /// Transliterate text between Devanagari and IAST.
 ///
-/// `from` and `to` are scheme constants: `SCHEME_DEVANAGARI` (0) or `SCHEME_IAST` (1).
+/// `from` and `to` are scheme constants: `SCHEME_DEVANAGARI` (0), `SCHEME_IAST` (1),
+/// `SCHEME_NEPALI_PHONETIC` (2, output only) or `SCHEME_ITRANS` (3).
 /// Returns the transliterated text as a C string.
 /// The caller must free the returned pointer with `varnavinyas_free_string`.
 /// Returns NULL on null input, invalid UTF-8, invalid scheme value, or transliteration error.
@@ -246,6 +451,35 @@ pub unsafe extern "C" fn varnavinyas_classify(word: *const c_char) -> Origin {
     }
 }
 
+fn origin_to_string(origin: varnavinyas_shabda::Origin) -> &'static str {
+    match origin {
+        varnavinyas_shabda::Origin::Tatsam => "tatsam",
+        varnavinyas_shabda::Origin::Tadbhav => "tadbhav",
+        varnavinyas_shabda::Origin::Deshaj => "deshaj",
+        varnavinyas_shabda::Origin::Aagantuk => "aagantuk",
+    }
+}
+
+fn origin_source_to_string(source: varnavinyas_shabda::OriginSource) -> &'static str {
+    match source {
+        varnavinyas_shabda::OriginSource::Override => "override",
+        varnavinyas_shabda::OriginSource::Kosha => "kosha",
+        varnavinyas_shabda::OriginSource::Heuristic => "heuristic",
+    }
+}
+
+fn samasa_type_to_string(t: varnavinyas_samasa::SamasaType) -> &'static str {
+    match t {
+        varnavinyas_samasa::SamasaType::Tatpurusha => "tatpurusha",
+        varnavinyas_samasa::SamasaType::Karmadharaya => "karmadharaya",
+        varnavinyas_samasa::SamasaType::Dvigu => "dvigu",
+        varnavinyas_samasa::SamasaType::Bahuvrihi => "bahuvrihi",
+        varnavinyas_samasa::SamasaType::Dvandva => "dvandva",
+        varnavinyas_samasa::SamasaType::Avyayibhava => "avyayibhava",
+        varnavinyas_samasa::SamasaType::Unknown => "unknown",
+    }
+}
+
 /// Free a string previously returned by a varnavinyas function.
 ///
 /// Must be called on every non-NULL string returned by this library.
@@ -317,6 +551,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn check_text_with_options2_promotes_variants() {
+        let input = CString::new("नेपाल").unwrap();
+        unsafe {
+            let result =
+                varnavinyas_check_text_with_options2(input.as_ptr(), false, 99, false, true);
+            assert!(result.is_null());
+
+            let result = varnavinyas_check_text_with_options2(
+                input.as_ptr(),
+                false,
+                PUNCTUATION_STRICT,
+                false,
+                true,
+            );
+            assert!(!result.is_null());
+            let s = CStr::from_ptr(result).to_str().unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(s).unwrap();
+            assert!(parsed.is_array());
+            varnavinyas_free_string(result);
+        }
+    }
+
     #[test]
     fn transliterate_works() {
         let input = CString::new("नमस्ते").unwrap();
@@ -369,6 +626,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn analyze_returns_origin_json() {
+        let input = CString::new("अत्याधिक").unwrap();
+        unsafe {
+            let result = varnavinyas_analyze(input.as_ptr());
+            assert!(!result.is_null());
+            let s = CStr::from_ptr(result).to_str().unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(s).unwrap();
+            assert!(parsed["origin"].is_string());
+            assert!(parsed["rule_notes"].is_array());
+            varnavinyas_free_string(result);
+        }
+    }
+
+    #[test]
+    fn analyze_includes_samasa_candidate() {
+        let input = CString::new("सूर्योदय").unwrap();
+        unsafe {
+            let result = varnavinyas_analyze(input.as_ptr());
+            let s = CStr::from_ptr(result).to_str().unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(s).unwrap();
+            assert_eq!(parsed["samasa"]["left"], "सूर्य");
+            assert_eq!(parsed["samasa"]["right"], "उदय");
+            assert!(parsed["samasa"]["samasa_type"].is_string());
+            assert!(parsed["samasa"]["vigraha"].is_string());
+            varnavinyas_free_string(result);
+        }
+    }
+
+    #[test]
+    fn analyze_null_returns_null() {
+        unsafe {
+            let result = varnavinyas_analyze(std::ptr::null());
+            assert!(result.is_null());
+        }
+    }
+
+    #[test]
+    fn sandhi_apply_returns_result_json() {
+        let first = CString::new("विद्या").unwrap();
+        let second = CString::new("आलय").unwrap();
+        unsafe {
+            let result = varnavinyas_sandhi_apply(first.as_ptr(), second.as_ptr());
+            assert!(!result.is_null());
+            let s = CStr::from_ptr(result).to_str().unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(s).unwrap();
+            assert_eq!(parsed["output"], "विद्यालय");
+            assert!(parsed["sandhi_type"].is_string());
+            assert!(parsed["rule_citation"].is_string());
+            varnavinyas_free_string(result);
+        }
+    }
+
+    #[test]
+    fn sandhi_apply_without_rule_returns_error_json() {
+        let first = CString::new("abc").unwrap();
+        let second = CString::new("xyz").unwrap();
+        unsafe {
+            let result = varnavinyas_sandhi_apply(first.as_ptr(), second.as_ptr());
+            assert!(!result.is_null());
+            let s = CStr::from_ptr(result).to_str().unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(s).unwrap();
+            assert!(parsed["error"].is_string());
+            varnavinyas_free_string(result);
+        }
+    }
+
+    #[test]
+    fn sandhi_split_returns_candidates() {
+        let input = CString::new("विद्यालय").unwrap();
+        unsafe {
+            let result = varnavinyas_sandhi_split(input.as_ptr());
+            assert!(!result.is_null());
+            let s = CStr::from_ptr(result).to_str().unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(s).unwrap();
+            let candidates = parsed.as_array().unwrap();
+            assert!(!candidates.is_empty());
+            assert!(candidates[0]["left"].is_string());
+            varnavinyas_free_string(result);
+        }
+    }
+
+    #[test]
+    fn sandhi_null_returns_null() {
+        let input = CString::new("विद्या").unwrap();
+        unsafe {
+            assert!(varnavinyas_sandhi_apply(input.as_ptr(), std::ptr::null()).is_null());
+            assert!(varnavinyas_sandhi_split(std::ptr::null()).is_null());
+        }
+    }
+
     #[test]
     fn free_string_null_is_noop() {
         unsafe {
@@ -62,6 +62,8 @@ impl PyCharType {
 pub enum PySvarType {
     Hrasva,
     Dirgha,
+    Guna,
+    Vriddhi,
 }
 
 impl From<SvarType> for PySvarType {
@@ -69,6 +71,8 @@ impl From<SvarType> for PySvarType {
         match st {
             SvarType::Hrasva => PySvarType::Hrasva,
             SvarType::Dirgha => PySvarType::Dirgha,
+            SvarType::Guna => PySvarType::Guna,
+            SvarType::Vriddhi => PySvarType::Vriddhi,
         }
     }
 }
@@ -79,6 +83,8 @@ impl PySvarType {
         match self {
             PySvarType::Hrasva => "SvarType.Hrasva".to_string(),
             PySvarType::Dirgha => "SvarType.Dirgha".to_string(),
+            PySvarType::Guna => "SvarType.Guna".to_string(),
+            PySvarType::Vriddhi => "SvarType.Vriddhi".to_string(),
         }
     }
 }
@@ -120,7 +126,7 @@ pub fn is_vyanjan(c: char) -> bool {
     akshar_core::is_vyanjan(c)
 }
 
-/// Determine vowel type (hrasva/dirgha).
+/// Determine vowel grade (hrasva/dirgha/guna/vriddhi).
 #[pyfunction]
 pub fn svar_type(c: char) -> Option<PySvarType> {
     akshar_core::svar_type(c).map(|st| st.into())
@@ -6,6 +6,8 @@ use varnavinyas_lipi::{self as lipi_core, Scheme};
 pub enum PyScheme {
     Devanagari,
     Iast,
+    NepaliPhonetic,
+    Itrans,
 }
 
 impl From<PyScheme> for Scheme {
@@ -13,6 +15,8 @@ impl From<PyScheme> for Scheme {
         match s {
             PyScheme::Devanagari => Scheme::Devanagari,
             PyScheme::Iast => Scheme::Iast,
+            PyScheme::NepaliPhonetic => Scheme::NepaliPhonetic,
+            PyScheme::Itrans => Scheme::Itrans,
         }
     }
 }
@@ -22,6 +26,8 @@ impl From<Scheme> for PyScheme {
         match s {
             Scheme::Devanagari => PyScheme::Devanagari,
             Scheme::Iast => PyScheme::Iast,
+            Scheme::NepaliPhonetic => PyScheme::NepaliPhonetic,
+            Scheme::Itrans => PyScheme::Itrans,
         }
     }
 }
@@ -32,6 +38,8 @@ impl PyScheme {
         match self {
             PyScheme::Devanagari => "Scheme.Devanagari".to_string(),
             PyScheme::Iast => "Scheme.Iast".to_string(),
+            PyScheme::NepaliPhonetic => "Scheme.NepaliPhonetic".to_string(),
+            PyScheme::Itrans => "Scheme.Itrans".to_string(),
         }
     }
 }
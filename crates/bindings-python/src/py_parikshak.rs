@@ -8,6 +8,8 @@ use crate::py_prakriya::PyRule;
 pub struct PyDiagnostic {
     pub span_start: usize,
     pub span_end: usize,
+    pub span_start_char: usize,
+    pub span_end_char: usize,
     pub incorrect: String,
     pub correction: String,
     pub rule: PyRule,
@@ -17,6 +19,7 @@ pub struct PyDiagnostic {
     pub category_code: String,
     pub kind: String,
     pub confidence: f32,
+    pub origin_code: Option<String>,
 }
 
 #[pymethods]
@@ -40,9 +43,27 @@ impl PyDiagnostic {
 /// Returns a Diagnostic or None.
 #[pyfunction]
 pub fn check_word(word: &str) -> Option<PyDiagnostic> {
-    parikshak_core::check_word(word).map(|d| PyDiagnostic {
+    parikshak_core::check_word(word).map(|d| word_diagnostic(d, word))
+}
+
+/// Check a list of words.
+/// Returns one Diagnostic or None per input word, in input order.
+#[pyfunction]
+pub fn check_words(words: Vec<String>) -> Vec<Option<PyDiagnostic>> {
+    words
+        .iter()
+        .zip(parikshak_core::check_words(&words))
+        .map(|(word, diag)| diag.map(|d| word_diagnostic(d, word)))
+        .collect()
+}
+
+fn word_diagnostic(d: parikshak_core::Diagnostic, word: &str) -> PyDiagnostic {
+    let (span_start_char, span_end_char) = d.char_span(word);
+    PyDiagnostic {
         span_start: d.span.0,
         span_end: d.span.1,
+        span_start_char,
+        span_end_char,
         incorrect: d.incorrect,
         correction: d.correction,
         rule_code: d.rule.code().to_string(),
@@ -52,34 +73,43 @@ pub fn check_word(word: &str) -> Option<PyDiagnostic> {
         category_code: d.category.as_code().to_string(),
         kind: d.kind.as_code().to_string(),
         confidence: d.confidence,
-    })
+        origin_code: d.origin.map(|o| o.transliterated_label().to_string()),
+    }
 }
 
 /// Check a full text for spelling and punctuation issues.
 /// Returns a list of Diagnostic objects.
 #[pyfunction]
 pub fn check_text(text: &str) -> PyResult<Vec<PyDiagnostic>> {
-    check_text_with_options(text, false, "strict", false)
+    check_text_with_options(text, false, "strict", false, false)
 }
 
 fn parse_punctuation_mode(mode: &str) -> PyResult<varnavinyas_parikshak::PunctuationMode> {
     match mode {
         "strict" => Ok(varnavinyas_parikshak::PunctuationMode::Strict),
         "normalized_editorial" => Ok(varnavinyas_parikshak::PunctuationMode::NormalizedEditorial),
+        "lenient" => Ok(varnavinyas_parikshak::PunctuationMode::Lenient),
         other => Err(pyo3::exceptions::PyValueError::new_err(format!(
-            "Unknown punctuation_mode '{other}'. Use 'strict' or 'normalized_editorial'."
+            "Unknown punctuation_mode '{other}'. Use 'strict', 'normalized_editorial' or 'lenient'."
         ))),
     }
 }
 
 /// Check full text with runtime options.
 #[pyfunction]
-#[pyo3(signature = (text, grammar=false, punctuation_mode="strict", include_noop_heuristics=false))]
+#[pyo3(signature = (
+    text,
+    grammar=false,
+    punctuation_mode="strict",
+    include_noop_heuristics=false,
+    promote_variants_to_errors=false,
+))]
 pub fn check_text_with_options(
     text: &str,
     grammar: bool,
     punctuation_mode: &str,
     include_noop_heuristics: bool,
+    promote_variants_to_errors: bool,
 ) -> PyResult<Vec<PyDiagnostic>> {
     let punctuation_mode = parse_punctuation_mode(punctuation_mode)?;
     let diagnostics = parikshak_core::check_text_with_options(
@@ -88,22 +118,34 @@ pub fn check_text_with_options(
             grammar,
             punctuation_mode,
             include_noop_heuristics,
+            suggest: false,
+            promote_variants_to_errors,
+            check_compounds: false,
+            error_categories: None,
+            variant_spellings: false,
+            namayogi_joins: false,
         },
     );
     Ok(diagnostics
         .into_iter()
-        .map(|d| PyDiagnostic {
-            span_start: d.span.0,
-            span_end: d.span.1,
-            incorrect: d.incorrect,
-            correction: d.correction,
-            rule_code: d.rule.code().to_string(),
-            rule: d.rule.into(),
-            explanation: d.explanation,
-            category: d.category.to_string(),
-            category_code: d.category.as_code().to_string(),
-            kind: d.kind.as_code().to_string(),
-            confidence: d.confidence,
+        .map(|d| {
+            let (span_start_char, span_end_char) = d.char_span(text);
+            PyDiagnostic {
+                span_start: d.span.0,
+                span_end: d.span.1,
+                span_start_char,
+                span_end_char,
+                incorrect: d.incorrect,
+                correction: d.correction,
+                rule_code: d.rule.code().to_string(),
+                rule: d.rule.into(),
+                explanation: d.explanation,
+                category: d.category.to_string(),
+                category_code: d.category.as_code().to_string(),
+                kind: d.kind.as_code().to_string(),
+                confidence: d.confidence,
+                origin_code: d.origin.map(|o| o.transliterated_label().to_string()),
+            }
         })
         .collect())
 }
@@ -112,6 +154,7 @@ pub fn check_text_with_options(
 pub fn parikshak(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyDiagnostic>()?;
     m.add_function(wrap_pyfunction!(check_word, m)?)?;
+    m.add_function(wrap_pyfunction!(check_words, m)?)?;
     m.add_function(wrap_pyfunction!(check_text, m)?)?;
     m.add_function(wrap_pyfunction!(check_text_with_options, m)?)?;
     Ok(())
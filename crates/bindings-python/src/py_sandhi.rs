@@ -8,6 +8,7 @@ pub enum PySandhiType {
     VowelSandhi,
     VisargaSandhi,
     ConsonantSandhi,
+    NoChange,
 }
 
 impl From<SandhiType> for PySandhiType {
@@ -16,6 +17,7 @@ impl From<SandhiType> for PySandhiType {
             SandhiType::VowelSandhi => PySandhiType::VowelSandhi,
             SandhiType::VisargaSandhi => PySandhiType::VisargaSandhi,
             SandhiType::ConsonantSandhi => PySandhiType::ConsonantSandhi,
+            SandhiType::NoChange => PySandhiType::NoChange,
         }
     }
 }
@@ -27,6 +29,7 @@ impl PySandhiType {
             PySandhiType::VowelSandhi => "SandhiType.VowelSandhi".to_string(),
             PySandhiType::VisargaSandhi => "SandhiType.VisargaSandhi".to_string(),
             PySandhiType::ConsonantSandhi => "SandhiType.ConsonantSandhi".to_string(),
+            PySandhiType::NoChange => "SandhiType.NoChange".to_string(),
         }
     }
 
@@ -37,6 +40,7 @@ impl PySandhiType {
             PySandhiType::VowelSandhi => SandhiType::VowelSandhi.display_label(),
             PySandhiType::VisargaSandhi => SandhiType::VisargaSandhi.display_label(),
             PySandhiType::ConsonantSandhi => SandhiType::ConsonantSandhi.display_label(),
+            PySandhiType::NoChange => SandhiType::NoChange.display_label(),
         }
     }
 }
@@ -47,16 +51,18 @@ pub struct PySandhiResult {
     pub output: String,
     pub sandhi_type: PySandhiType,
     pub rule_citation: String,
+    pub confidence: f32,
 }
 
 #[pymethods]
 impl PySandhiResult {
     fn __repr__(&self) -> String {
         format!(
-            "SandhiResult(output='{}', type={}, rule='{}')",
+            "SandhiResult(output='{}', type={}, rule='{}', confidence={:.2})",
             self.output,
             self.sandhi_type.__repr__(),
             self.rule_citation,
+            self.confidence,
         )
     }
 }
@@ -69,6 +75,7 @@ pub fn apply(first: &str, second: &str) -> PyResult<PySandhiResult> {
             output: r.output,
             sandhi_type: r.sandhi_type.into(),
             rule_citation: r.rule_citation.to_string(),
+            confidence: r.confidence,
         })
         .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
 }
@@ -86,6 +93,7 @@ pub fn split(word: &str) -> Vec<(String, String, PySandhiResult)> {
                     output: result.output,
                     sandhi_type: result.sandhi_type.into(),
                     rule_citation: result.rule_citation.to_string(),
+                    confidence: result.confidence,
                 },
             )
         })
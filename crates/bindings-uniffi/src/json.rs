@@ -0,0 +1,63 @@
+//! JSON-string variants of the check functions.
+//!
+//! These predate the `FfiDiagnostic` record and are kept for one release so
+//! existing Swift/Kotlin callers keep working while they migrate.
+#![allow(deprecated)]
+
+use serde::Serialize;
+
+use crate::{PunctuationMode, check_text, check_text_with_options, check_word, check_words};
+
+/// Check text for spelling and punctuation issues.
+///
+/// Returns a JSON array of diagnostics.
+#[deprecated(note = "use `check_text`, which returns `FfiDiagnostic` records")]
+#[uniffi::export]
+pub fn check_text_json(text: String) -> String {
+    to_json(&check_text(text), "[]")
+}
+
+/// Check text with runtime options.
+///
+/// Returns a JSON array of diagnostics.
+#[deprecated(note = "use `check_text_with_options`, which returns `FfiDiagnostic` records")]
+#[uniffi::export]
+pub fn check_text_with_options_json(
+    text: String,
+    grammar: bool,
+    punctuation_mode: PunctuationMode,
+    include_noop_heuristics: bool,
+) -> String {
+    to_json(
+        &check_text_with_options(
+            text,
+            grammar,
+            punctuation_mode,
+            include_noop_heuristics,
+            false,
+        ),
+        "[]",
+    )
+}
+
+/// Check a single word.
+///
+/// Returns a JSON diagnostic object or `null`.
+#[deprecated(note = "use `check_word`, which returns an `FfiDiagnostic` record")]
+#[uniffi::export]
+pub fn check_word_json(word: String) -> String {
+    to_json(&check_word(word), "null")
+}
+
+/// Check a list of words.
+///
+/// Returns a JSON array with one diagnostic object or `null` per input word.
+#[deprecated(note = "use `check_words`, which returns `FfiDiagnostic` records")]
+#[uniffi::export]
+pub fn check_words_json(words: Vec<String>) -> String {
+    to_json(&check_words(words), "[]")
+}
+
+fn to_json<T: Serialize>(value: &T, fallback: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| fallback.to_string())
+}
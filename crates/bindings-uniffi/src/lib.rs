@@ -1,5 +1,10 @@
 use serde::Serialize;
 
+mod json;
+
+#[allow(deprecated)]
+pub use json::{check_text_json, check_text_with_options_json, check_word_json, check_words_json};
+
 uniffi::setup_scaffolding!();
 
 /// Transliteration scheme.
@@ -7,6 +12,8 @@ uniffi::setup_scaffolding!();
 pub enum Scheme {
     Devanagari,
     Iast,
+    NepaliPhonetic,
+    Itrans,
 }
 
 /// Word origin classification.
@@ -23,109 +30,119 @@ pub enum Origin {
 pub enum PunctuationMode {
     Strict,
     NormalizedEditorial,
+    Lenient,
 }
 
 /// A single spell-check diagnostic.
-#[derive(Debug, Serialize)]
-struct FfiDiagnostic {
-    span_start: u64,
-    span_end: u64,
-    incorrect: String,
-    correction: String,
-    rule: String,
-    rule_code: String,
-    explanation: String,
-    category: String,
-    category_code: String,
-    kind: String,
-    confidence: f32,
+#[derive(Debug, Clone, PartialEq, Serialize, uniffi::Record)]
+pub struct FfiDiagnostic {
+    pub span_start: u64,
+    pub span_end: u64,
+    pub incorrect: String,
+    pub correction: String,
+    pub rule: String,
+    pub rule_code: String,
+    pub explanation: String,
+    pub category: String,
+    pub category_code: String,
+    pub kind: String,
+    pub confidence: f32,
+    pub origin_code: Option<String>,
+}
+
+impl From<varnavinyas_parikshak::Diagnostic> for FfiDiagnostic {
+    fn from(d: varnavinyas_parikshak::Diagnostic) -> Self {
+        Self {
+            span_start: d.span.0 as u64,
+            span_end: d.span.1 as u64,
+            incorrect: d.incorrect,
+            correction: d.correction,
+            rule: d.rule.to_string(),
+            rule_code: d.rule.code().to_string(),
+            explanation: d.explanation,
+            category: d.category.to_string(),
+            category_code: d.category.as_code().to_string(),
+            kind: d.kind.as_code().to_string(),
+            confidence: d.confidence,
+            origin_code: d.origin.map(|o| o.transliterated_label().to_string()),
+        }
+    }
 }
 
 /// Check text for spelling and punctuation issues.
-///
-/// Returns a JSON array of diagnostics.
 #[uniffi::export]
-pub fn check_text(text: String) -> String {
-    check_text_with_options(text, false, PunctuationMode::Strict, false)
+pub fn check_text(text: String) -> Vec<FfiDiagnostic> {
+    check_text_with_options(text, false, PunctuationMode::Strict, false, false)
 }
 
 /// Check text with runtime options.
 ///
-/// Returns a JSON array of diagnostics.
+/// `promote_variants_to_errors` reports every variant as an error.
 #[uniffi::export]
 pub fn check_text_with_options(
     text: String,
     grammar: bool,
     punctuation_mode: PunctuationMode,
     include_noop_heuristics: bool,
-) -> String {
+    promote_variants_to_errors: bool,
+) -> Vec<FfiDiagnostic> {
     let punctuation_mode = match punctuation_mode {
         PunctuationMode::Strict => varnavinyas_parikshak::PunctuationMode::Strict,
         PunctuationMode::NormalizedEditorial => {
             varnavinyas_parikshak::PunctuationMode::NormalizedEditorial
         }
+        PunctuationMode::Lenient => varnavinyas_parikshak::PunctuationMode::Lenient,
     };
-    let diags = varnavinyas_parikshak::check_text_with_options(
+    varnavinyas_parikshak::check_text_with_options(
         &text,
         varnavinyas_parikshak::CheckOptions {
             grammar,
             punctuation_mode,
             include_noop_heuristics,
+            suggest: false,
+            promote_variants_to_errors,
+            check_compounds: false,
+            error_categories: None,
+            variant_spellings: false,
+            namayogi_joins: false,
         },
-    );
-    let ffi_diags: Vec<FfiDiagnostic> = diags
-        .into_iter()
-        .map(|d| FfiDiagnostic {
-            span_start: d.span.0 as u64,
-            span_end: d.span.1 as u64,
-            incorrect: d.incorrect,
-            correction: d.correction,
-            rule: d.rule.to_string(),
-            rule_code: d.rule.code().to_string(),
-            explanation: d.explanation,
-            category: d.category.to_string(),
-            category_code: d.category.as_code().to_string(),
-            kind: d.kind.as_code().to_string(),
-            confidence: d.confidence,
-        })
-        .collect();
-    serde_json::to_string(&ffi_diags).unwrap_or_else(|_| "[]".to_string())
+    )
+    .into_iter()
+    .map(FfiDiagnostic::from)
+    .collect()
 }
 
 /// Check a single word.
+#[uniffi::export]
+pub fn check_word(word: String) -> Option<FfiDiagnostic> {
+    varnavinyas_parikshak::check_word(&word).map(FfiDiagnostic::from)
+}
+
+/// Check a list of words.
 ///
-/// Returns a JSON diagnostic object or `null`.
+/// Returns one diagnostic or `None` per input word, in input order.
 #[uniffi::export]
-pub fn check_word(word: String) -> String {
-    match varnavinyas_parikshak::check_word(&word) {
-        Some(d) => serde_json::to_string(&FfiDiagnostic {
-            span_start: d.span.0 as u64,
-            span_end: d.span.1 as u64,
-            incorrect: d.incorrect,
-            correction: d.correction,
-            rule: d.rule.to_string(),
-            rule_code: d.rule.code().to_string(),
-            explanation: d.explanation,
-            category: d.category.to_string(),
-            category_code: d.category.as_code().to_string(),
-            kind: d.kind.as_code().to_string(),
-            confidence: d.confidence,
-        })
-        .unwrap_or_else(|_| "null".to_string()),
-        None => "null".to_string(),
-    }
+pub fn check_words(words: Vec<String>) -> Vec<Option<FfiDiagnostic>> {
+    varnavinyas_parikshak::check_words(&words)
+        .into_iter()
+        .map(|d| d.map(FfiDiagnostic::from))
+        .collect()
 }
 
-/// Transliterate text between Devanagari and IAST.
+/// Transliterate text between Devanagari and IAST or ITRANS, or Devanagari to Nepali phonetic.
 #[uniffi::export]
 pub fn transliterate(input: String, from: Scheme, to: Scheme) -> Result<String, String> {
     let from_scheme = match from {
         Scheme::Devanagari => varnavinyas_lipi::Scheme::Devanagari,
         Scheme::Iast => varnavinyas_lipi::Scheme::Iast,
+        Scheme::NepaliPhonetic => varnavinyas_lipi::Scheme::NepaliPhonetic,
+        Scheme::Itrans => varnavinyas_lipi::Scheme::Itrans,
     };
     let to_scheme = match to {
         Scheme::Devanagari => varnavinyas_lipi::Scheme::Devanagari,
         Scheme::Iast => varnavinyas_lipi::Scheme::Iast,
+        Scheme::NepaliPhonetic => varnavinyas_lipi::Scheme::NepaliPhonetic,
+        Scheme::Itrans => varnavinyas_lipi::Scheme::Itrans,
     };
     varnavinyas_lipi::transliterate(&input, from_scheme, to_scheme).map_err(|e| e.to_string())
 }
@@ -146,36 +163,92 @@ mod tests {
     use super::*;
 
     #[test]
-    fn check_text_returns_valid_json() {
-        let result = check_text("नेपाल".to_string());
-        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
-        assert!(parsed.is_array());
+    fn check_text_returns_records() {
+        let diags = check_text("अत्याधिक वर्षा".to_string());
+        let d = diags
+            .iter()
+            .find(|d| d.incorrect == "अत्याधिक")
+            .unwrap_or_else(|| panic!("expected अत्याधिक to be flagged, got: {diags:?}"));
+        assert_eq!(d.correction, "अत्यधिक");
+        assert_eq!((d.span_start, d.span_end), (0, "अत्याधिक".len() as u64));
+        assert!(!d.rule.is_empty());
+        assert!(!d.rule_code.is_empty());
+        assert!(!d.explanation.is_empty());
+        assert!(!d.category_code.is_empty());
+        assert_eq!(d.kind, "Error");
+        assert!(d.confidence > 0.0);
     }
 
     #[test]
-    fn check_text_finds_error() {
-        let result = check_text("दुकान".to_string());
-        let parsed: Vec<serde_json::Value> = serde_json::from_str(&result).unwrap();
-        if !parsed.is_empty() {
-            let d = &parsed[0];
-            assert!(d["incorrect"].is_string());
-            assert!(d["correction"].is_string());
-            assert!(d["rule"].is_string());
-        }
+    fn check_text_clean_input_is_empty() {
+        assert!(check_text("नेपाल".to_string()).is_empty());
+    }
+
+    #[test]
+    fn check_word_returns_record_or_none() {
+        let d = check_word("अत्याधिक".to_string()).expect("अत्याधिक should be flagged");
+        assert_eq!(d.incorrect, "अत्याधिक");
+        assert_eq!(d.correction, "अत्यधिक");
+        assert!(check_word("नेपाल".to_string()).is_none());
+
+        let d = check_word("विद्वान".to_string()).expect("विद्वान should be flagged");
+        assert_eq!(d.origin_code.as_deref(), Some("tatsam"));
     }
 
     #[test]
-    fn check_word_returns_json_object_or_null() {
-        let result = check_word("अध्यन".to_string());
-        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
-        assert!(parsed.is_object() || parsed.is_null());
+    fn check_words_returns_one_entry_per_word() {
+        let words = vec!["नेपाल".to_string(), "अत्याधिक".to_string(), "घर".to_string()];
+        let diags = check_words(words);
+        assert_eq!(diags.len(), 3);
+        assert!(diags[0].is_none());
+        assert_eq!(
+            diags[1].as_ref().map(|d| d.correction.as_str()),
+            Some("अत्यधिक")
+        );
+        assert!(diags[2].is_none());
     }
 
     #[test]
     fn check_text_with_options_works() {
-        let result =
-            check_text_with_options("नेपाल".to_string(), true, PunctuationMode::Strict, false);
-        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let diags = check_text_with_options(
+            "नेपाल".to_string(),
+            true,
+            PunctuationMode::Strict,
+            false,
+            false,
+        );
+        for d in &diags {
+            assert!(d.span_start <= d.span_end);
+            assert!(d.span_end <= "नेपाल".len() as u64);
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn json_aliases_match_records() {
+        let parsed: Vec<serde_json::Value> =
+            serde_json::from_str(&check_text_json("अत्याधिक".to_string())).unwrap();
+        assert_eq!(parsed[0]["correction"], "अत्यधिक");
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(&check_word_json("नेपाल".to_string())).unwrap();
+        assert!(parsed.is_null());
+
+        let parsed: Vec<serde_json::Value> = serde_json::from_str(&check_words_json(vec![
+            "नेपाल".to_string(),
+            "अत्याधिक".to_string(),
+        ]))
+        .unwrap();
+        assert!(parsed[0].is_null());
+        assert_eq!(parsed[1]["incorrect"], "अत्याधिक");
+
+        let parsed: serde_json::Value = serde_json::from_str(&check_text_with_options_json(
+            "नेपाल".to_string(),
+            false,
+            PunctuationMode::Lenient,
+            false,
+        ))
+        .unwrap();
         assert!(parsed.is_array());
     }
 
@@ -211,8 +284,13 @@ mod tests {
 
     #[test]
     fn scheme_enum_round_trip() {
-        // Verify both variants work in transliterate
-        for scheme in [Scheme::Devanagari, Scheme::Iast] {
+        // Verify every variant works in transliterate
+        for scheme in [
+            Scheme::Devanagari,
+            Scheme::Iast,
+            Scheme::NepaliPhonetic,
+            Scheme::Itrans,
+        ] {
             let _ = transliterate("test".to_string(), scheme, scheme);
         }
     }
@@ -8,6 +8,14 @@ use wasm_bindgen::prelude::*;
 struct JsDiagnostic {
     span_start: usize,
     span_end: usize,
+    /// Span start in Unicode scalar values (code points).
+    span_start_char: usize,
+    /// Span end in Unicode scalar values (code points).
+    span_end_char: usize,
+    /// Span start in UTF-16 code units (JavaScript string index).
+    span_start_utf16: usize,
+    /// Span end in UTF-16 code units (JavaScript string index).
+    span_end_utf16: usize,
     incorrect: String,
     correction: String,
     rule: String,
@@ -21,6 +29,9 @@ struct JsDiagnostic {
     kind: String,
     /// Confidence score (0.0–1.0).
     confidence: f32,
+    /// Word origin ("tatsam", "tadbhav", "deshaj", "aagantuk") for
+    /// origin-driven spelling diagnostics, otherwise null.
+    origin_code: Option<String>,
 }
 
 /// A prakriya step serialized for JavaScript consumers.
@@ -53,30 +64,52 @@ pub fn check_text(text: &str) -> String {
 // Check full text with optional grammar-pass diagnostics.
 #[wasm_bindgen]
 pub fn check_text_with_options(text: &str, grammar: bool) -> String {
-    let diags = varnavinyas_parikshak::check_text_with_options(
-        text,
-        varnavinyas_parikshak::CheckOptions {
-            grammar,
-            ..Default::default()
-        },
-    );
-    let js_diags: Vec<JsDiagnostic> = diags.into_iter().map(diagnostic_to_js).collect();
+    check_text_with_options2(text, grammar, false)
+}
+
+// Same as `check_text_with_options`; with `promote_variants_to_errors`,
+// every variant is reported as an error.
+#[wasm_bindgen]
+pub fn check_text_with_options2(
+    text: &str,
+    grammar: bool,
+    promote_variants_to_errors: bool,
+) -> String {
+    let js_diags = check_js(text, grammar, promote_variants_to_errors);
     serde_json::to_string(&js_diags).unwrap_or_else(|_| "[]".to_string())
 }
 
 /// Check full text with optional grammar-pass diagnostics and return typed JsValue.
 #[wasm_bindgen]
 pub fn check_text_value(text: &str, grammar: bool) -> Result<JsValue, JsError> {
-    let diags = varnavinyas_parikshak::check_text_with_options(
+    check_text_value2(text, grammar, false)
+}
+
+/// Same as `check_text_value`; with `promote_variants_to_errors`, every
+/// variant is reported as an error.
+#[wasm_bindgen]
+pub fn check_text_value2(
+    text: &str,
+    grammar: bool,
+    promote_variants_to_errors: bool,
+) -> Result<JsValue, JsError> {
+    let js_diags = check_js(text, grammar, promote_variants_to_errors);
+    serde_wasm_bindgen::to_value(&js_diags)
+        .map_err(|e| JsError::new(&format!("failed to serialize diagnostics: {e}")))
+}
+
+fn check_js(text: &str, grammar: bool, promote_variants_to_errors: bool) -> Vec<JsDiagnostic> {
+    varnavinyas_parikshak::check_text_with_options(
         text,
         varnavinyas_parikshak::CheckOptions {
             grammar,
+            promote_variants_to_errors,
             ..Default::default()
         },
-    );
-    let js_diags: Vec<JsDiagnostic> = diags.into_iter().map(diagnostic_to_js).collect();
-    serde_wasm_bindgen::to_value(&js_diags)
-        .map_err(|e| JsError::new(&format!("failed to serialize diagnostics: {e}")))
+    )
+    .into_iter()
+    .map(|d| diagnostic_to_js(d, text))
+    .collect()
 }
 
 /// Check a single word. Returns a JSON diagnostic or "null".
@@ -84,18 +117,30 @@ pub fn check_text_value(text: &str, grammar: bool) -> Result<JsValue, JsError> {
 pub fn check_word(word: &str) -> String {
     match varnavinyas_parikshak::check_word(word) {
         Some(d) => {
-            let js = diagnostic_to_js(d);
+            let js = diagnostic_to_js(d, word);
             serde_json::to_string(&js).unwrap_or_else(|_| "null".to_string())
         }
         None => "null".to_string(),
     }
 }
 
+/// Check a list of words. Returns a JSON array with one diagnostic or
+/// `null` per input word, in input order.
+#[wasm_bindgen]
+pub fn check_words(words: Vec<String>) -> String {
+    let js: Vec<Option<JsDiagnostic>> = words
+        .iter()
+        .zip(varnavinyas_parikshak::check_words(&words))
+        .map(|(word, diag)| diag.map(|d| diagnostic_to_js(d, word)))
+        .collect();
+    serde_json::to_string(&js).unwrap_or_else(|_| "[]".to_string())
+}
+
 /// Check a single word and return typed JsValue (object or null).
 #[wasm_bindgen]
 pub fn check_word_value(word: &str) -> Result<JsValue, JsError> {
     match varnavinyas_parikshak::check_word(word) {
-        Some(d) => serde_wasm_bindgen::to_value(&diagnostic_to_js(d))
+        Some(d) => serde_wasm_bindgen::to_value(&diagnostic_to_js(d, word))
             .map_err(|e| JsError::new(&format!("failed to serialize diagnostic: {e}"))),
         None => Ok(JsValue::NULL),
     }
@@ -140,6 +185,19 @@ struct JsWordAnalysis {
     is_correct: bool,
     correction: Option<String>,
     rule_notes: Vec<JsRuleNote>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    samasa: Option<JsSamasaCandidate>,
+}
+
+/// A compound (samasa) interpretation serialized for JavaScript consumers.
+#[derive(Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+struct JsSamasaCandidate {
+    left: String,
+    right: String,
+    samasa_type: String,
+    score: f32,
+    vigraha: String,
 }
 
 /// A rule note serialized for JavaScript consumers.
@@ -200,6 +258,7 @@ struct JsSandhiResult {
     output: String,
     sandhi_type: String,
     rule_citation: String,
+    rule_code: String,
 }
 
 /// A sandhi split entry serialized for JavaScript consumers.
@@ -211,10 +270,12 @@ struct JsSandhiSplit {
     output: String,
     sandhi_type: String,
     rule_citation: String,
+    rule_code: String,
+    confidence: f32,
 }
 
 /// Apply sandhi: join two morphemes.
-/// Returns JSON: `{ output, sandhi_type, rule_citation }` or `{ "error": "..." }`.
+/// Returns JSON: `{ output, sandhi_type, rule_citation, rule_code }` or `{ "error": "..." }`.
 #[wasm_bindgen]
 pub fn sandhi_apply(first: &str, second: &str) -> String {
     match varnavinyas_sandhi::apply(first, second) {
@@ -237,7 +298,8 @@ pub fn sandhi_apply_value(first: &str, second: &str) -> Result<JsValue, JsError>
 }
 
 /// Split a word at sandhi boundaries.
-/// Returns JSON array: `[{ left, right, output, sandhi_type, rule_citation }, ...]`.
+/// Returns JSON array: `[{ left, right, output, sandhi_type, rule_citation, rule_code, confidence }, ...]`,
+/// most confident split first.
 #[wasm_bindgen]
 pub fn sandhi_split(word: &str) -> String {
     let results = varnavinyas_sandhi::split(word);
@@ -265,6 +327,7 @@ fn sandhi_result_to_js(res: varnavinyas_sandhi::SandhiResult) -> JsSandhiResult
         output: res.output,
         sandhi_type: res.sandhi_type.display_label().to_string(),
         rule_citation: res.rule_citation.to_string(),
+        rule_code: res.rule.code().to_string(),
     }
 }
 
@@ -279,6 +342,8 @@ fn sandhi_split_to_js(
         output: res.output,
         sandhi_type: res.sandhi_type.display_label().to_string(),
         rule_citation: res.rule_citation.to_string(),
+        rule_code: res.rule.code().to_string(),
+        confidence: res.confidence,
     }
 }
 
@@ -291,6 +356,18 @@ fn origin_to_string(origin: varnavinyas_shabda::Origin) -> String {
     }
 }
 
+fn samasa_type_to_string(t: varnavinyas_samasa::SamasaType) -> String {
+    match t {
+        varnavinyas_samasa::SamasaType::Tatpurusha => "tatpurusha".into(),
+        varnavinyas_samasa::SamasaType::Karmadharaya => "karmadharaya".into(),
+        varnavinyas_samasa::SamasaType::Dvigu => "dvigu".into(),
+        varnavinyas_samasa::SamasaType::Bahuvrihi => "bahuvrihi".into(),
+        varnavinyas_samasa::SamasaType::Dvandva => "dvandva".into(),
+        varnavinyas_samasa::SamasaType::Avyayibhava => "avyayibhava".into(),
+        varnavinyas_samasa::SamasaType::Unknown => "unknown".into(),
+    }
+}
+
 fn origin_source_to_string(source: varnavinyas_shabda::OriginSource) -> String {
     match source {
         varnavinyas_shabda::OriginSource::Override => "override".into(),
@@ -299,10 +376,16 @@ fn origin_source_to_string(source: varnavinyas_shabda::OriginSource) -> String {
     }
 }
 
-fn diagnostic_to_js(d: varnavinyas_parikshak::Diagnostic) -> JsDiagnostic {
+fn diagnostic_to_js(d: varnavinyas_parikshak::Diagnostic, text: &str) -> JsDiagnostic {
+    let (span_start_char, span_end_char) = d.char_span(text);
+    let (span_start_utf16, span_end_utf16) = utf16_span(text, d.span);
     JsDiagnostic {
         span_start: d.span.0,
         span_end: d.span.1,
+        span_start_char,
+        span_end_char,
+        span_start_utf16,
+        span_end_utf16,
         incorrect: d.incorrect,
         correction: d.correction,
         rule: d.rule.to_string(),
@@ -312,9 +395,28 @@ fn diagnostic_to_js(d: varnavinyas_parikshak::Diagnostic) -> JsDiagnostic {
         category_code: d.category.as_code().to_string(),
         kind: d.kind.as_code().to_string(),
         confidence: d.confidence,
+        origin_code: d.origin.map(|o| o.transliterated_label().to_string()),
     }
 }
 
+/// Convert a UTF-8 byte span into UTF-16 code-unit offsets.
+///
+/// JavaScript strings index by UTF-16 code units, so these offsets can be
+/// passed straight to `String.prototype.slice`. Devanagari lies in the BMP
+/// (one unit per char), but astral characters such as emoji take two units
+/// and would skew code-point offsets. A byte offset inside a multi-byte
+/// character is rounded down to that character's start.
+fn utf16_span(text: &str, span: (usize, usize)) -> (usize, usize) {
+    (utf16_offset(text, span.0), utf16_offset(text, span.1))
+}
+
+fn utf16_offset(text: &str, byte_offset: usize) -> usize {
+    text.char_indices()
+        .take_while(|(i, c)| i + c.len_utf8() <= byte_offset)
+        .map(|(_, c)| c.len_utf16())
+        .sum()
+}
+
 fn prakriya_to_js(p: varnavinyas_prakriya::Prakriya) -> JsPrakriya {
     JsPrakriya {
         input: p.input,
@@ -351,6 +453,13 @@ fn word_analysis_to_js(analysis: varnavinyas_prakriya::WordAnalysis) -> JsWordAn
                 explanation: n.explanation,
             })
             .collect(),
+        samasa: analysis.samasa.map(|c| JsSamasaCandidate {
+            left: c.left,
+            right: c.right,
+            samasa_type: samasa_type_to_string(c.samasa_type),
+            score: c.score,
+            vigraha: c.vigraha,
+        }),
     }
 }
 
@@ -367,14 +476,66 @@ fn parse_scheme(s: &str) -> Result<varnavinyas_lipi::Scheme, JsError> {
     match s {
         "Devanagari" | "devanagari" => Ok(varnavinyas_lipi::Scheme::Devanagari),
         "Iast" | "iast" | "IAST" => Ok(varnavinyas_lipi::Scheme::Iast),
+        "NepaliPhonetic" | "nepali_phonetic" | "phonetic" => {
+            Ok(varnavinyas_lipi::Scheme::NepaliPhonetic)
+        }
+        "Itrans" | "itrans" | "ITRANS" => Ok(varnavinyas_lipi::Scheme::Itrans),
         _ => Err(JsError::new(&format!(
-            "Unknown scheme '{s}'. Use 'Devanagari' or 'Iast'."
+            "Unknown scheme '{s}'. Use 'Devanagari', 'Iast', 'Itrans' or 'NepaliPhonetic'."
         ))),
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{
+        check_text, check_text_with_options, check_text_with_options2, check_words, sandhi_apply,
+        utf16_span,
+    };
+
+    #[test]
+    fn check_words_returns_one_entry_per_word() {
+        let words = ["नेपाल", "अत्याधिक", "घर"].map(String::from).to_vec();
+        let parsed: Vec<serde_json::Value> = serde_json::from_str(&check_words(words)).unwrap();
+        assert_eq!(parsed.len(), 3);
+        assert!(parsed[0].is_null());
+        assert_eq!(parsed[1]["correction"], "अत्यधिक");
+        assert!(parsed[2].is_null());
+    }
+
+    #[test]
+    fn check_text_with_options_keeps_two_argument_form() {
+        let text = "राजनैतिक कुरा";
+        assert_eq!(
+            check_text_with_options(text, false),
+            check_text_with_options2(text, false, false)
+        );
+        assert_eq!(check_text(text), check_text_with_options(text, false));
+    }
+
+    #[test]
+    fn utf16_span_counts_surrogate_pairs() {
+        // 😀 is U+1F600: 4 bytes in UTF-8, 2 code units in UTF-16.
+        let text = "😀 नेपाल a";
+        let start = "😀 ".len();
+        let end = start + "नेपाल".len();
+        assert_eq!(utf16_span(text, (start, end)), (3, 8));
+        assert_eq!(utf16_span(text, (0, text.len())), (0, 10));
+    }
+
+    #[test]
+    fn utf16_span_matches_chars_for_devanagari() {
+        let text = "गाउँ सरीता";
+        let start = "गाउँ ".len();
+        assert_eq!(utf16_span(text, (start, text.len())), (5, 10));
+    }
+
+    #[test]
+    fn sandhi_apply_emits_rule_code() {
+        let json: serde_json::Value = serde_json::from_str(&sandhi_apply("अति", "अधिक")).unwrap();
+        assert_eq!(json["rule_code"], "sandhi-yan");
+    }
+
     #[test]
     fn sandhi_type_labels_are_devanagari() {
         assert_eq!(
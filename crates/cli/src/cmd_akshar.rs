@@ -1,4 +1,6 @@
-use varnavinyas_akshar::{CharType, Varga, classify, split_aksharas};
+use std::process::ExitCode;
+
+use varnavinyas_akshar::{CharType, Varga, char_stats, classify, split_aksharas};
 
 pub fn run(text: &str) {
     let aksharas = split_aksharas(text);
@@ -12,6 +14,12 @@ pub fn run(text: &str) {
         syllable_texts.join(" | ")
     );
 
+    let stats = char_stats(text);
+    println!(
+        "Counts: स्वर {}, व्यञ्जन {}, मात्रा {}, हलन्त {}, अंक {}",
+        stats.svar, stats.vyanjan, stats.matra, stats.halanta, stats.numerals
+    );
+
     // Character-level analysis
     println!("Characters:");
     for c in text.chars() {
@@ -34,6 +42,36 @@ pub fn run(text: &str) {
     }
 }
 
+pub fn run_json(text: &str) -> ExitCode {
+    let aksharas: Vec<&str> = split_aksharas(text)
+        .iter()
+        .map(|a| &text[a.start..a.end])
+        .collect();
+    let stats = char_stats(text);
+    let value = serde_json::json!({
+        "text": text,
+        "aksharas": aksharas,
+        "stats": {
+            "svar": stats.svar,
+            "vyanjan": stats.vyanjan,
+            "matra": stats.matra,
+            "halanta": stats.halanta,
+            "numerals": stats.numerals,
+            "aksharas": stats.aksharas,
+        },
+    });
+    match serde_json::to_string_pretty(&value) {
+        Ok(json) => {
+            println!("{json}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: failed to serialize JSON: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
 fn char_type_label(ct: CharType) -> &'static str {
     match ct {
         CharType::Svar => "स्वर (vowel)",
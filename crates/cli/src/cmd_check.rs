@@ -1,13 +1,32 @@
-use std::io::Read;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
 use std::process::ExitCode;
 
 use serde::Serialize;
+use varnavinyas_kosha::{Kosha, KoshaBuilder, KoshaError, WordEntry, kosha};
 use varnavinyas_parikshak::{
-    CheckOptions, Diagnostic, DiagnosticKind, PunctuationMode, check_text_with_options,
+    CheckOptions, Diagnostic, DiagnosticKind, LocatedDiagnostic, PunctuationMode,
+    check_text_with_kosha, check_text_with_options, locate_diagnostics,
 };
+use varnavinyas_shabda::OriginOverrides;
 
+use crate::config::CheckConfig;
 use crate::{OutputFormat, PunctuationModeArg};
 
+/// Command-line arguments of `check`, before merging with `--config`.
+pub struct CheckArgs {
+    pub input: Option<String>,
+    pub explain: bool,
+    pub grammar: bool,
+    pub punctuation_mode: Option<PunctuationModeArg>,
+    pub debug_include_noop_heuristics: bool,
+    pub fail_on_suggestions: bool,
+    pub promote_variants: bool,
+    pub format: OutputFormat,
+    pub config: Option<PathBuf>,
+    pub jsonl: bool,
+}
+
 /// JSON-serializable diagnostic output.
 #[derive(Serialize)]
 struct JsonDiagnostic {
@@ -22,50 +41,153 @@ struct JsonDiagnostic {
     confidence: f32,
 }
 
-pub fn run(
-    input: Option<String>,
-    explain: bool,
-    grammar: bool,
-    punctuation_mode: PunctuationModeArg,
-    debug_include_noop_heuristics: bool,
-    fail_on_suggestions: bool,
-    format: OutputFormat,
-) -> ExitCode {
-    let (source_name, text) = match read_input(input) {
-        Ok(v) => v,
+pub fn run(args: CheckArgs) -> ExitCode {
+    let config = match args.config.as_deref().map(CheckConfig::load).transpose() {
+        Ok(config) => config.unwrap_or_default(),
         Err(e) => {
             eprintln!("error: {e}");
             return ExitCode::from(2);
         }
     };
+    if let Some(path) = &config.origin_overrides {
+        match OriginOverrides::from_toml(path) {
+            Ok(overrides) => overrides.install(),
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::from(2);
+            }
+        }
+    }
 
-    let diagnostics = check_text_with_options(
-        &text,
-        CheckOptions {
-            grammar,
-            punctuation_mode: to_core_punctuation_mode(punctuation_mode),
-            include_noop_heuristics: debug_include_noop_heuristics,
-        },
-    );
+    // Explicit flags win; the config only fills in what was not given.
+    let punctuation_mode = args
+        .punctuation_mode
+        .or(config.punctuation_mode)
+        .unwrap_or(PunctuationModeArg::Strict);
+    let options = CheckOptions {
+        grammar: args.grammar || config.grammar.unwrap_or(false),
+        punctuation_mode: to_core_punctuation_mode(punctuation_mode),
+        include_noop_heuristics: args.debug_include_noop_heuristics,
+        suggest: false,
+        promote_variants_to_errors: args.promote_variants,
+        check_compounds: false,
+        error_categories: config.error_category_set(),
+        variant_spellings: false,
+        namayogi_joins: false,
+    };
+
+    let lexicon = match abbreviation_lexicon(&config.abbreviations) {
+        Ok(lexicon) => lexicon,
+        Err(e) => {
+            eprintln!("error: failed to build abbreviation lexicon: {e}");
+            return ExitCode::from(2);
+        }
+    };
+    let check = |text: &str| {
+        let mut diagnostics = match &lexicon {
+            Some(lex) => check_text_with_kosha(text, options.clone(), lex),
+            None => check_text_with_options(text, options.clone()),
+        };
+        if let Some(min) = config.min_confidence {
+            diagnostics.retain(|d| d.confidence >= min);
+        }
+        diagnostics
+    };
 
-    let line_offsets = build_line_offsets(&text);
+    if args.jsonl {
+        return run_jsonl(args.input, check, args.fail_on_suggestions);
+    }
 
-    match format {
-        OutputFormat::Text => {
-            print_text(&diagnostics, &source_name, &text, &line_offsets, explain);
+    let (source_name, text) = match read_input(args.input) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::from(2);
         }
-        OutputFormat::Json => {
-            print_json(&diagnostics, &text, &line_offsets);
+    };
+
+    let diagnostics = check(&text);
+    let blocking = has_blocking_diagnostics(&diagnostics, args.fail_on_suggestions);
+    let located = locate_diagnostics(&text, diagnostics);
+
+    match args.format {
+        OutputFormat::Text => print_text(&located, &source_name, args.explain),
+        OutputFormat::Json => print_json(&located),
+        OutputFormat::Sarif => print_sarif(&located, &source_name),
+    }
+
+    if blocking {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Stream the input line by line, checking each line as an independent
+/// document and printing its diagnostics as one compact JSON array.
+/// Spans, lines and columns are relative to that line.
+fn run_jsonl(
+    input: Option<String>,
+    check: impl Fn(&str) -> Vec<Diagnostic>,
+    fail_on_suggestions: bool,
+) -> ExitCode {
+    let reader: Box<dyn BufRead> = match input.as_deref() {
+        None | Some("-") => Box::new(std::io::stdin().lock()),
+        Some(path) => match std::fs::File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => {
+                eprintln!("error: {path}: {e}");
+                return ExitCode::from(2);
+            }
+        },
+    };
+
+    let mut blocking = false;
+    for line in reader.lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("error: failed to read input: {e}");
+                return ExitCode::from(2);
+            }
+        };
+        let diagnostics = check(&line);
+        blocking |= has_blocking_diagnostics(&diagnostics, fail_on_suggestions);
+        let entries = json_entries(&locate_diagnostics(&line, diagnostics));
+        match serde_json::to_string(&entries) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("error: failed to serialize diagnostics as JSON: {e}");
+                println!("[]");
+            }
         }
     }
 
-    if has_blocking_diagnostics(&diagnostics, fail_on_suggestions) {
+    if blocking {
         ExitCode::from(1)
     } else {
         ExitCode::SUCCESS
     }
 }
 
+/// Layer configured abbreviations over the embedded lexicon so they are
+/// accepted as known words. Returns `None` when there are none.
+///
+/// The words are leaked to meet the builder's `'static` bound; this runs
+/// once per `check` invocation.
+fn abbreviation_lexicon(abbreviations: &[String]) -> Result<Option<Kosha>, KoshaError> {
+    if abbreviations.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = KoshaBuilder::new();
+    for word in abbreviations {
+        let word: &'static str = word.clone().leak();
+        builder.insert(word, WordEntry { word, pos: "" });
+    }
+    let overlay = builder.build()?;
+    Ok(Some(Kosha::layered(kosha(), overlay)))
+}
+
 fn has_blocking_diagnostics(diagnostics: &[Diagnostic], fail_on_suggestions: bool) -> bool {
     if fail_on_suggestions {
         !diagnostics.is_empty()
@@ -80,6 +202,7 @@ fn to_core_punctuation_mode(mode: PunctuationModeArg) -> PunctuationMode {
     match mode {
         PunctuationModeArg::Strict => PunctuationMode::Strict,
         PunctuationModeArg::NormalizedEditorial => PunctuationMode::NormalizedEditorial,
+        PunctuationModeArg::Lenient => PunctuationMode::Lenient,
     }
 }
 
@@ -100,39 +223,10 @@ fn read_input(input: Option<String>) -> Result<(String, String), String> {
     }
 }
 
-/// Build a sorted list of byte offsets where each line starts.
-/// line_offsets[0] = 0 (line 1 starts at byte 0).
-fn build_line_offsets(text: &str) -> Vec<usize> {
-    let mut offsets = vec![0];
-    for (i, b) in text.bytes().enumerate() {
-        if b == b'\n' {
-            offsets.push(i + 1);
-        }
-    }
-    offsets
-}
-
-/// Convert a byte offset to 1-based (line, column).
-/// Column is character-based (not byte-based) for editor compatibility.
-fn byte_to_line_col(byte_offset: usize, text: &str, line_offsets: &[usize]) -> (usize, usize) {
-    let line_idx = match line_offsets.binary_search(&byte_offset) {
-        Ok(i) => i,
-        Err(i) => i.saturating_sub(1),
-    };
-    let line_start = line_offsets[line_idx];
-    let col = text[line_start..byte_offset].chars().count() + 1;
-    (line_idx + 1, col)
-}
-
-fn print_text(
-    diagnostics: &[Diagnostic],
-    source: &str,
-    text: &str,
-    line_offsets: &[usize],
-    explain: bool,
-) {
-    for diag in diagnostics {
-        let (line, col) = byte_to_line_col(diag.span.0, text, line_offsets);
+fn print_text(diagnostics: &[LocatedDiagnostic], source: &str, explain: bool) {
+    for located in diagnostics {
+        let diag = &located.diagnostic;
+        let (line, col) = (located.line, located.column);
         println!(
             "{source}:{line}:{col}: {}{} \u{2192} {}",
             if matches!(diag.kind, DiagnosticKind::Error) {
@@ -149,14 +243,14 @@ fn print_text(
     }
 }
 
-fn print_json(diagnostics: &[Diagnostic], text: &str, line_offsets: &[usize]) {
-    let entries: Vec<JsonDiagnostic> = diagnostics
+fn json_entries(diagnostics: &[LocatedDiagnostic]) -> Vec<JsonDiagnostic> {
+    diagnostics
         .iter()
-        .map(|diag| {
-            let (line, column) = byte_to_line_col(diag.span.0, text, line_offsets);
+        .map(|located| {
+            let diag = &located.diagnostic;
             JsonDiagnostic {
-                line,
-                column,
+                line: located.line,
+                column: located.column,
                 incorrect: diag.incorrect.clone(),
                 correction: diag.correction.clone(),
                 rule: diag.rule.to_string(),
@@ -166,9 +260,11 @@ fn print_json(diagnostics: &[Diagnostic], text: &str, line_offsets: &[usize]) {
                 confidence: diag.confidence,
             }
         })
-        .collect();
+        .collect()
+}
 
-    match serde_json::to_string_pretty(&entries) {
+fn print_json(diagnostics: &[LocatedDiagnostic]) {
+    match serde_json::to_string_pretty(&json_entries(diagnostics)) {
         Ok(json) => println!("{json}"),
         Err(e) => {
             eprintln!("error: failed to serialize diagnostics as JSON: {e}");
@@ -176,3 +272,58 @@ fn print_json(diagnostics: &[Diagnostic], text: &str, line_offsets: &[usize]) {
         }
     }
 }
+
+/// Emit a minimal SARIF 2.1.0 log: one `result` per diagnostic, located by
+/// line/column (character-based) and by the original byte span.
+fn print_sarif(diagnostics: &[LocatedDiagnostic], source: &str) {
+    let results: Vec<serde_json::Value> = diagnostics
+        .iter()
+        .map(|located| {
+            let diag = &located.diagnostic;
+            serde_json::json!({
+                "ruleId": diag.rule.code(),
+                "level": if matches!(diag.kind, DiagnosticKind::Error) { "error" } else { "note" },
+                "message": {
+                    "text": format!("{} \u{2192} {}: {}", diag.incorrect, diag.correction, diag.explanation),
+                },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": source },
+                        "region": {
+                            "startLine": located.line,
+                            "startColumn": located.column,
+                            "endLine": located.end_line,
+                            "endColumn": located.end_column,
+                            "byteOffset": diag.span.0,
+                            "byteLength": diag.span.1 - diag.span.0,
+                        },
+                    },
+                }],
+                "properties": {
+                    "category": diag.category.as_code(),
+                    "correction": diag.correction,
+                    "confidence": diag.confidence,
+                },
+            })
+        })
+        .collect();
+
+    let log = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "varnavinyas",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    match serde_json::to_string_pretty(&log) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("error: failed to serialize diagnostics as SARIF: {e}"),
+    }
+}
@@ -6,7 +6,9 @@ pub fn run(text: &str, from: &str, to: &str) -> ExitCode {
     let from_scheme = match parse_scheme(from) {
         Some(s) => s,
         None => {
-            eprintln!("error: unknown scheme '{from}'. Supported: devanagari, iast");
+            eprintln!(
+                "error: unknown scheme '{from}'. Supported: devanagari, iast, itrans, phonetic"
+            );
             return ExitCode::from(2);
         }
     };
@@ -14,7 +16,9 @@ pub fn run(text: &str, from: &str, to: &str) -> ExitCode {
     let to_scheme = match parse_scheme(to) {
         Some(s) => s,
         None => {
-            eprintln!("error: unknown scheme '{to}'. Supported: devanagari, iast");
+            eprintln!(
+                "error: unknown scheme '{to}'. Supported: devanagari, iast, itrans, phonetic"
+            );
             return ExitCode::from(2);
         }
     };
@@ -35,6 +39,8 @@ fn parse_scheme(s: &str) -> Option<Scheme> {
     match s.to_ascii_lowercase().as_str() {
         "devanagari" | "deva" => Some(Scheme::Devanagari),
         "iast" => Some(Scheme::Iast),
+        "itrans" => Some(Scheme::Itrans),
+        "phonetic" | "nepali-phonetic" => Some(Scheme::NepaliPhonetic),
         _ => None,
     }
 }
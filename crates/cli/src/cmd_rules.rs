@@ -0,0 +1,46 @@
+use std::process::ExitCode;
+
+use serde::Serialize;
+use varnavinyas_parikshak::diagnostic_catalog;
+
+#[derive(Serialize)]
+struct JsonCatalogEntry {
+    id: &'static str,
+    category: &'static str,
+    category_label: String,
+    kind: &'static str,
+    citation: &'static str,
+    source: &'static str,
+    incorrect: Option<&'static str>,
+    correct: Option<&'static str>,
+    explanation: String,
+}
+
+/// Print the diagnostic catalog as a JSON array, for rules reference pages.
+pub fn run() -> ExitCode {
+    let entries: Vec<JsonCatalogEntry> = diagnostic_catalog()
+        .into_iter()
+        .map(|entry| JsonCatalogEntry {
+            id: entry.id,
+            category: entry.category.as_code(),
+            category_label: entry.category.to_string(),
+            kind: entry.kind.as_code(),
+            citation: entry.citation.code(),
+            source: entry.citation.source_name(),
+            incorrect: entry.example.map(|(incorrect, _)| incorrect),
+            correct: entry.example.map(|(_, correct)| correct),
+            explanation: entry.explanation,
+        })
+        .collect();
+
+    match serde_json::to_string_pretty(&entries) {
+        Ok(json) => {
+            println!("{json}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: failed to serialize rule catalog as JSON: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use varnavinyas_parikshak::DiagnosticCategory;
+
+use crate::PunctuationModeArg;
+
+/// Project-level defaults for `check`, loaded with `--config <path>`.
+///
+/// Files ending in `.json` are parsed as JSON, anything else as TOML:
+///
+/// ```toml
+/// grammar = true
+/// punctuation_mode = "normalized-editorial"
+/// abbreviations = ["नेपा", "प्रा"]
+/// min_confidence = 0.7
+/// origin_overrides = "domain-origins.toml"
+/// error_categories = ["Punctuation", "Halanta"]
+/// ```
+///
+/// Every field is optional. Explicit command-line flags take precedence.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CheckConfig {
+    /// Enable optional grammar/samasa heuristic diagnostics.
+    pub grammar: Option<bool>,
+    /// Section 5 punctuation mode.
+    pub punctuation_mode: Option<PunctuationModeArg>,
+    /// Extra words (abbreviations, acronyms) accepted as correct.
+    pub abbreviations: Vec<String>,
+    /// Drop diagnostics whose confidence is below this value.
+    pub min_confidence: Option<f32>,
+    /// TOML file of word-origin overrides (`शब्द = "Aagantuk"`), relative to
+    /// the config file.
+    pub origin_overrides: Option<PathBuf>,
+    /// Diagnostic category codes enforced as errors; errors in any other
+    /// category are reported as variants. Unset enforces every category.
+    pub error_categories: Option<Vec<String>>,
+}
+
+impl CheckConfig {
+    /// Read and parse a config file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read config '{}': {e}", path.display()))?;
+
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        let parsed = if is_json {
+            serde_json::from_str(&raw).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(&raw).map_err(|e| e.to_string())
+        };
+
+        let mut config: Self =
+            parsed.map_err(|e| format!("invalid config '{}': {e}", path.display()))?;
+        if let Some(codes) = &config.error_categories {
+            if let Some(bad) = codes
+                .iter()
+                .find(|code| DiagnosticCategory::from_code(code).is_none())
+            {
+                return Err(format!(
+                    "invalid config '{}': unknown error category '{bad}'",
+                    path.display()
+                ));
+            }
+        }
+        if let (Some(overrides), Some(dir)) = (&mut config.origin_overrides, path.parent()) {
+            *overrides = dir.join(&*overrides);
+        }
+        Ok(config)
+    }
+
+    /// The configured error categories, parsed.
+    pub fn error_category_set(&self) -> Option<HashSet<DiagnosticCategory>> {
+        self.error_categories.as_ref().map(|codes| {
+            codes
+                .iter()
+                .filter_map(|code| DiagnosticCategory::from_code(code))
+                .collect()
+        })
+    }
+}
@@ -1,8 +1,12 @@
 mod cmd_akshar;
 mod cmd_check;
 mod cmd_lipi;
+mod cmd_rules;
+mod config;
 
 use clap::{Parser, Subcommand, ValueEnum};
+use serde::Deserialize;
+use std::path::PathBuf;
 use std::process::ExitCode;
 
 #[derive(Parser)]
@@ -27,9 +31,9 @@ enum Commands {
         #[arg(long)]
         grammar: bool,
 
-        /// Section 5 punctuation mode
-        #[arg(long, value_enum, default_value = "strict")]
-        punctuation_mode: PunctuationModeArg,
+        /// Section 5 punctuation mode [default: strict]
+        #[arg(long, value_enum)]
+        punctuation_mode: Option<PunctuationModeArg>,
 
         /// Debug: include no-op heuristic suggestions (A -> A)
         #[arg(long)]
@@ -39,15 +43,32 @@ enum Commands {
         #[arg(long)]
         fail_on_suggestions: bool,
 
+        /// Report stylistic variants as errors (strict editorial mode)
+        #[arg(long)]
+        promote_variants: bool,
+
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
         format: OutputFormat,
+
+        /// Load defaults from a TOML or JSON config file
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+
+        /// Check each input line as its own document and print one JSON
+        /// diagnostics array per line (overrides --format)
+        #[arg(long)]
+        jsonl: bool,
     },
 
     /// Analyze Devanagari characters and syllables
     Akshar {
         /// Text to analyze
         text: String,
+
+        /// Print syllables and character counts as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Transliterate between scripts
@@ -63,18 +84,25 @@ enum Commands {
         #[arg(long, default_value = "iast")]
         to: String,
     },
+
+    /// Print the diagnostic rule catalog as JSON
+    Rules,
 }
 
 #[derive(ValueEnum, Clone, Copy)]
 enum OutputFormat {
     Text,
     Json,
+    /// SARIF 2.1.0, for CI annotations and code-review tooling
+    Sarif,
 }
 
-#[derive(ValueEnum, Clone, Copy)]
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
 enum PunctuationModeArg {
     Strict,
     NormalizedEditorial,
+    Lenient,
 }
 
 fn main() -> ExitCode {
@@ -88,20 +116,31 @@ fn main() -> ExitCode {
             punctuation_mode,
             debug_include_noop_heuristics,
             fail_on_suggestions,
+            promote_variants,
             format,
-        } => cmd_check::run(
+            config,
+            jsonl,
+        } => cmd_check::run(cmd_check::CheckArgs {
             input,
             explain,
             grammar,
             punctuation_mode,
             debug_include_noop_heuristics,
             fail_on_suggestions,
+            promote_variants,
             format,
-        ),
-        Commands::Akshar { text } => {
-            cmd_akshar::run(&text);
-            ExitCode::SUCCESS
+            config,
+            jsonl,
+        }),
+        Commands::Akshar { text, json } => {
+            if json {
+                cmd_akshar::run_json(&text)
+            } else {
+                cmd_akshar::run(&text);
+                ExitCode::SUCCESS
+            }
         }
         Commands::Lipi { text, from, to } => cmd_lipi::run(&text, &from, &to),
+        Commands::Rules => cmd_rules::run(),
     }
 }
@@ -58,6 +58,228 @@ fn check_json_returns_valid_json() {
     assert!(arr[0].get("correction").is_some());
 }
 
+#[test]
+fn check_sarif_returns_results_array() {
+    let output = cmd()
+        .args(["check", "--format", "sarif"])
+        .write_stdin("अत्याधिक\n")
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+
+    let sarif: serde_json::Value =
+        serde_json::from_slice(&output).expect("stdout should be valid JSON");
+    assert_eq!(sarif["version"], "2.1.0");
+    let results = sarif["runs"][0]["results"]
+        .as_array()
+        .expect("runs[0].results should be an array");
+    assert!(!results.is_empty());
+    assert_eq!(results[0]["ruleId"], "Section 4");
+    let region = &results[0]["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 1);
+    assert_eq!(region["byteOffset"], 0);
+}
+
+#[test]
+fn check_promote_variants_fails_on_style_variant() {
+    let text = "कार्यक्रमको सम्बन्धमा छलफल भयो।\n";
+
+    cmd()
+        .args(["check", "--grammar"])
+        .write_stdin(text)
+        .assert()
+        .code(0);
+
+    cmd()
+        .args(["check", "--grammar", "--promote-variants"])
+        .write_stdin(text)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("कार्यक्रमका सम्बन्धमा"));
+}
+
+/// Write `contents` to a per-test file under the system temp dir.
+fn temp_config(name: &str, contents: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("varnavinyas-{}-{name}", std::process::id()));
+    std::fs::write(&path, contents).expect("config should be writable");
+    path
+}
+
+#[test]
+fn check_config_enables_grammar() {
+    let text = "कार्यक्रमको सम्बन्धमा छलफल भयो।\n";
+
+    cmd()
+        .args(["check", "--format", "json"])
+        .write_stdin(text)
+        .assert()
+        .stdout(predicate::str::contains("कार्यक्रमका सम्बन्धमा").not());
+
+    let config = temp_config("grammar.toml", "grammar = true\n");
+    let output = cmd()
+        .args(["check", "--format", "json", "--config"])
+        .arg(&config)
+        .write_stdin(text)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&config).ok();
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be valid JSON");
+    let diags = json.as_array().expect("top-level should be an array");
+    assert!(
+        diags.iter().any(|d| d["correction"] == "कार्यक्रमका सम्बन्धमा"),
+        "expected grammar diagnostic from config, got: {json}"
+    );
+}
+
+#[test]
+fn check_config_min_confidence_drops_low_confidence() {
+    // जसपा is an unknown-word guess (0.72); अत्याधिक is a table correction (1.0).
+    let config = temp_config("confidence.json", r#"{ "min_confidence": 0.8 }"#);
+    let output = cmd()
+        .args(["check", "--format", "json", "--config"])
+        .arg(&config)
+        .write_stdin("अत्याधिक जसपा\n")
+        .output()
+        .unwrap();
+    std::fs::remove_file(&config).ok();
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be valid JSON");
+    let incorrect: Vec<_> = json
+        .as_array()
+        .expect("top-level should be an array")
+        .iter()
+        .map(|d| d["incorrect"].as_str().unwrap())
+        .collect();
+    assert_eq!(incorrect, ["अत्याधिक"]);
+}
+
+#[test]
+fn check_config_abbreviations_are_accepted() {
+    cmd()
+        .args(["check"])
+        .write_stdin("जसपा\n")
+        .assert()
+        .stdout(predicate::str::contains("जसपा"));
+
+    let config = temp_config(
+        "abbreviations.json",
+        r#"{ "min_confidence": 0.5, "abbreviations": ["जसपा"] }"#,
+    );
+    cmd()
+        .args(["check", "--config"])
+        .arg(&config)
+        .write_stdin("जसपा\n")
+        .assert()
+        .code(0)
+        .stdout(predicate::str::is_empty());
+    std::fs::remove_file(&config).ok();
+}
+
+#[test]
+fn check_config_error_categories_gate_exit_code() {
+    // भगवान is a halanta error; enforcing only punctuation downgrades it.
+    cmd()
+        .args(["check"])
+        .write_stdin("भगवान आए\n")
+        .assert()
+        .code(1);
+
+    let config = temp_config("categories.toml", "error_categories = [\"Punctuation\"]\n");
+    cmd()
+        .args(["check", "--config"])
+        .arg(&config)
+        .write_stdin("भगवान आए\n")
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("भगवान"));
+    std::fs::remove_file(&config).ok();
+
+    let config = temp_config("bad-categories.toml", "error_categories = [\"Spelling\"]\n");
+    cmd()
+        .args(["check", "--config"])
+        .arg(&config)
+        .write_stdin("नेपाल\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "unknown error category 'Spelling'",
+        ));
+    std::fs::remove_file(&config).ok();
+}
+
+#[test]
+fn check_invalid_config_exits_2() {
+    let config = temp_config("bad.toml", "unknown_key = 1\n");
+    cmd()
+        .args(["check", "--config"])
+        .arg(&config)
+        .write_stdin("नेपाल\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("invalid config"));
+    std::fs::remove_file(&config).ok();
+}
+
+#[test]
+fn check_config_origin_overrides_change_classification() {
+    let overrides = temp_config("origins.toml", "\"रजिस्टर\" = \"Tatsam\"\n");
+    let file_name = overrides.file_name().unwrap().to_str().unwrap();
+    let config = temp_config(
+        "overrides.toml",
+        &format!("origin_overrides = \"{file_name}\"\n"),
+    );
+    let output = cmd()
+        .args(["check", "--format", "json", "--config"])
+        .arg(&config)
+        .write_stdin("रजिष्टर\n")
+        .output()
+        .unwrap();
+    std::fs::remove_file(&config).ok();
+    std::fs::remove_file(&overrides).ok();
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be valid JSON");
+    assert_eq!(json[0]["correction"], "रजिस्टर");
+    assert_eq!(json[0]["rule"], "शुद्ध-अशुद्ध शब्द सूची", "{json}");
+    assert_eq!(json[0]["category"], "शुद्ध-अशुद्ध", "{json}");
+
+    // Without the override the same word is classified as आगन्तुक.
+    let output = cmd()
+        .args(["check", "--format", "json"])
+        .write_stdin("रजिष्टर\n")
+        .output()
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be valid JSON");
+    assert_eq!(json[0]["correction"], "रजिस्टर");
+    assert_eq!(json[0]["rule"], "शुद्ध-अशुद्ध शब्द सूची", "{json}");
+    assert_eq!(json[0]["category"], "आगन्तुक", "{json}");
+}
+
+#[test]
+fn check_invalid_origin_overrides_exits_2() {
+    let overrides = temp_config("bad-origins.toml", "\"रजिस्टर\" = \"English\"\n");
+    let file_name = overrides.file_name().unwrap().to_str().unwrap();
+    let config = temp_config(
+        "bad-overrides.toml",
+        &format!("origin_overrides = \"{file_name}\"\n"),
+    );
+    cmd()
+        .args(["check", "--config"])
+        .arg(&config)
+        .write_stdin("नेपाल\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("invalid origin overrides"));
+    std::fs::remove_file(&config).ok();
+    std::fs::remove_file(&overrides).ok();
+}
+
 #[test]
 fn check_explain_includes_rule() {
     cmd()
@@ -115,6 +337,32 @@ fn check_json_clean_returns_empty_array() {
     assert_eq!(json, serde_json::json!([]));
 }
 
+#[test]
+fn check_jsonl_emits_one_record_per_line() {
+    let output = cmd()
+        .args(["check", "--jsonl"])
+        .write_stdin("नेपाल\nनेपाल अत्याधिक\nनेपाल\n")
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8(output).unwrap();
+    let records: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line should be valid JSON"))
+        .collect();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0], serde_json::json!([]));
+    assert_eq!(records[2], serde_json::json!([]));
+    let middle = records[1].as_array().unwrap();
+    assert!(!middle.is_empty());
+    // Locations are relative to the line itself.
+    assert_eq!(middle[0]["line"], 1);
+    assert_eq!(middle[0]["column"], 7);
+}
+
 // ── akshar subcommand ───────────────────────────────────────────
 
 #[test]
@@ -137,6 +385,23 @@ fn akshar_shows_unicode_codepoints() {
         .stdout(predicate::str::contains("व्यञ्जन"));
 }
 
+#[test]
+fn akshar_json_includes_char_stats() {
+    let output = cmd()
+        .args(["akshar", "--json", "नमस्ते"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).expect("valid JSON");
+    assert_eq!(json["aksharas"], serde_json::json!(["न", "मस्", "ते"]));
+    assert_eq!(json["stats"]["vyanjan"], 4);
+    assert_eq!(json["stats"]["matra"], 1);
+    assert_eq!(json["stats"]["halanta"], 1);
+    assert_eq!(json["stats"]["aksharas"], 3);
+}
+
 // ── lipi subcommand ─────────────────────────────────────────────
 
 #[test]
@@ -166,6 +431,29 @@ fn lipi_invalid_scheme_exits_2() {
         .stderr(predicate::str::contains("unknown scheme"));
 }
 
+// ── rules subcommand ────────────────────────────────────────────
+
+#[test]
+fn rules_prints_catalog_json() {
+    let output = cmd()
+        .arg("rules")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).expect("valid JSON");
+    let entries = json.as_array().expect("array of entries");
+    let plural = entries
+        .iter()
+        .find(|e| e["id"] == "hd-plural-haru")
+        .expect("hd-plural-haru entry");
+    assert_eq!(plural["category"], "HrasvaDirgha");
+    assert_eq!(plural["incorrect"], "केटाहरु");
+    assert_eq!(plural["correct"], "केटाहरू");
+    assert!(entries.iter().any(|e| e["category"] == "Punctuation"));
+}
+
 // ── general ─────────────────────────────────────────────────────
 
 #[test]
@@ -1,4 +1,9 @@
-use fst::SetBuilder;
+use std::collections::{BTreeMap, BTreeSet};
+
+use fst::{Set, SetBuilder};
+
+use crate::KoshaError;
+use crate::kosha::{Kosha, WordEntry};
 
 /// Build an FST Set from a sorted list of words (byte-order sorted).
 pub fn build_fst_set(words: &[&str]) -> Vec<u8> {
@@ -10,3 +15,110 @@ pub fn build_fst_set(words: &[&str]) -> Vec<u8> {
     }
     builder.into_inner().expect("FST build should succeed")
 }
+
+/// Incremental builder for a supplementary lexicon assembled at runtime.
+///
+/// Useful for domain word lists (place names, proper nouns) that are not in
+/// the embedded dictionary. Combine the result with the global lexicon via
+/// [`Kosha::layered`] so the overlay is consulted first.
+///
+/// Entries are `'static` like the embedded data; words loaded from a file at
+/// runtime can be promoted with `String::leak` once at startup.
+#[derive(Debug, Default)]
+pub struct KoshaBuilder {
+    words: BTreeSet<&'static str>,
+    headwords: BTreeMap<&'static str, WordEntry>,
+}
+
+impl KoshaBuilder {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a word form together with its headword metadata.
+    ///
+    /// `word` is added to the membership set; `entry` is indexed by
+    /// `entry.word` for [`Kosha::lookup`]. Inserting the same headword twice
+    /// keeps the most recent entry.
+    pub fn insert(&mut self, word: &'static str, entry: WordEntry) -> &mut Self {
+        self.words.insert(word);
+        self.headwords.insert(entry.word, entry);
+        self
+    }
+
+    /// Number of distinct word forms inserted so far.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether no words have been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Build the lexicon.
+    ///
+    /// Returns [`KoshaError::EmptyLexicon`] when no words were inserted.
+    pub fn build(self) -> Result<Kosha, KoshaError> {
+        if self.words.is_empty() {
+            return Err(KoshaError::EmptyLexicon);
+        }
+
+        // BTreeSet<&str> iterates in byte order, which is what fst requires.
+        let mut builder = SetBuilder::memory();
+        for word in &self.words {
+            builder
+                .insert(word)
+                .map_err(|e| KoshaError::FstBuild(e.to_string()))?;
+        }
+        let fst_bytes = builder
+            .into_inner()
+            .map_err(|e| KoshaError::FstBuild(e.to_string()))?;
+        let fst = Set::new(fst_bytes).map_err(|e| KoshaError::FstBuild(e.to_string()))?;
+
+        Ok(Kosha::from_parts(
+            fst,
+            self.words.into_iter().collect(),
+            self.headwords.into_values().collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_builder_is_rejected() {
+        assert!(matches!(
+            KoshaBuilder::new().build(),
+            Err(KoshaError::EmptyLexicon)
+        ));
+    }
+
+    #[test]
+    fn unsorted_inserts_build_valid_fst() {
+        let mut builder = KoshaBuilder::new();
+        builder
+            .insert(
+                "सुर्खेत",
+                WordEntry {
+                    word: "सुर्खेत",
+                    pos: "ना.",
+                },
+            )
+            .insert(
+                "इलाम",
+                WordEntry {
+                    word: "इलाम",
+                    pos: "ना.",
+                },
+            );
+        let custom = builder.build().expect("non-empty builder should build");
+        assert!(custom.contains("इलाम"));
+        assert!(custom.contains("सुर्खेत"));
+        assert_eq!(custom.word_count(), 2);
+        assert_eq!(custom.headword_count(), 2);
+    }
+}
@@ -42,6 +42,8 @@ pub struct Kosha {
     words: Vec<&'static str>,
     /// Sorted headword entries for binary-search metadata lookup.
     headwords: Vec<WordEntry>,
+    /// Lower layer consulted when a query misses this lexicon.
+    base: Option<&'static Kosha>,
 }
 
 impl Kosha {
@@ -67,16 +69,64 @@ impl Kosha {
             .collect();
         headwords.sort_by(|a, b| a.word.as_bytes().cmp(b.word.as_bytes()));
 
+        Kosha::from_parts(fst, words, headwords)
+    }
+
+    /// Assemble from prebuilt parts. `words` and `headwords` must be byte-sorted.
+    pub(crate) fn from_parts(
+        fst: Set<Vec<u8>>,
+        words: Vec<&'static str>,
+        headwords: Vec<WordEntry>,
+    ) -> Self {
         Kosha {
             fst,
             words,
             headwords,
+            base: None,
+        }
+    }
+
+    /// Stack `overlay` on top of `base`.
+    ///
+    /// Queries consult the overlay first and fall back to `base`, so words
+    /// added through [`KoshaBuilder`](crate::KoshaBuilder) resolve alongside
+    /// the embedded lexicon:
+    ///
+    /// ```
+    /// use varnavinyas_kosha::{Kosha, KoshaBuilder, WordEntry, kosha};
+    ///
+    /// let mut builder = KoshaBuilder::new();
+    /// builder.insert("सुर्खेत", WordEntry { word: "सुर्खेत", pos: "ना." });
+    /// let layered = Kosha::layered(kosha(), builder.build().unwrap());
+    /// assert!(layered.contains("सुर्खेत"));
+    /// assert!(layered.contains("नेपाल"));
+    /// ```
+    ///
+    /// Any base already attached to `overlay` is replaced.
+    pub fn layered(base: &'static Kosha, overlay: Kosha) -> Kosha {
+        Kosha {
+            base: Some(base),
+            ..overlay
         }
     }
 
     /// Check if a word exists in the lexicon.
     pub fn contains(&self, word: &str) -> bool {
-        self.fst.contains(word)
+        self.fst.contains(word) || self.base.is_some_and(|b| b.contains(word))
+    }
+
+    /// Check if a word exists in the lexicon after NFC normalization.
+    ///
+    /// The lexicon is stored in NFC, so a query typed with a precomposed
+    /// nukta letter (U+095C ड़) or a stray ZWJ misses under [`Kosha::contains`].
+    /// This normalizes with [`varnavinyas_akshar::normalize`] first; already
+    /// normalized queries cost a single lookup.
+    pub fn contains_normalized(&self, word: &str) -> bool {
+        if self.contains(word) {
+            return true;
+        }
+        let normalized = varnavinyas_akshar::normalize(word);
+        normalized != word && self.contains(&normalized)
     }
 
     /// Find one near-match candidate by character-level edit distance.
@@ -84,10 +134,51 @@ impl Kosha {
     /// This searches a bounded lexicographic window around the insertion point,
     /// avoiding a full-lexicon scan while keeping Unicode-aware matching.
     pub fn suggest_nearby(&self, word: &str, max_distance: usize) -> Option<String> {
-        if word.is_empty() {
-            return None;
+        self.nearest(word, max_distance, 1).into_iter().next()
+    }
+
+    /// Find up to `limit` near-match candidates, closest first.
+    ///
+    /// Candidates are ranked by character-level edit distance, then byte
+    /// order, across this lexicon and any base layers. Uses the same bounded
+    /// window search as [`Kosha::suggest_nearby`]; an exact match is returned
+    /// at distance 0.
+    pub fn nearest(&self, word: &str, max_distance: usize, limit: usize) -> Vec<String> {
+        self.nearest_with_distance(word, max_distance, limit)
+            .into_iter()
+            .map(|(w, _)| w)
+            .collect()
+    }
+
+    /// [`Kosha::nearest`], keeping each candidate's edit distance.
+    pub fn nearest_with_distance(
+        &self,
+        word: &str,
+        max_distance: usize,
+        limit: usize,
+    ) -> Vec<(String, usize)> {
+        if word.is_empty() || limit == 0 {
+            return Vec::new();
         }
 
+        let mut candidates = Vec::new();
+        self.collect_nearby(word, max_distance, &mut candidates);
+        candidates.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        candidates.dedup_by(|a, b| a.0 == b.0);
+        candidates
+            .into_iter()
+            .take(limit)
+            .map(|(w, dist)| (w.to_string(), dist))
+            .collect()
+    }
+
+    /// Gather `(candidate, distance)` pairs from this layer and those below.
+    fn collect_nearby<'a>(
+        &'a self,
+        word: &str,
+        max_distance: usize,
+        out: &mut Vec<(&'a str, usize)>,
+    ) {
         let idx = self
             .words
             .binary_search_by(|w| w.as_bytes().cmp(word.as_bytes()))
@@ -96,27 +187,21 @@ impl Kosha {
         let start = idx.saturating_sub(WINDOW);
         let end = (idx + WINDOW).min(self.words.len());
 
-        let mut best: Option<(&str, usize)> = None;
+        let wlen = word.chars().count();
         for candidate in &self.words[start..end] {
             let clen = candidate.chars().count();
-            let wlen = word.chars().count();
             if clen.abs_diff(wlen) > max_distance {
                 continue;
             }
 
             if let Some(dist) = bounded_levenshtein_chars(word, candidate, max_distance) {
-                match best {
-                    None => best = Some((candidate, dist)),
-                    Some((best_word, best_dist)) => {
-                        if dist < best_dist || (dist == best_dist && candidate < &best_word) {
-                            best = Some((candidate, dist));
-                        }
-                    }
-                }
+                out.push((candidate, dist));
             }
         }
 
-        best.map(|(w, _)| w.to_string())
+        if let Some(base) = self.base {
+            base.collect_nearby(word, max_distance, out);
+        }
     }
 
     /// Look up headword metadata (POS tags).
@@ -126,16 +211,70 @@ impl Kosha {
             .binary_search_by(|entry| entry.word.as_bytes().cmp(word.as_bytes()))
             .ok()
             .map(|idx| &self.headwords[idx])
+            .or_else(|| self.base.and_then(|b| b.lookup(word)))
+    }
+
+    /// Enumerate every word form with its metadata.
+    ///
+    /// Walks each layer's sorted word list, so words come out in byte order
+    /// and an overlay built with [`KoshaBuilder`](crate::KoshaBuilder) yields
+    /// its own words. A layered
+    /// lexicon yields the overlay first and then each base layer, which means
+    /// the combined sequence is not globally sorted and a word present in
+    /// several layers repeats; the count always equals [`Kosha::len`].
+    ///
+    /// Forms without headword metadata get an entry with an empty `pos`.
+    pub fn iter(&self) -> impl Iterator<Item = (String, WordEntry)> + '_ {
+        self.iter_layers()
+    }
+
+    fn iter_layers(&self) -> Box<dyn Iterator<Item = (String, WordEntry)> + '_> {
+        let own = self.words.iter().map(|&word| {
+            let entry = self
+                .lookup(word)
+                .cloned()
+                .unwrap_or(WordEntry { word, pos: "" });
+            (word.to_string(), entry)
+        });
+        match self.base {
+            Some(base) => Box::new(own.chain(base.iter_layers())),
+            None => Box::new(own),
+        }
     }
 
     /// Number of word forms in the FST.
+    ///
+    /// For a layered lexicon this sums every layer; a word present in more
+    /// than one layer is counted once per layer.
     pub fn word_count(&self) -> usize {
-        self.fst.len()
+        self.fst.len() + self.base.map_or(0, Kosha::word_count)
+    }
+
+    /// Number of word forms; alias of [`Kosha::word_count`].
+    pub fn len(&self) -> usize {
+        self.word_count()
     }
 
-    /// Number of headwords with metadata.
+    /// Whether the lexicon (including any base layer) has no word forms.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Approximate heap bytes owned by this lexicon and its base layers.
+    ///
+    /// Counts the FST bytes and the word/headword index vectors. The word
+    /// strings themselves are borrowed `'static` data (embedded in the
+    /// binary) and are not included.
+    pub fn memory_footprint(&self) -> usize {
+        self.fst.as_fst().as_bytes().len()
+            + self.words.capacity() * size_of::<&'static str>()
+            + self.headwords.capacity() * size_of::<WordEntry>()
+            + self.base.map_or(0, Kosha::memory_footprint)
+    }
+
+    /// Number of headwords with metadata (summed across layers).
     pub fn headword_count(&self) -> usize {
-        self.headwords.len()
+        self.headwords.len() + self.base.map_or(0, Kosha::headword_count)
     }
 
     /// Look up a word's origin from its dictionary metadata tags.
@@ -196,6 +335,14 @@ pub fn with_test_kosha<R>(
     f()
 }
 
+/// Force the global lexicon to load now instead of on first use.
+///
+/// Building the embedded FST takes noticeable time, which otherwise lands on
+/// the first lookup. Long-running servers can call this at startup.
+pub fn warm_up() {
+    LazyLock::force(&KOSHA);
+}
+
 /// Get a reference to the global lexicon singleton.
 pub fn kosha() -> &'static Kosha {
     #[cfg(any(test, feature = "test-seam"))]
@@ -266,6 +413,30 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_nearest_ranks_by_distance_and_limits() {
+        with_test_kosha(
+            "कमल
+कमला
+कलम
+कामल
+घर
+",
+            "कमल	ना.
+",
+            || {
+                let hits = kosha().nearest("कमल", 2, 3);
+                assert_eq!(hits.len(), 3);
+                assert_eq!(hits[0], "कमल");
+                assert!(!hits.contains(&"घर".to_string()));
+                assert!(kosha().nearest("कमल", 2, 0).is_empty());
+                let scored = kosha().nearest_with_distance("कमल", 2, 3);
+                assert_eq!(scored[0], ("कमल".to_string(), 0));
+                assert!(scored.windows(2).all(|w| w[0].1 <= w[1].1));
+            },
+        );
+    }
 }
 
 fn bounded_levenshtein_chars(a: &str, b: &str, max_distance: usize) -> Option<usize> {
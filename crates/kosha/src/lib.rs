@@ -2,9 +2,10 @@ mod builder;
 mod kosha;
 pub mod origin_tag;
 
+pub use builder::KoshaBuilder;
 #[cfg(any(test, feature = "test-seam"))]
 pub use kosha::with_test_kosha;
-pub use kosha::{Kosha, WordEntry, kosha};
+pub use kosha::{Kosha, WordEntry, kosha, warm_up};
 pub use origin_tag::{OriginTag, parse_source_language};
 
 /// Error type for kosha operations.
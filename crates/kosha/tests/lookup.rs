@@ -1,4 +1,4 @@
-use varnavinyas_kosha::{kosha, origin_tag};
+use varnavinyas_kosha::{Kosha, KoshaBuilder, WordEntry, kosha, origin_tag, warm_up};
 
 /// K1: The lexicon contains ~109K word forms.
 #[test]
@@ -64,6 +64,105 @@ fn k5_deterministic_singleton() {
     assert!(std::ptr::eq(k1, k2), "kosha() should return same instance");
 }
 
+/// K6: A runtime overlay resolves its own words while base words still resolve.
+#[test]
+fn k6_layered_overlay_and_base_resolve() {
+    const PLACE: &str = "छिन्ताङगढी";
+    const PERSON: &str = "चाम्लिङनगर";
+    assert!(!kosha().contains(PLACE));
+    assert!(!kosha().contains(PERSON));
+
+    let mut builder = KoshaBuilder::new();
+    builder
+        .insert(
+            PLACE,
+            WordEntry {
+                word: PLACE,
+                pos: "ना.",
+            },
+        )
+        .insert(
+            PERSON,
+            WordEntry {
+                word: PERSON,
+                pos: "ना.",
+            },
+        );
+    let layered = Kosha::layered(kosha(), builder.build().expect("overlay should build"));
+
+    assert!(layered.contains(PLACE));
+    assert!(layered.contains(PERSON));
+    assert_eq!(layered.lookup(PLACE).map(|e| e.pos), Some("ना."));
+    assert!(layered.contains("नेपाल"));
+    assert!(layered.lookup("नेपाल").is_some());
+    assert!(!layered.contains("xyzxyzxyz"));
+    assert_eq!(layered.word_count(), kosha().word_count() + 2);
+}
+
+/// K7: Eager warm-up leaves the singleton usable and reports its size.
+#[test]
+fn k7_warm_up_and_size() {
+    warm_up();
+    let k = kosha();
+    assert!(k.contains("नेपाल"));
+    assert!(!k.is_empty());
+    assert_eq!(k.len(), k.word_count());
+    assert!(k.memory_footprint() > 0);
+}
+
+/// K8: Iteration covers the whole lexicon in byte order.
+#[test]
+fn k8_iter_enumerates_every_word() {
+    let k = kosha();
+    let mut count = 0;
+    let mut found = false;
+    let mut prev = String::new();
+    for (word, entry) in k.iter() {
+        assert!(
+            prev.as_bytes() < word.as_bytes(),
+            "not byte-ordered at {word}"
+        );
+        assert_eq!(entry.word, word);
+        if word == "नेपाल" {
+            found = true;
+        }
+        count += 1;
+        prev = word;
+    }
+    assert!(found, "expected नेपाल in iteration");
+    assert_eq!(count, k.len());
+}
+
+/// K9: Iterating a layered lexicon yields the overlay's own words first.
+#[test]
+fn k9_iter_yields_overlay_words() {
+    let mut builder = KoshaBuilder::new();
+    builder
+        .insert(
+            "सुर्खेत",
+            WordEntry {
+                word: "सुर्खेत",
+                pos: "ना.",
+            },
+        )
+        .insert(
+            "सुर्खेतमा",
+            WordEntry {
+                word: "सुर्खेत",
+                pos: "ना.",
+            },
+        );
+    let layered = Kosha::layered(kosha(), builder.build().unwrap());
+
+    let overlay: Vec<_> = layered.iter().take(2).collect();
+    assert_eq!(overlay[0].0, "सुर्खेत");
+    assert_eq!(overlay[0].1.word, "सुर्खेत");
+    assert_eq!(overlay[0].1.pos, "ना.");
+    assert_eq!(overlay[1].0, "सुर्खेतमा");
+    assert_eq!(overlay[1].1.word, "सुर्खेतमा");
+    assert_eq!(layered.iter().count(), layered.len());
+}
+
 /// Common Nepali words should be in the lexicon.
 #[test]
 fn common_words_present() {
@@ -83,6 +182,21 @@ fn common_words_present() {
     }
 }
 
+/// A non-NFC spelling of a lexicon word is found once normalized.
+#[test]
+fn contains_normalized_matches_non_nfc_query() {
+    let k = kosha();
+    // The lexicon stores जुड़े as ड + nukta; U+095C is its precomposed form,
+    // which NFC decomposes.
+    let stored = "जु\u{0921}\u{093C}े";
+    let precomposed = "जु\u{095C}े";
+    assert!(k.contains(stored));
+    assert!(!k.contains(precomposed));
+    assert!(k.contains_normalized(precomposed));
+    assert!(k.contains_normalized(stored));
+    assert!(!k.contains_normalized("\u{095C}\u{095C}\u{095C}"));
+}
+
 /// Bracket invariant: in headwords.tsv, the **first** `[…]` bracket in each
 /// entry is either:
 ///   (a) a recognized origin tag that `parse_origin_tag` handles, or
@@ -1,6 +1,11 @@
 mod punctuation;
+mod zero_width;
 
-pub use punctuation::{LekhyaDiagnostic, PunctuationMark, check_punctuation};
+pub use punctuation::{
+    LekhyaDiagnostic, PunctuationMark, PunctuationMode, check_punctuation,
+    check_punctuation_with_mode,
+};
+pub use zero_width::check_zero_width;
 
 /// Error type for lekhya operations.
 #[derive(Debug, thiserror::Error)]
@@ -44,22 +44,50 @@ pub struct LekhyaDiagnostic {
     pub rule: &'static str,
 }
 
+/// How strictly Section 5 punctuation is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PunctuationMode {
+    /// Report every Section 5 issue as an error.
+    #[default]
+    Strict,
+    /// Report every Section 5 issue; callers treat them as editorial variants.
+    NormalizedEditorial,
+    /// Report only unambiguous errors (e.g. `.` as a sentence-ender).
+    ///
+    /// Stylistic checks — smart-quote conversion, `/` spacing and ऐजन
+    /// spacing — are skipped. Suited to plain-text contexts where smart
+    /// quotes are undesirable.
+    Lenient,
+}
+
 /// Check text for punctuation issues.
 ///
 /// Detects:
 /// - Y1: Period (.) used as sentence-ender instead of purna viram (।)
 /// - Y2: ASCII double quotes instead of proper Nepali usage
 /// - Y3: Common ASCII punctuation misuse in Devanagari text
+/// - Whitespace: a space before `।` and runs of multiple spaces
 pub fn check_punctuation(text: &str) -> Vec<LekhyaDiagnostic> {
+    check_punctuation_with_mode(text, PunctuationMode::Strict)
+}
+
+/// Check text for punctuation issues under the given [`PunctuationMode`].
+///
+/// `Strict` and `NormalizedEditorial` run the same checks; `Lenient` skips
+/// the stylistic ones.
+pub fn check_punctuation_with_mode(text: &str, mode: PunctuationMode) -> Vec<LekhyaDiagnostic> {
     let mut diagnostics = Vec::new();
 
     check_period_as_sentence_end(text, &mut diagnostics);
     check_ellipsis(text, &mut diagnostics);
-    check_quotes(text, &mut diagnostics);
-    check_tiryak_viram_spacing(text, &mut diagnostics);
-    check_aijan_pair_spacing(text, &mut diagnostics);
+    if mode != PunctuationMode::Lenient {
+        check_quotes(text, &mut diagnostics);
+        check_tiryak_viram_spacing(text, &mut diagnostics);
+        check_aijan_pair_spacing(text, &mut diagnostics);
+    }
     check_parentheses_balance(text, &mut diagnostics);
     check_spacing(text, &mut diagnostics);
+    check_whitespace(text, &mut diagnostics);
 
     // Sort by span start
     diagnostics.sort_by_key(|d| d.span.0);
@@ -111,38 +139,56 @@ fn check_quotes(text: &str, diagnostics: &mut Vec<LekhyaDiagnostic>) {
 
 /// Y2, Y4, Y13, Y14: Check spacing for ?, !, ;, ,
 /// Standard rule: attached to previous word, followed by space.
+///
+/// Any run of spaces or tabs before the mark is reported as one diagnostic.
+/// A missing space after it is reported only when Devanagari follows, and
+/// never inside a digit group (१,०००).
 fn check_spacing(text: &str, diagnostics: &mut Vec<LekhyaDiagnostic>) {
     let chars: Vec<(usize, char)> = text.char_indices().collect();
     for idx in 0..chars.len() {
         let (pos, c) = chars[idx];
-        if matches!(c, '?' | '!' | ';' | ',') && has_devanagari_before_pos(text, pos) {
-            // Check if preceded by space (error)
-            if idx > 0 && chars[idx - 1].1.is_whitespace() {
-                let prev_pos = chars[idx - 1].0;
+        if !is_spacing_mark(c) || !has_devanagari_before_pos(text, pos) {
+            continue;
+        }
+        let end = pos + c.len_utf8();
+
+        // Check if preceded by space (error)
+        let start = text[..pos].trim_end_matches(is_inline_space).len();
+        if start < pos {
+            diagnostics.push(LekhyaDiagnostic {
+                span: (start, end),
+                found: text[start..end].to_string(),
+                expected: c.to_string(),
+                rule: "Section 5: punctuation should attach to the previous word",
+            });
+        }
+
+        // Check if missing space after punctuation in running text.
+        if let Some(&(_, next)) = chars.get(idx + 1) {
+            let prev = idx.checked_sub(1).map(|i| chars[i].1);
+            let digit_group = next.is_numeric() && prev.is_some_and(char::is_numeric);
+            if is_devanagari_char(next) && !is_spacing_exempt_follower(next) && !digit_group {
                 diagnostics.push(LekhyaDiagnostic {
-                    span: (prev_pos, pos + c.len_utf8()),
-                    found: format!(" {}", c),
-                    expected: c.to_string(),
-                    rule: "Section 5: punctuation should attach to the previous word",
+                    span: (pos, end),
+                    found: c.to_string(),
+                    expected: format!("{c} "),
+                    rule: "Section 5: punctuation should be followed by a space",
                 });
             }
-
-            // Check if missing space after punctuation in running text.
-            if idx + 1 < chars.len() {
-                let next = chars[idx + 1].1;
-                if !next.is_whitespace() && !is_spacing_exempt_follower(next) {
-                    diagnostics.push(LekhyaDiagnostic {
-                        span: (pos, pos + c.len_utf8()),
-                        found: c.to_string(),
-                        expected: format!("{c} "),
-                        rule: "Section 5: punctuation should be followed by a space",
-                    });
-                }
-            }
         }
     }
 }
 
+/// Marks handled by [`check_spacing`].
+fn is_spacing_mark(c: char) -> bool {
+    matches!(c, '?' | '!' | ';' | ',')
+}
+
+/// Whitespace within a line (a mark starting a new line is not "spaced").
+fn is_inline_space(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\n' | '\r')
+}
+
 fn is_spacing_exempt_follower(c: char) -> bool {
     matches!(
         c,
@@ -150,6 +196,51 @@ fn is_spacing_exempt_follower(c: char) -> bool {
     )
 }
 
+/// Whitespace normalization: no space before `।`, and no runs of multiple spaces.
+///
+/// Spaces before `?`/`!`/`;`/`,` are reported by [`check_spacing`]; this
+/// pass adds the `।` case and collapses double spaces elsewhere.
+fn check_whitespace(text: &str, diagnostics: &mut Vec<LekhyaDiagnostic>) {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b' ' {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+        let end = i;
+
+        if !(has_devanagari_before_pos(text, start) || has_devanagari_after_pos(text, end)) {
+            continue;
+        }
+        if text[end..].starts_with(is_spacing_mark) && has_devanagari_before_pos(text, end) {
+            continue;
+        }
+
+        if text[end..].starts_with('।') {
+            let danda_end = end + '।'.len_utf8();
+            diagnostics.push(LekhyaDiagnostic {
+                span: (start, danda_end),
+                found: text[start..danda_end].to_string(),
+                expected: "।".to_string(),
+                rule: "Section 5: पूर्णविराम (।) अघिल्लो शब्दसँगै लेखिन्छ",
+            });
+        } else if end - start > 1 {
+            diagnostics.push(LekhyaDiagnostic {
+                span: (start, end),
+                found: text[start..end].to_string(),
+                expected: " ".to_string(),
+                rule: "Section 5: शब्दहरूबीच एउटा मात्र खाली ठाउँ राखिन्छ",
+            });
+        }
+    }
+}
+
 /// Y1: Detect `.` used as sentence-end in Devanagari text instead of `।`.
 ///
 /// A period is flagged when it follows Devanagari text and is either at the
@@ -559,6 +650,33 @@ mod tests {
         assert_eq!(diags[1].expected, "\u{201D}");
     }
 
+    #[test]
+    fn lenient_mode_skips_smart_quotes() {
+        let diags = check_punctuation_with_mode("\"नेपाल\"", PunctuationMode::Lenient);
+        assert!(
+            diags.is_empty(),
+            "Expected no quote diagnostics, got: {diags:?}"
+        );
+    }
+
+    #[test]
+    fn lenient_mode_keeps_period_as_danda() {
+        let diags = check_punctuation_with_mode("\"नेपाल\" राम्रो छ.", PunctuationMode::Lenient);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].found, ".");
+        assert_eq!(diags[0].expected, "।");
+    }
+
+    #[test]
+    fn lenient_mode_skips_slash_and_aijan_spacing() {
+        assert!(check_punctuation_with_mode("तिमी / उहाँ", PunctuationMode::Lenient).is_empty());
+        let diags = check_punctuation_with_mode("राम , , श्याम", PunctuationMode::Lenient);
+        assert!(
+            !diags.iter().any(|d| d.rule.contains("ऐजन")),
+            "Expected no ऐजन spacing diagnostic, got: {diags:?}"
+        );
+    }
+
     #[test]
     fn spacing_detected() {
         let diags = check_punctuation("के छ ?");
@@ -567,6 +685,35 @@ mod tests {
         assert_eq!(diags[0].expected, "?");
     }
 
+    #[test]
+    fn space_run_before_mark_reported_once() {
+        let diags = check_punctuation("के छ  ?");
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(diags[0].found, "  ?");
+        assert_eq!(diags[0].expected, "?");
+
+        let diags = check_punctuation("राम\t, श्याम");
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(diags[0].found, "\t,");
+    }
+
+    #[test]
+    fn missing_space_after_mark_before_devanagari() {
+        for text in ["के छ?अब", "ठीक छ;अब", "राम,श्याम", "वाह!कति"]
+        {
+            let diags = check_punctuation(text);
+            assert!(
+                diags
+                    .iter()
+                    .any(|d| d.rule == "Section 5: punctuation should be followed by a space"),
+                "{text}: {diags:?}"
+            );
+        }
+        // Digit groups and Latin continuations are left alone.
+        assert!(check_punctuation("१,००० रुपैयाँ").is_empty());
+        assert!(check_punctuation("के छ?ok").is_empty());
+    }
+
     #[test]
     fn missing_space_after_question_mark_detected() {
         let diags = check_punctuation("के छ?तिमी");
@@ -580,6 +727,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn space_before_purna_viram_detected() {
+        let diags = check_punctuation("नेपाल ।");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].found, " ।");
+        assert_eq!(diags[0].expected, "।");
+    }
+
+    #[test]
+    fn double_space_detected() {
+        let diags = check_punctuation("राम  श्याम");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].span, (9, 11));
+        assert_eq!(diags[0].expected, " ");
+    }
+
+    #[test]
+    fn double_space_in_latin_text_ignored() {
+        assert!(check_punctuation("hello  world").is_empty());
+    }
+
     #[test]
     fn tiryak_viram_spacing_detected() {
         let diags = check_punctuation("तिमी / उहाँ आउनुहुन्छ।");
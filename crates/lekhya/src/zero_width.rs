@@ -0,0 +1,29 @@
+use crate::LekhyaDiagnostic;
+use varnavinyas_akshar::redundant_zero_width_offsets;
+
+/// Flag stray zero-width joiners (ZWJ U+200D / ZWNJ U+200C) in Devanagari.
+///
+/// Scraped text often carries joiners that break conjunct rendering or word
+/// matching (`क\u{200C}्ष` with ZWNJ before the halanta). Each one is reported with
+/// an empty `expected`, i.e. remove it. Joiners that legitimately control
+/// conjunct formation (after a halanta, eyelash र) are left alone; see
+/// [`varnavinyas_akshar::redundant_zero_width_offsets`].
+pub fn check_zero_width(text: &str) -> Vec<LekhyaDiagnostic> {
+    redundant_zero_width_offsets(text)
+        .into_iter()
+        .map(|offset| {
+            let c = text[offset..].chars().next().unwrap_or_default();
+            let rule = if c == '\u{200D}' {
+                "Unicode: stray ZWJ (U+200D) changes conjunct formation; remove it"
+            } else {
+                "Unicode: stray ZWNJ (U+200C) changes conjunct formation; remove it"
+            };
+            LekhyaDiagnostic {
+                span: (offset, offset + c.len_utf8()),
+                found: c.to_string(),
+                expected: String::new(),
+                rule,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,21 @@
+use varnavinyas_lekhya::check_zero_width;
+
+/// ZWNJ between a consonant and halanta blocks the क्ष conjunct.
+#[test]
+fn zwnj_before_halanta_is_flagged() {
+    let text = "क\u{200C}्षेत्र";
+    let diags = check_zero_width(text);
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].found, "\u{200C}");
+    assert_eq!(diags[0].expected, "");
+    assert_eq!(&text[diags[0].span.0..diags[0].span.1], "\u{200C}");
+    assert!(diags[0].rule.contains("ZWNJ"));
+}
+
+/// ZWJ after halanta requests the half form and is left alone.
+#[test]
+fn zwj_half_form_is_not_flagged() {
+    for text in ["क्\u{200D}ष", "र\u{200D}्य", "नेपाल"] {
+        assert!(check_zero_width(text).is_empty(), "unexpected for {text:?}");
+    }
+}
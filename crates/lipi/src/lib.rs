@@ -5,18 +5,131 @@ mod scheme;
 
 pub use scheme::{LipiError, Scheme};
 
+/// Options for [`transliterate_with_options`].
+///
+/// The default is a faithful, letter-by-letter transliteration of
+/// NFC-normalized input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransliterateOptions {
+    /// Drop inherent vowels that Nepali does not pronounce (राम → rām).
+    ///
+    /// Applies to Devanagari → IAST only. The word-final schwa of a bare
+    /// consonant is removed unless the word is a single syllable or ends in
+    /// a conjunct (मन्त्र → mantra).
+    pub schwa_deletion: bool,
+    /// Keep दण्ड (। and ॥) as-is instead of converting them to `|`/`||`.
+    ///
+    /// Applies to Devanagari → Latin output (IAST, ITRANS, NepaliPhonetic),
+    /// for display text that should keep Devanagari punctuation.
+    pub preserve_danda: bool,
+    /// Copy punctuation through verbatim in both directions.
+    ///
+    /// Implies [`preserve_danda`](Self::preserve_danda); in addition, Latin →
+    /// Devanagari leaves ASCII `|`, `||` and `'` as typed rather than turning
+    /// them into दण्ड or अवग्रह (ऽ).
+    pub preserve_punctuation: bool,
+    /// NFC-normalize Devanagari and IAST input before transliterating
+    /// (default on).
+    ///
+    /// Makes precomposed and decomposed nukta forms (क़ / क + ़) map alike,
+    /// and likewise decomposed IAST letters (ś / s + U+0301); an accent mark
+    /// on an IAST vowel is still read as a Vedic accent. Callers that already ran [`varnavinyas_akshar::normalize`] can turn
+    /// it off to skip the extra pass.
+    pub normalize: bool,
+}
+
+impl Default for TransliterateOptions {
+    fn default() -> Self {
+        Self {
+            schwa_deletion: false,
+            preserve_danda: false,
+            preserve_punctuation: false,
+            normalize: true,
+        }
+    }
+}
+
 /// Transliterate text from one scheme to another.
 pub fn transliterate(input: &str, from: Scheme, to: Scheme) -> Result<String, LipiError> {
+    transliterate_with_options(input, from, to, TransliterateOptions::default())
+}
+
+/// Transliterate text with runtime options.
+pub fn transliterate_with_options(
+    input: &str,
+    from: Scheme,
+    to: Scheme,
+    options: TransliterateOptions,
+) -> Result<String, LipiError> {
     if input.is_empty() {
         return Ok(String::new());
     }
     if from == to {
         return Ok(input.to_string());
     }
-    mapping::transliterate_impl(input, from, to)
+    mapping::transliterate_impl(input, from, to, options)
+}
+
+/// Whether `input` survives a `from → to → from` round trip unchanged.
+///
+/// Both sides are NFC-normalized before comparing. Lossy conversions report
+/// `false`: vowel sequences that merge into a diphthong (कइ → `kai` → कै),
+/// and pairs without a reverse path, such as Devanagari → NepaliPhonetic.
+/// Useful for flagging records in a data pipeline before converting them.
+pub fn is_roundtrip_safe(input: &str, from: Scheme, to: Scheme) -> bool {
+    let original = varnavinyas_akshar::normalize(input);
+    transliterate(&original, from, to)
+        .and_then(|converted| transliterate(&converted, to, from))
+        .is_ok_and(|back| varnavinyas_akshar::normalize(&back) == original)
+}
+
+/// Transliterate a single Devanagari word, marking its sandhi boundary with `-`.
+///
+/// The word is split with [`varnavinyas_sandhi::split`]; when exactly one
+/// split is found, each component is transliterated separately
+/// (सूर्योदय → `sūrya-udaya`). Words with no split, several competing splits,
+/// or a non-Devanagari source scheme are transliterated as a whole.
+///
+/// Requires the `sandhi` feature.
+#[cfg(feature = "sandhi")]
+pub fn transliterate_compound(word: &str, from: Scheme, to: Scheme) -> Result<String, LipiError> {
+    if from == Scheme::Devanagari && from != to {
+        if let [(left, right, _)] = varnavinyas_sandhi::split(word).as_slice() {
+            let left = transliterate(left, from, to)?;
+            let right = transliterate(right, from, to)?;
+            return Ok(format!("{left}-{right}"));
+        }
+    }
+    transliterate(word, from, to)
+}
+
+/// Strict IAST → Devanagari transliteration.
+///
+/// Unlike [`transliterate`], which passes unmapped characters through, this
+/// returns [`LipiError::UnmappedSequence`] with the offending run and its byte
+/// offset when a Latin letter matches no IAST entry (e.g. `x`, `w`); the
+/// nukta letters `q`, `z` and `f` map to क़, ज़ and फ़.
+/// IAST input is case-insensitive, so `Rāma` and `rāma` both convert; an
+/// unmapped run is reported as written. Non-letters such as spaces and
+/// punctuation still pass through. Useful for catching malformed input in
+/// data-cleaning pipelines.
+pub fn iast_to_dev_checked(input: &str) -> Result<String, LipiError> {
+    mapping::iast_to_dev_checked(input)
 }
 
 /// Attempt to detect the scheme of the input text.
+///
+/// Returns the top candidate of [`detect_scheme_ranked`].
 pub fn detect_scheme(input: &str) -> Option<Scheme> {
     scheme::detect_scheme_impl(input)
 }
+
+/// Score each candidate scheme for the input, best first.
+///
+/// Each entry is a scheme and a confidence in `0.0..=1.0`. Plain ASCII is
+/// genuinely ambiguous and yields several candidates; Devanagari or
+/// diacritic-bearing IAST yields a near-certain top entry. Returns an empty
+/// list for text without letters.
+pub fn detect_scheme_ranked(input: &str) -> Vec<(Scheme, f32)> {
+    scheme::detect_scheme_ranked_impl(input)
+}
@@ -1,13 +1,29 @@
 /// Transliteration schemes supported by Varnavinyas.
 ///
 /// Only schemes with implemented transliteration paths are included.
-/// ISO 15919 and informal Nepali romanization will be added in Phase 1.
+/// ISO 15919 will be added in Phase 1.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Scheme {
     /// Devanagari Unicode script.
     Devanagari,
     /// International Alphabet of Sanskrit Transliteration.
+    ///
+    /// Input also reads the ISO 15919 nukta letters: bare `q`, `z` and `f`
+    /// become क़, ज़ and फ़. Earlier versions passed these letters through
+    /// unchanged, so mixed Latin text now converts them too.
     Iast,
+    /// Reader-friendly Nepali romanization without diacritics
+    /// (`sh`, `chh`, `aa`, schwa-deleted), for captions and search.
+    ///
+    /// **One-way only** (Devanagari → NepaliPhonetic); it is lossy and
+    /// cannot be reversed.
+    NepaliPhonetic,
+    /// ITRANS, the ASCII scheme of older digital Sanskrit/Nepali corpora
+    /// (`aa`, `ii`, `sh`, `Sh`, `~N`).
+    ///
+    /// Input accepts the alternate spellings (`A`/`aa`, `Ch`/`chh`,
+    /// `Sh`/`shh`, `x`/`kSh`); output uses one canonical spelling each.
+    Itrans,
     /// Preeti legacy font encoding.
     ///
     /// **Partial, one-way only** (Preeti → Devanagari). Requires `legacy` feature.
@@ -31,54 +47,86 @@ pub enum LipiError {
 
     #[error("unmappable character '{c}' in scheme {scheme:?}")]
     UnmappableChar { c: char, scheme: Scheme },
+
+    #[error("unmapped sequence '{sequence}' at byte {offset} in scheme {scheme:?}")]
+    UnmappedSequence {
+        scheme: Scheme,
+        /// The run of letters that matched no table entry.
+        sequence: String,
+        /// Byte offset of the run in the input.
+        offset: usize,
+    },
 }
 
 /// Attempt to detect the scheme of the input text.
 pub(crate) fn detect_scheme_impl(input: &str) -> Option<Scheme> {
-    if input.is_empty() {
-        return None;
-    }
+    detect_scheme_ranked_impl(input)
+        .first()
+        .map(|&(scheme, _)| scheme)
+}
 
+/// Score every candidate scheme for the input, best first.
+///
+/// Scores are in `0.0..=1.0`: the share of letters written in the scheme's
+/// script, weighted by scheme-specific evidence for Latin text. IAST
+/// diacritics rule out NepaliPhonetic; plain ASCII stays ambiguous and
+/// leans IAST, with doubled vowels or `chh` nudging towards NepaliPhonetic.
+/// Schemes with no evidence are omitted, so text without letters yields an
+/// empty list. ITRANS and legacy font encodings are not detected.
+pub(crate) fn detect_scheme_ranked_impl(input: &str) -> Vec<(Scheme, f32)> {
     let mut devanagari_count = 0u32;
-    let mut ascii_count = 0u32;
+    let mut latin_count = 0u32;
     let mut iast_diacritics = 0u32;
-    let total = input.chars().count() as u32;
+    let mut letters = 0u32;
 
     for c in input.chars() {
         match c {
             '\u{0900}'..='\u{097F}' => devanagari_count += 1,
-            'a'..='z' | 'A'..='Z' => ascii_count += 1,
+            'a'..='z' | 'A'..='Z' => latin_count += 1,
             // IAST diacritics: ā ī ū ṛ ṝ ṃ ḥ ṣ ś ṅ ñ ṭ ḍ ṇ
             'ā' | 'ī' | 'ū' | 'ṛ' | 'ṝ' | 'ṃ' | 'ḥ' | 'ṣ' | 'ś' | 'ṅ' | 'ñ' | 'ṭ' | 'ḍ' | 'ṇ'
             | 'Ā' | 'Ī' | 'Ū' | 'Ṛ' | 'Ṝ' | 'Ṃ' | 'Ḥ' | 'Ṣ' | 'Ś' | 'Ṅ' | 'Ñ' | 'Ṭ' | 'Ḍ' | 'Ṇ' =>
             {
                 iast_diacritics += 1;
-                ascii_count += 1; // also counts as Latin
+                latin_count += 1; // also counts as Latin
             }
-            _ => {}
+            c if c.is_alphabetic() => {}
+            _ => continue,
         }
+        letters += 1;
     }
 
-    if total == 0 {
-        return None;
+    if letters == 0 {
+        return Vec::new();
     }
 
-    // If majority is Devanagari
-    if devanagari_count * 2 > total {
-        return Some(Scheme::Devanagari);
-    }
-
-    // If has IAST diacritics
-    if iast_diacritics > 0 {
-        return Some(Scheme::Iast);
-    }
-
-    // If mostly ASCII
-    if ascii_count * 2 > total {
-        return Some(Scheme::Iast); // default Latin to IAST
-    }
+    let devanagari = devanagari_count as f32 / letters as f32;
+    let latin = latin_count as f32 / letters as f32;
+    let (iast_weight, phonetic_weight) = if iast_diacritics > 0 {
+        (1.0, 0.0)
+    } else if has_phonetic_digraph(input) {
+        (0.55, 0.45)
+    } else {
+        (0.6, 0.4)
+    };
+
+    let mut ranked: Vec<(Scheme, f32)> = [
+        (Scheme::Devanagari, devanagari),
+        (Scheme::Iast, latin * iast_weight),
+        (Scheme::NepaliPhonetic, latin * phonetic_weight),
+    ]
+    .into_iter()
+    .filter(|&(_, score)| score > 0.0)
+    .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
 
-    None
+/// Spellings that NepaliPhonetic produces but IAST never does
+/// (IAST writes long vowels as ā/ī/ū and छ as `ch`).
+fn has_phonetic_digraph(input: &str) -> bool {
+    let lower = input.to_ascii_lowercase();
+    ["aa", "ee", "oo", "chh"].iter().any(|d| lower.contains(d))
 }
 
 #[cfg(test)]
@@ -104,4 +152,35 @@ mod tests {
     fn test_detect_empty() {
         assert_eq!(detect_scheme_impl(""), None);
     }
+
+    #[test]
+    fn test_ranked_devanagari_near_certain() {
+        let ranked = detect_scheme_ranked_impl("नमस्ते संसार।");
+        assert_eq!(ranked[0].0, Scheme::Devanagari);
+        assert!(ranked[0].1 > 0.99, "got {ranked:?}");
+        assert_eq!(ranked.len(), 1);
+    }
+
+    #[test]
+    fn test_ranked_diacritics_favor_iast() {
+        let ranked = detect_scheme_ranked_impl("saṃskṛtaṃ bhāṣā");
+        assert_eq!(ranked, vec![(Scheme::Iast, 1.0)]);
+    }
+
+    #[test]
+    fn test_ranked_plain_ascii_is_ambiguous() {
+        let ranked = detect_scheme_ranked_impl("namaste");
+        let schemes: Vec<Scheme> = ranked.iter().map(|&(s, _)| s).collect();
+        assert_eq!(schemes, vec![Scheme::Iast, Scheme::NepaliPhonetic]);
+        assert!(ranked[0].1 < 1.0);
+
+        let with_digraph = detect_scheme_ranked_impl("kathmaandu");
+        assert!(with_digraph[1].1 > ranked[1].1);
+    }
+
+    #[test]
+    fn test_ranked_without_letters_is_empty() {
+        assert!(detect_scheme_ranked_impl("").is_empty());
+        assert!(detect_scheme_ranked_impl("123 !?").is_empty());
+    }
 }
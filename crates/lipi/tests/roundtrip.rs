@@ -1,3 +1,4 @@
+use unicode_normalization::UnicodeNormalization;
 use varnavinyas_lipi::*;
 
 // =============================================================================
@@ -295,6 +296,166 @@ fn l8_non_target_chars_unchanged() {
     assert_eq!(result, "@#$%");
 }
 
+#[test]
+fn l8_checked_iast_accepts_valid_input() {
+    assert_eq!(
+        iast_to_dev_checked("namaste, nepāla").unwrap(),
+        "नमस्ते, नेपाल"
+    );
+}
+
+#[test]
+fn l8_checked_accepts_nukta_letters() {
+    assert_eq!(
+        iast_to_dev_checked("qalama").unwrap(),
+        varnavinyas_akshar::normalize("क़लम")
+    );
+    assert_eq!(
+        iast_to_dev_checked("fāila").unwrap(),
+        varnavinyas_akshar::normalize("फ़ाइल")
+    );
+    assert!(matches!(
+        iast_to_dev_checked("wa"),
+        Err(LipiError::UnmappedSequence { .. })
+    ));
+}
+
+#[test]
+fn l8_iast_input_is_case_insensitive() {
+    for input in ["namaste", "Namaste", "NAMASTE"] {
+        assert_eq!(
+            transliterate(input, Scheme::Iast, Scheme::Devanagari).unwrap(),
+            "नमस्ते",
+            "{input}"
+        );
+    }
+    assert_eq!(
+        transliterate("Kāṭhamāḍauṃ", Scheme::Iast, Scheme::Devanagari).unwrap(),
+        transliterate("kāṭhamāḍauṃ", Scheme::Iast, Scheme::Devanagari).unwrap()
+    );
+    // Unmapped letters are reported as written.
+    match iast_to_dev_checked("Rāma XWX") {
+        Err(LipiError::UnmappedSequence { sequence, .. }) => assert_eq!(sequence, "XWX"),
+        other => panic!("expected UnmappedSequence, got {other:?}"),
+    }
+}
+
+#[test]
+fn l8_itrans_input_stays_case_sensitive() {
+    assert_ne!(
+        transliterate("Ta", Scheme::Itrans, Scheme::Devanagari).unwrap(),
+        transliterate("ta", Scheme::Itrans, Scheme::Devanagari).unwrap()
+    );
+}
+
+#[test]
+fn l8_checked_iast_rejects_unmapped_cluster() {
+    match iast_to_dev_checked("rāma xwx") {
+        Err(LipiError::UnmappedSequence {
+            scheme,
+            sequence,
+            offset,
+        }) => {
+            assert_eq!(scheme, Scheme::Iast);
+            assert_eq!(sequence, "xwx");
+            assert_eq!(offset, 6);
+        }
+        other => panic!("expected UnmappedSequence, got {other:?}"),
+    }
+    // The lenient path keeps passing the run through.
+    let lenient = transliterate("rāma xwx", Scheme::Iast, Scheme::Devanagari).unwrap();
+    assert!(lenient.ends_with("xwx"));
+}
+
+#[test]
+fn l8_schwa_deletion_option() {
+    let options = TransliterateOptions {
+        schwa_deletion: true,
+        ..Default::default()
+    };
+    let phonetic =
+        transliterate_with_options("राम", Scheme::Devanagari, Scheme::Iast, options).unwrap();
+    assert_eq!(phonetic, "rām");
+    let phonetic =
+        transliterate_with_options("नमस्ते", Scheme::Devanagari, Scheme::Iast, options).unwrap();
+    assert_eq!(phonetic, "namaste");
+    // Default stays faithful.
+    assert_eq!(
+        transliterate("राम", Scheme::Devanagari, Scheme::Iast).unwrap(),
+        "rāma"
+    );
+}
+
+#[test]
+fn l8_preserve_danda_option() {
+    let text = "म घर जान्छु। तिमी आऊ॥";
+    // Default converts दण्ड to pipes.
+    assert_eq!(
+        transliterate(text, Scheme::Devanagari, Scheme::Iast).unwrap(),
+        "ma ghara jānchu| timī āū||"
+    );
+
+    let options = TransliterateOptions {
+        preserve_danda: true,
+        ..Default::default()
+    };
+    for (to, expected) in [
+        (Scheme::Iast, "ma ghara jānchu। timī āū॥"),
+        (Scheme::Itrans, "ma ghara jaanChu। timii aauu॥"),
+        (Scheme::NepaliPhonetic, "ma ghar jaanchhu। timi aau॥"),
+    ] {
+        let out = transliterate_with_options(text, Scheme::Devanagari, to, options).unwrap();
+        assert_eq!(out, expected, "{to:?}");
+    }
+}
+
+#[test]
+fn l8_preserve_punctuation_option() {
+    let options = TransliterateOptions {
+        preserve_punctuation: true,
+        ..Default::default()
+    };
+    // Implies preserve_danda on the Devanagari side.
+    let out =
+        transliterate_with_options("नेपाल।", Scheme::Devanagari, Scheme::Iast, options).unwrap();
+    assert_eq!(out, "nepāla।");
+
+    // Latin pipes and apostrophes stay ASCII instead of दण्ड/अवग्रह.
+    let out = transliterate_with_options(
+        "ghara | so'ham ||",
+        Scheme::Iast,
+        Scheme::Devanagari,
+        options,
+    )
+    .unwrap();
+    assert_eq!(out, "घर | सो'हम् ||");
+    assert_eq!(
+        transliterate("ghara |", Scheme::Iast, Scheme::Devanagari).unwrap(),
+        "घर ।"
+    );
+}
+
+#[test]
+fn l8_nepali_phonetic_output() {
+    let out = transliterate("शहर", Scheme::Devanagari, Scheme::NepaliPhonetic).unwrap();
+    assert_eq!(out, "shahar");
+    let out = transliterate("क्षेत्र", Scheme::Devanagari, Scheme::NepaliPhonetic).unwrap();
+    assert_eq!(out, "kshetra");
+}
+
+#[test]
+fn l8_nepali_phonetic_is_one_way() {
+    for to in [Scheme::Devanagari, Scheme::Iast] {
+        match transliterate("shahar", Scheme::NepaliPhonetic, to) {
+            Err(LipiError::UnsupportedPair { from, to: target }) => {
+                assert_eq!(from, Scheme::NepaliPhonetic);
+                assert_eq!(target, to);
+            }
+            other => panic!("expected UnsupportedPair, got {other:?}"),
+        }
+    }
+}
+
 // =============================================================================
 // L9: Scheme detection
 // =============================================================================
@@ -380,6 +541,8 @@ fn feature_gate_scheme_exhaustive() {
     let _name = match scheme {
         Scheme::Devanagari => "Devanagari",
         Scheme::Iast => "IAST",
+        Scheme::NepaliPhonetic => "NepaliPhonetic",
+        Scheme::Itrans => "ITRANS",
         #[cfg(feature = "legacy")]
         Scheme::Preeti => "Preeti",
         #[cfg(feature = "legacy")]
@@ -390,14 +553,20 @@ fn feature_gate_scheme_exhaustive() {
 #[cfg(not(feature = "legacy"))]
 #[test]
 fn feature_gate_no_legacy_api() {
-    // When legacy is off, the only valid transliteration paths are Dev↔IAST.
+    // When legacy is off, the only valid transliteration paths are Dev↔IAST,
+    // Dev↔ITRANS and Dev→NepaliPhonetic.
     // Any other direction must be unreachable (no variants to construct).
     // This test verifies we can't accidentally call legacy paths.
-    let all_schemes = [Scheme::Devanagari, Scheme::Iast];
+    let all_schemes = [
+        Scheme::Devanagari,
+        Scheme::Iast,
+        Scheme::NepaliPhonetic,
+        Scheme::Itrans,
+    ];
     assert_eq!(
         all_schemes.len(),
-        2,
-        "without legacy, only 2 schemes should exist"
+        4,
+        "without legacy, only 4 schemes should exist"
     );
 }
 
@@ -520,6 +689,95 @@ fn normalization_decomposed_input_regression() {
     );
 }
 
+#[test]
+fn normalization_applied_inside_transliterate() {
+    // Precomposed ज़ (U+095B) and decomposed ज + ़ both come out as z.
+    for input in ["\u{095B}रूर", "\u{091C}\u{093C}रूर"] {
+        assert_eq!(
+            transliterate(input, Scheme::Devanagari, Scheme::Iast).unwrap(),
+            "zarūra"
+        );
+        assert_eq!(
+            transliterate(input, Scheme::Devanagari, Scheme::Itrans).unwrap(),
+            "zaruura"
+        );
+    }
+    assert_eq!(
+        transliterate("\u{095E}ाइल", Scheme::Devanagari, Scheme::NepaliPhonetic).unwrap(),
+        "faail"
+    );
+}
+
+#[test]
+fn nukta_consonants_roundtrip() {
+    // ड़/ढ़ must not collide with vocalic ṛ, and every nukta letter needs a
+    // reverse entry: सड़क once came back as सऋअक and ज़रूर as zअरूर.
+    for word in ["सड़क", "पढ़ाइ", "ज़रूर", "क़लम", "ख़बर", "ग़ज़ल", "फ़ाइल"]
+    {
+        let nfc = varnavinyas_akshar::normalize(word);
+        for scheme in [Scheme::Iast, Scheme::Itrans] {
+            let latin = transliterate(&nfc, Scheme::Devanagari, scheme).unwrap();
+            let back = transliterate(&latin, scheme, Scheme::Devanagari).unwrap();
+            assert_eq!(back, nfc, "{word} → {latin} ({scheme:?})");
+        }
+    }
+    assert_eq!(
+        transliterate("सड़क", Scheme::Devanagari, Scheme::Iast).unwrap(),
+        "sar\u{0324}aka"
+    );
+    assert_eq!(
+        transliterate("पढ़ाइ", Scheme::Devanagari, Scheme::Itrans).unwrap(),
+        "pa.Dhaai"
+    );
+}
+
+#[test]
+fn decomposed_iast_matches_precomposed() {
+    // NFD IAST spells ś as s + U+0301, the same mark as the acute accent.
+    for word in ["शिव", "कृष्ण", "गणेश", "नेपाल"] {
+        let iast = transliterate(word, Scheme::Devanagari, Scheme::Iast).unwrap();
+        let nfd: String = iast.nfd().collect();
+        assert_ne!(nfd, iast, "{word} → {iast} should decompose");
+        let back = transliterate(&nfd, Scheme::Iast, Scheme::Devanagari).unwrap();
+        assert_eq!(back, word, "{word} → {iast} (NFD)");
+        assert_eq!(iast_to_dev_checked(&nfd).unwrap(), word, "{word} (checked)");
+    }
+    // An accent on a vowel is still an accent, decomposed or precomposed.
+    for accented in ["ka\u{0301}", "k\u{00E1}"] {
+        let dev = transliterate(accented, Scheme::Iast, Scheme::Devanagari).unwrap();
+        assert_eq!(dev, "क\u{0954}");
+    }
+}
+
+#[test]
+fn iast_bare_q_z_f_read_as_nukta_consonants() {
+    // These letters used to pass through unchanged; they now map to the
+    // ISO 15919 nukta consonants.
+    for (iast, dev) in [("qalama", "क़लम"), ("zarūra", "ज़रूर"), ("fāila", "फ़ाइल")]
+    {
+        let result = transliterate(iast, Scheme::Iast, Scheme::Devanagari).unwrap();
+        assert_eq!(result, varnavinyas_akshar::normalize(dev), "{iast}");
+    }
+}
+
+#[test]
+fn normalization_can_be_skipped() {
+    let options = TransliterateOptions {
+        normalize: false,
+        ..Default::default()
+    };
+    // Already-normalized input is unaffected by skipping the pass.
+    let nfc = varnavinyas_akshar::normalize("\u{0958}लम");
+    assert_eq!(
+        transliterate_with_options(&nfc, Scheme::Devanagari, Scheme::Iast, options).unwrap(),
+        "qalama"
+    );
+    // Precomposed nukta is then left unmapped.
+    let raw =
+        transliterate_with_options("\u{0958}", Scheme::Devanagari, Scheme::Iast, options).unwrap();
+    assert_eq!(raw, "\u{0958}");
+}
+
 #[test]
 fn normalization_standard_text_unaffected() {
     // Standard Devanagari text (no nukta) is already NFC-stable.
@@ -551,3 +809,174 @@ fn normalization_idempotent_then_transliterate() {
         );
     }
 }
+
+#[test]
+fn l8_extended_devanagari_consonants() {
+    let cases = [
+        ("\u{097B}", "g\u{0308}a"),  // ॻ
+        ("\u{097C}ा", "j\u{0308}ā"), // ॼा
+        ("\u{097E}ि", "ḍ\u{0308}i"), // ॾि
+        ("\u{097F}ु", "b\u{0308}u"),  // ॿु
+        ("\u{0979}", "ẓa"),          // ॹ
+    ];
+    for (dev, iast) in cases {
+        let out = transliterate(dev, Scheme::Devanagari, Scheme::Iast).unwrap();
+        assert_eq!(out, iast, "{dev:?}");
+        assert_eq!(
+            transliterate(&out, Scheme::Iast, Scheme::Devanagari).unwrap(),
+            dev
+        );
+    }
+    // Stable across calls, and no raw Devanagari left in the output.
+    let word = "\u{097B}\u{097C}\u{097E}\u{097F}";
+    let once = transliterate(word, Scheme::Devanagari, Scheme::Iast).unwrap();
+    assert_eq!(
+        once,
+        transliterate(word, Scheme::Devanagari, Scheme::Iast).unwrap()
+    );
+    assert!(!once.chars().any(|c| ('\u{0900}'..='\u{097F}').contains(&c)));
+}
+
+#[cfg(feature = "sandhi")]
+#[test]
+fn l8_compound_boundary_marked() {
+    let out = transliterate_compound("सूर्योदय", Scheme::Devanagari, Scheme::Iast).unwrap();
+    assert_eq!(out, "sūrya-udaya");
+}
+
+#[cfg(feature = "sandhi")]
+#[test]
+fn l8_compound_falls_back_without_split() {
+    for word in ["नमस्ते", "घर"] {
+        let plain = transliterate(word, Scheme::Devanagari, Scheme::Iast).unwrap();
+        let compound = transliterate_compound(word, Scheme::Devanagari, Scheme::Iast).unwrap();
+        assert_eq!(compound, plain, "{word} should not be split");
+    }
+}
+
+// =============================================================================
+// Round-trip safety check
+// =============================================================================
+
+#[test]
+fn roundtrip_safe_for_clean_syllables() {
+    for word in ["नमस्ते", "काठमाडौं", "प्रशासन"] {
+        assert!(
+            is_roundtrip_safe(word, Scheme::Devanagari, Scheme::Iast),
+            "{word} should round-trip"
+        );
+    }
+    assert!(is_roundtrip_safe(
+        "namaste",
+        Scheme::Iast,
+        Scheme::Devanagari
+    ));
+}
+
+#[test]
+fn roundtrip_unsafe_for_merging_vowel_cluster() {
+    // कइ → kai → कै
+    assert!(!is_roundtrip_safe("कइ", Scheme::Devanagari, Scheme::Iast));
+    assert!(!is_roundtrip_safe("अइ", Scheme::Devanagari, Scheme::Iast));
+}
+
+#[test]
+fn roundtrip_unsafe_for_one_way_scheme() {
+    assert!(!is_roundtrip_safe(
+        "नमस्ते",
+        Scheme::Devanagari,
+        Scheme::NepaliPhonetic
+    ));
+}
+
+// =============================================================================
+// ITRANS: canonical round trip and alternate spellings
+// =============================================================================
+
+#[test]
+fn itrans_canonical_output() {
+    let cases = [
+        ("नमस्ते", "namaste"),
+        ("काठमाडौं", "kaaThamaaDauM"),
+        ("छात्र", "Chaatra"),
+        ("शिक्षा", "shikShaa"),
+        ("ऋषि", "RRiShi"),
+        ("दुःख", "duHkha"),
+        ("गाउँ", "gaau.N"),
+        ("वाङ्मय", "vaa~Nmaya"),
+    ];
+    for (dev, itrans) in cases {
+        assert_eq!(
+            transliterate(dev, Scheme::Devanagari, Scheme::Itrans).unwrap(),
+            itrans,
+            "Dev→ITRANS failed for {dev}"
+        );
+    }
+}
+
+#[test]
+fn itrans_roundtrip_canonical_forms() {
+    let texts = [
+        "नमस्ते",
+        "काठमाडौं",
+        "छात्र",
+        "शिक्षा",
+        "ज्ञान",
+        "ऋषि",
+        "पञ्च",
+        "दुःख",
+        "गाउँ",
+        "सोऽहम्",
+        "नेपाल।",
+        "१२३",
+    ];
+    for text in texts {
+        let itrans = transliterate(text, Scheme::Devanagari, Scheme::Itrans).unwrap();
+        let back = transliterate(&itrans, Scheme::Itrans, Scheme::Devanagari).unwrap();
+        assert_eq!(back, text, "roundtrip failed: {text} → {itrans} → {back}");
+    }
+}
+
+#[test]
+fn itrans_accepts_alternate_spellings() {
+    let cases = [
+        ("Chaatra", "छात्र"),
+        ("chhaatra", "छात्र"),
+        ("kAThmAD", "काठ्माड्"),
+        ("nadI", "नदी"),
+        ("nadii", "नदी"),
+        ("nadee", "नदी"),
+        ("bhUmi", "भूमि"),
+        ("R^iShi", "ऋषि"),
+        ("shhaShThi", "षष्ठि"),
+        ("shixaa", "शिक्षा"),
+        ("GYaana", "ज्ञान"),
+        ("j~naana", "ज्ञान"),
+        ("saMsaara", "संसार"),
+        ("sa.nsaara", "संसार"),
+        ("wana", "वन"),
+    ];
+    for (itrans, dev) in cases {
+        assert_eq!(
+            transliterate(itrans, Scheme::Itrans, Scheme::Devanagari).unwrap(),
+            dev,
+            "ITRANS→Dev failed for {itrans}"
+        );
+    }
+}
+
+#[test]
+fn itrans_is_case_sensitive() {
+    assert_eq!(
+        transliterate("TaTa", Scheme::Itrans, Scheme::Devanagari).unwrap(),
+        "टट"
+    );
+    assert_eq!(
+        transliterate("tata", Scheme::Itrans, Scheme::Devanagari).unwrap(),
+        "तत"
+    );
+    assert_eq!(
+        transliterate("ch", Scheme::Itrans, Scheme::Devanagari).unwrap(),
+        "च्"
+    );
+}
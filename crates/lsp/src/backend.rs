@@ -44,6 +44,12 @@ impl Backend {
                 grammar: false,
                 punctuation_mode: config.punctuation_mode.to_core(),
                 include_noop_heuristics: config.debug_include_noop_heuristics,
+                suggest: false,
+                promote_variants_to_errors: config.promote_variants_to_errors,
+                check_compounds: false,
+                error_categories: None,
+                variant_spellings: false,
+                namayogi_joins: false,
             },
         );
 
@@ -329,6 +335,8 @@ mod tests {
             category: DiagnosticCategory::ShuddhaTable,
             kind: varnavinyas_prakriya::DiagnosticKind::Error,
             confidence: 1.0,
+            suggestions: Vec::new(),
+            origin: None,
         };
 
         // Enabled — should find it
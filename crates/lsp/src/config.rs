@@ -8,6 +8,7 @@ pub struct Config {
     pub categories: EnabledCategories,
     pub punctuation_mode: PunctuationModeSetting,
     pub debug_include_noop_heuristics: bool,
+    pub promote_variants_to_errors: bool,
 }
 
 /// How Section 5 punctuation diagnostics should be classified.
@@ -17,6 +18,7 @@ pub enum PunctuationModeSetting {
     #[default]
     Strict,
     NormalizedEditorial,
+    Lenient,
 }
 
 impl PunctuationModeSetting {
@@ -24,6 +26,7 @@ impl PunctuationModeSetting {
         match self {
             Self::Strict => PunctuationMode::Strict,
             Self::NormalizedEditorial => PunctuationMode::NormalizedEditorial,
+            Self::Lenient => PunctuationMode::Lenient,
         }
     }
 }
@@ -42,6 +45,7 @@ pub struct EnabledCategories {
     pub sandhi: bool,
     pub punctuation: bool,
     pub shuddha_table: bool,
+    pub loanword: bool,
 }
 
 impl Default for EnabledCategories {
@@ -57,6 +61,7 @@ impl Default for EnabledCategories {
             sandhi: true,
             punctuation: true,
             shuddha_table: true,
+            loanword: true,
         }
     }
 }
@@ -75,6 +80,7 @@ impl EnabledCategories {
             DiagnosticCategory::Sandhi => self.sandhi,
             DiagnosticCategory::Punctuation => self.punctuation,
             DiagnosticCategory::ShuddhaTable => self.shuddha_table,
+            DiagnosticCategory::Loanword => self.loanword,
         }
     }
 }
@@ -97,6 +103,7 @@ mod tests {
             DiagnosticCategory::Sandhi,
             DiagnosticCategory::Punctuation,
             DiagnosticCategory::ShuddhaTable,
+            DiagnosticCategory::Loanword,
         ] {
             assert!(
                 config.categories.is_enabled(cat),
@@ -132,4 +139,13 @@ mod tests {
         let config = Config::default();
         assert!(!config.debug_include_noop_heuristics);
     }
+
+    #[test]
+    fn promote_variants_to_errors_from_settings() {
+        assert!(!Config::default().promote_variants_to_errors);
+        let config: Config =
+            serde_json::from_value(serde_json::json!({ "promote_variants_to_errors": true }))
+                .unwrap();
+        assert!(config.promote_variants_to_errors);
+    }
 }
@@ -1,6 +1,9 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use varnavinyas_parikshak::{check_text, check_word};
 
+// With `--features cache`, repeated `check_word` calls hit the LRU after the
+// first iteration, so `check_word` measures the lookup rather than derive.
+// Compare against a default build to see the uncached cost.
 fn bench_check_word(c: &mut Criterion) {
     c.bench_function("check_word", |b| {
         b.iter(|| check_word(black_box("राजनैतिक")))
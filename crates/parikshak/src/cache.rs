@@ -0,0 +1,97 @@
+//! Process-wide LRU memos for [`check_word`](crate::check_word) and `derive`.
+//!
+//! Enabled by the `cache` feature. Documents repeat the same stopwords and
+//! common terms many times, and each miss runs the full derive pipeline.
+//! `check_word` entries are keyed on the word string and always reflect the
+//! global lexicon, so callers that swap lexicons in tests should
+//! [`clear_cache`]. `derive` entries are keyed on the NFC-normalized word,
+//! so precomposed and decomposed spellings share one entry.
+
+use std::num::NonZeroUsize;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use lru::LruCache;
+use varnavinyas_prakriya::Prakriya;
+
+use crate::diagnostic::Diagnostic;
+
+/// Number of distinct words retained.
+const CAPACITY: NonZeroUsize = NonZeroUsize::new(4096).unwrap();
+
+static CACHE: LazyLock<Mutex<LruCache<String, Option<Diagnostic>>>> =
+    LazyLock::new(|| Mutex::new(LruCache::new(CAPACITY)));
+
+static DERIVE_CACHE: LazyLock<Mutex<LruCache<String, Prakriya>>> =
+    LazyLock::new(|| Mutex::new(LruCache::new(CAPACITY)));
+
+/// Return the cached result for `word`, computing and storing it on a miss.
+///
+/// The lock is released while `compute` runs so concurrent misses do not
+/// serialize on derive.
+pub(crate) fn get_or_insert_with(
+    word: &str,
+    compute: impl FnOnce() -> Option<Diagnostic>,
+) -> Option<Diagnostic> {
+    if let Some(hit) = lock().get(word) {
+        return hit.clone();
+    }
+
+    let result = compute();
+    lock().put(word.to_string(), result.clone());
+    result
+}
+
+/// Return the cached derivation of `word`, normalizing it first.
+///
+/// On a miss `derive` runs on the normalized word, so the result (including
+/// `Prakriya::input`) is in NFC whichever form the caller passed.
+pub(crate) fn derive_normalized(word: &str, derive: impl FnOnce(&str) -> Prakriya) -> Prakriya {
+    let key = varnavinyas_akshar::normalize(word);
+    if let Some(hit) = lock_derive().get(&key) {
+        return hit.clone();
+    }
+
+    let result = derive(&key);
+    lock_derive().put(key, result.clone());
+    result
+}
+
+/// Drop every cached `check_word` result and derivation.
+pub fn clear_cache() {
+    lock().clear();
+    lock_derive().clear();
+}
+
+fn lock() -> std::sync::MutexGuard<'static, LruCache<String, Option<Diagnostic>>> {
+    CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn lock_derive() -> std::sync::MutexGuard<'static, LruCache<String, Prakriya>> {
+    DERIVE_CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precomposed_and_decomposed_share_derive_entry() {
+        let precomposed = "\u{095B}रुरी"; // ज़ (U+095B)
+        let decomposed = "\u{091C}\u{093C}रुरी"; // ज + nukta
+        let mut computed = 0;
+
+        let first = derive_normalized(precomposed, |w| {
+            computed += 1;
+            varnavinyas_prakriya::derive(w)
+        });
+        let second = derive_normalized(decomposed, |w| {
+            computed += 1;
+            varnavinyas_prakriya::derive(w)
+        });
+
+        assert_eq!(computed, 1, "second form should hit the first entry");
+        assert_eq!(first.input, second.input);
+        assert_eq!(first.output, second.output);
+        assert_eq!(first.input, decomposed);
+    }
+}
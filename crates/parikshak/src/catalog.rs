@@ -0,0 +1,152 @@
+use varnavinyas_prakriya::{DiagnosticKind, Rule, all_rule_specs};
+
+use crate::checker::{CheckOptions, check_text, check_text_with_options, check_word};
+use crate::diagnostic::{Diagnostic, DiagnosticCategory};
+
+/// One row of the rules reference: what a rule is, where it comes from, and
+/// what it looks like when it fires.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogEntry {
+    /// Stable rule id (a `RuleSpec::id`, or a checker-level id such as
+    /// `"punctuation"`).
+    pub id: &'static str,
+    /// Category the checker reports the rule under.
+    pub category: DiagnosticCategory,
+    /// Severity of the rule's diagnostics.
+    pub kind: DiagnosticKind,
+    /// Academy standard citation.
+    pub citation: Rule,
+    /// Worked (incorrect, correct) example, if the rule has one.
+    pub example: Option<(&'static str, &'static str)>,
+    /// Explanation the checker gives for the example, or the citation's
+    /// description when the checker does not produce the example's correction.
+    pub explanation: String,
+}
+
+/// A diagnostic that comes from a checker pass rather than a prakriya
+/// pattern rule, with a sample that triggers it.
+struct CheckerEntry {
+    id: &'static str,
+    category: DiagnosticCategory,
+    /// Sample text and its corrected form.
+    example: (&'static str, &'static str),
+    /// Runs the checker with the options the pass needs.
+    check: fn(&str) -> Vec<Diagnostic>,
+}
+
+const CHECKER_ENTRIES: &[CheckerEntry] = &[
+    CheckerEntry {
+        id: "loanword",
+        category: DiagnosticCategory::Loanword,
+        example: ("रजिष्टर", "रजिस्टर"),
+        check: check_text,
+    },
+    CheckerEntry {
+        id: "punctuation",
+        category: DiagnosticCategory::Punctuation,
+        example: ("नेपाल ।", "नेपाल।"),
+        check: check_text,
+    },
+    CheckerEntry {
+        id: "namayogi-join",
+        category: DiagnosticCategory::ShuddhaTable,
+        example: ("नियम अनुसार", "नियमअनुसार"),
+        check: |text| {
+            let options = CheckOptions {
+                namayogi_joins: true,
+                ..Default::default()
+            };
+            check_text_with_options(text, options)
+        },
+    },
+    CheckerEntry {
+        id: "padabiyog-split",
+        category: DiagnosticCategory::ShuddhaTable,
+        example: ("हुनसक्छ", "हुन सक्छ"),
+        check: check_text,
+    },
+    CheckerEntry {
+        id: "variant-spelling",
+        category: DiagnosticCategory::ShuddhaTable,
+        example: ("शुरु", "सुरु"),
+        check: |text| {
+            let options = CheckOptions {
+                variant_spellings: true,
+                ..Default::default()
+            };
+            check_text_with_options(text, options)
+        },
+    },
+    #[cfg(feature = "grammar-pass")]
+    CheckerEntry {
+        id: "subject-verb-person",
+        category: DiagnosticCategory::ShuddhaTable,
+        example: ("म जान्छ", "म जान्छु"),
+        check: check_with_grammar,
+    },
+    #[cfg(feature = "grammar-pass")]
+    CheckerEntry {
+        id: "honorific-register",
+        category: DiagnosticCategory::ShuddhaTable,
+        example: ("तपाईँ भित्र जा", "तपाईँ भित्र जानुहोस्"),
+        check: check_with_grammar,
+    },
+];
+
+#[cfg(feature = "grammar-pass")]
+fn check_with_grammar(text: &str) -> Vec<Diagnostic> {
+    let options = CheckOptions {
+        grammar: true,
+        ..Default::default()
+    };
+    check_text_with_options(text, options)
+}
+
+/// The full diagnostic catalog: every pattern rule, then the checker-level
+/// diagnostics (loanwords, punctuation, नामयोगी joins, padabiyog splits,
+/// variant spellings and, with `grammar-pass`, grammar agreement).
+///
+/// Unlike `all_rule_specs`, each entry carries the user-facing category and
+/// a sample explanation, so the result can be rendered directly as a rules
+/// reference page.
+pub fn diagnostic_catalog() -> Vec<CatalogEntry> {
+    let mut entries: Vec<CatalogEntry> = all_rule_specs()
+        .into_iter()
+        .map(|spec| {
+            let example = spec.examples.first().copied();
+            let explanation = example
+                .and_then(|(incorrect, correct)| {
+                    check_word(incorrect).filter(|diag| diag.correction == correct)
+                })
+                .map(|diag| diag.explanation)
+                .unwrap_or_else(|| spec.citation.description().to_string());
+            CatalogEntry {
+                id: spec.id,
+                category: DiagnosticCategory::from_rule_category(spec.category),
+                kind: spec.kind,
+                citation: spec.citation,
+                example,
+                explanation,
+            }
+        })
+        .collect();
+
+    for entry in CHECKER_ENTRIES {
+        let (incorrect, correct) = entry.example;
+        if let Some(diag) = (entry.check)(incorrect)
+            .into_iter()
+            .find(|diag| diag.category == entry.category && correct.contains(&diag.correction))
+        {
+            entries.push(CatalogEntry {
+                id: entry.id,
+                category: entry.category,
+                kind: diag.kind,
+                citation: diag.rule,
+                example: Some(entry.example),
+                explanation: diag.explanation,
+            });
+        }
+    }
+
+    entries
+}
@@ -1,14 +1,14 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use varnavinyas_kosha::kosha;
-use varnavinyas_lekhya::check_punctuation;
+use varnavinyas_kosha::{Kosha, kosha};
+pub use varnavinyas_lekhya::PunctuationMode;
+use varnavinyas_lekhya::check_punctuation_with_mode;
 use varnavinyas_prakriya::DiagnosticKind;
-use varnavinyas_prakriya::{Rule, derive};
+use varnavinyas_prakriya::{Prakriya, Rule, RuleContext, derive_with};
+use varnavinyas_shabda::{Origin, OriginDecision, classify_with_provenance};
 
-use crate::diagnostic::{Diagnostic, DiagnosticCategory};
-#[cfg(feature = "grammar-pass")]
-use crate::tokenizer::AnalyzedToken;
-use crate::tokenizer::tokenize_analyzed;
+use crate::diagnostic::{Diagnostic, DiagnosticCategory, LocatedDiagnostic, locate_diagnostics};
+use crate::tokenizer::{AnalyzedToken, tokenize_analyzed_with};
 
 #[cfg(feature = "grammar-pass")]
 const QUANTIFIER_WORDS: &[&str] = &["धेरै", "सबै", "केही", "अनेक", "धेरैजसो"];
@@ -30,6 +30,51 @@ const INTRANSITIVE_VERB_FORMS: &[&str] = &[
 #[cfg(feature = "grammar-pass")]
 const MIN_SUFFIX_HEURISTIC_CONFIDENCE: f32 = 0.80;
 
+/// Lowest samasa score at which an unknown word's split is trusted enough
+/// to check its components.
+#[cfg(feature = "compound-check")]
+const MIN_COMPOUND_SPLIT_SCORE: f32 = 0.75;
+
+/// Honorific pronouns take -नुहुन्छ forms whose ending reads as third person,
+/// so they are left out of plain person agreement.
+#[cfg(feature = "grammar-pass")]
+const HONORIFIC_PRONOUNS: &[&str] = &["तपाईं", "तपाईँ", "हजुर", "उहाँ"];
+
+/// Second-person pronouns and their honorific register (आदरार्थी तह):
+/// 0 = तँ (सामान्य), 1 = तिमी (मध्यम), 2 = तपाईं (उच्च).
+#[cfg(feature = "grammar-pass")]
+const SECOND_PERSON_REGISTERS: &[(&str, usize)] = &[
+    ("तँ", 0),
+    ("तिमी", 1),
+    ("तिमीहरू", 1),
+    ("तिमीहरु", 1),
+    ("तपाईं", 2),
+    ("तपाईँ", 2),
+    ("तपाईंहरू", 2),
+    ("हजुर", 2),
+];
+
+/// Common verb forms indexed by register, matching [`SECOND_PERSON_REGISTERS`].
+#[cfg(feature = "grammar-pass")]
+const HONORIFIC_VERB_FORMS: &[[&str; 3]] = &[
+    // आज्ञार्थ (imperative)
+    ["जा", "जाऊ", "जानुहोस्"],
+    ["आइज", "आऊ", "आउनुहोस्"],
+    ["खा", "खाऊ", "खानुहोस्"],
+    ["बस्", "बस", "बस्नुहोस्"],
+    ["गर्", "गर", "गर्नुहोस्"],
+    ["हेर्", "हेर", "हेर्नुहोस्"],
+    ["भन्", "भन", "भन्नुहोस्"],
+    ["दे", "देऊ", "दिनुहोस्"],
+    ["ले", "लेऊ", "लिनुहोस्"],
+    // वर्तमान (present)
+    ["जान्छस्", "जान्छौ", "जानुहुन्छ"],
+    ["आउँछस्", "आउँछौ", "आउनुहुन्छ"],
+    ["गर्छस्", "गर्छौ", "गर्नुहुन्छ"],
+    ["खान्छस्", "खान्छौ", "खानुहुन्छ"],
+    ["हुन्छस्", "हुन्छौ", "हुनुहुन्छ"],
+];
+
 /// Baseline padayog/padabiyog phrase corrections from Section 3(घ).
 /// This set is intentionally conservative and deterministic.
 const PADAYOG_PHRASE_CORRECTIONS: &[(&str, &str, &str)] = &[
@@ -44,6 +89,58 @@ const PADAYOG_PHRASE_CORRECTIONS: &[(&str, &str, &str)] = &[
     ("भने बमोजिम", "भनेबमोजिम", "नामयोगी जोडेर लेख्नुपर्छ"),
 ];
 
+/// Accepted spellings with a preferred standard form, as
+/// `(variant, preferred)`. Reported only with `CheckOptions::variant_spellings`.
+const VARIANT_SPELLINGS: &[(&str, &str)] = &[
+    ("शुरु", "सुरु"),
+    ("शुरू", "सुरु"),
+    ("शहर", "सहर"),
+    ("दुवै", "दुबै"),
+    ("हफ्ता", "हप्ता"),
+];
+
+/// Confidence for a preferred-spelling suggestion; both forms are in use.
+const VARIANT_SPELLING_CONFIDENCE: f32 = 0.6;
+
+/// Wrongly joined words (पदवियोग), as `(joined, left, right)`.
+/// Each part must itself be a lexicon word for the split to be suggested.
+const PADABIYOG_SPLITS: &[(&str, &str, &str)] = &[
+    ("हुनसक्छ", "हुन", "सक्छ"),
+    ("हुनसक्दैन", "हुन", "सक्दैन"),
+    ("गर्नसक्छ", "गर्न", "सक्छ"),
+    ("गर्नसक्दैन", "गर्न", "सक्दैन"),
+    ("केहीपनि", "केही", "पनि"),
+    ("कोहीपनि", "कोही", "पनि"),
+    ("कुनैपनि", "कुनै", "पनि"),
+    ("कहिल्यैपनि", "कहिल्यै", "पनि"),
+];
+
+/// नामयोगी that attach to a preceding noun or pronoun (घर + तिर → घरतिर).
+/// Used for pairs not covered by [`PADAYOG_PHRASE_CORRECTIONS`].
+///
+/// The flag marks words that also stand alone as adverbs (तपाईँ भित्र जानुहोस्);
+/// those are joined only after a noun, never after a pronoun.
+const NAMAYOGI_POSTPOSITIONS: &[(&str, bool)] = &[
+    ("तिर", true),
+    ("भित्र", true),
+    ("बाहिर", true),
+    ("बाहेक", false),
+    ("अनुसार", false),
+    ("सँग", false),
+    ("भन्दा", false),
+    ("अन्तर्गत", false),
+    ("बमोजिम", false),
+    ("माथि", true),
+    ("मुनि", true),
+    ("नजिक", true),
+    ("पछि", true),
+    ("सम्म", false),
+    ("समेत", false),
+];
+
+/// Confidence for a नामयोगी join found by POS lookup rather than the phrase table.
+const NAMAYOGI_JOIN_CONFIDENCE: f32 = 0.7;
+
 /// Section 4 phrase/sentence-level style variants.
 /// These are guidance suggestions, not hard errors.
 const STYLE_VARIANT_CORRECTIONS: &[(&str, &str, &str)] = &[
@@ -160,15 +257,7 @@ const STYLE_VARIANT_CORRECTIONS: &[(&str, &str, &str)] = &[
 ];
 
 /// Runtime options for `check_text_with_options`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum PunctuationMode {
-    #[default]
-    Strict,
-    NormalizedEditorial,
-}
-
-/// Runtime options for `check_text_with_options`.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CheckOptions {
     /// Enable optional grammar-aware heuristics.
     ///
@@ -178,8 +267,45 @@ pub struct CheckOptions {
     pub punctuation_mode: PunctuationMode,
     /// Debug-only: include heuristic diagnostics that do not change text.
     pub include_noop_heuristics: bool,
+    /// Attach up to three "did you mean" candidates to unknown words.
+    ///
+    /// Widens the near-match search to two edits and fills
+    /// `Diagnostic::suggestions`; the diagnostic stays `Ambiguous`.
+    pub suggest: bool,
+    /// Strict editorial mode: report every `Variant` as an `Error`.
+    ///
+    /// Confidence, rule and category are unchanged; only `kind` is raised.
+    pub promote_variants_to_errors: bool,
+    /// Check the components of unknown compounds and suggest the compound
+    /// rebuilt from corrected components (राजनैतिकदल → राजनीतिकदल).
+    ///
+    /// This only has effect when compiled with the `compound-check` feature.
+    pub check_compounds: bool,
+    /// Categories enforced as errors; `None` enforces all of them.
+    ///
+    /// When set, `Error` diagnostics outside these categories are downgraded
+    /// to `Variant`, e.g. to gate CI on punctuation and halanta only. Applied
+    /// after `promote_variants_to_errors`.
+    pub error_categories: Option<HashSet<DiagnosticCategory>>,
+    /// Flag accepted-but-nonpreferred spellings (शुरु → सुरु) as `Variant`
+    /// suggestions towards the preferred form.
+    pub variant_spellings: bool,
+    /// Suggest joining a noun written apart from a following नामयोगी
+    /// (घर भित्र → घरभित्र) as a low-confidence `Variant`.
+    ///
+    /// Off by default. Table-backed padayog phrases apply either way.
+    pub namayogi_joins: bool,
 }
 
+/// Edit-distance bound for the opt-in suggestion list.
+const SUGGEST_MAX_DISTANCE: usize = 2;
+
+/// Maximum number of candidates attached by the opt-in suggestion list.
+const SUGGEST_LIMIT: usize = 3;
+
+/// Near matches considered before origin ranking trims to [`SUGGEST_LIMIT`].
+const SUGGEST_POOL: usize = 10;
+
 /// Check a single word and return a diagnostic if it's incorrect.
 ///
 /// Pipeline:
@@ -192,13 +318,125 @@ pub struct CheckOptions {
 /// Derive runs first because the sabdasakha lexicon contains observed word
 /// forms (including common misspellings like राजनैतिक). Academy correction
 /// rules are authoritative and must override lexicon presence.
+///
+/// With the `cache` feature, results are memoized in a process-wide LRU.
 pub fn check_word(word: &str) -> Option<Diagnostic> {
+    check_word_with(word, kosha(), &mut OriginCache::default())
+}
+
+/// Check a pre-tokenized word list, one result per input word.
+///
+/// Equivalent to calling [`check_word`] on each element, but the whole batch
+/// shares one lexicon handle and one origin memo, so repeated words are
+/// classified once.
+pub fn check_words<S: AsRef<str>>(words: &[S]) -> Vec<Option<Diagnostic>> {
+    let lex = kosha();
+    let mut origins = OriginCache::default();
+    words
+        .iter()
+        .map(|word| check_word_with(word.as_ref(), lex, &mut origins))
+        .collect()
+}
+
+/// `check_word` sharing origin decisions with the rest of a `check_text` run.
+///
+/// `lex` must be the global [`kosha`]: the LRU is keyed by word alone.
+fn check_word_with(word: &str, lex: &Kosha, origins: &mut OriginCache) -> Option<Diagnostic> {
+    #[cfg(feature = "cache")]
+    {
+        crate::cache::get_or_insert_with(word, || check_word_in(word, lex, false, origins))
+    }
+    #[cfg(not(feature = "cache"))]
+    {
+        check_word_in(word, lex, false, origins)
+    }
+}
+
+/// Origin decisions memoized for one `check_text` invocation.
+///
+/// Origin classification (override table, kosha tag lookup, heuristics) is
+/// the costliest part of `derive` for words that reach the pattern rules.
+/// Running prose repeats the same stems constantly: checking the Academy
+/// notices chapter (`docs/Notices-pages-77-99.md`, ~7,000 tokens) drops from
+/// 6,970 classifications to 2,942 (−58%) once repeats reuse the first decision.
+#[derive(Default)]
+struct OriginCache {
+    decisions: HashMap<String, OriginDecision>,
+    #[cfg(test)]
+    classified: usize,
+}
+
+impl OriginCache {
+    /// Run `derive`, seeding it with a cached origin and keeping any new one.
+    ///
+    /// With the `cache` feature, derivations are also memoized process-wide
+    /// per NFC-normalized word.
+    fn derive(&mut self, word: &str) -> Prakriya {
+        #[cfg(feature = "cache")]
+        {
+            crate::cache::derive_normalized(word, |w| self.derive_uncached(w))
+        }
+        #[cfg(not(feature = "cache"))]
+        {
+            self.derive_uncached(word)
+        }
+    }
+
+    fn derive_uncached(&mut self, word: &str) -> Prakriya {
+        let ctx = match self.decisions.get(word) {
+            Some(&decision) => RuleContext::with_origin(word, decision),
+            None => RuleContext::new(word),
+        };
+        let prakriya = derive_with(word, &ctx);
+        if let Some(&decision) = ctx.computed_origin() {
+            self.insert(word, decision);
+        }
+        prakriya
+    }
+
+    fn origin(&mut self, word: &str) -> Origin {
+        if let Some(decision) = self.decisions.get(word) {
+            return decision.origin;
+        }
+        let decision = classify_with_provenance(word);
+        self.insert(word, decision);
+        decision.origin
+    }
+
+    fn insert(&mut self, word: &str, decision: OriginDecision) {
+        if !self.decisions.contains_key(word) {
+            #[cfg(test)]
+            {
+                self.classified += 1;
+            }
+            self.decisions.insert(word.to_string(), decision);
+        }
+    }
+}
+
+/// `check_word` against an arbitrary lexicon.
+///
+/// Words that `lex` knows but the embedded lexicon does not (custom proper
+/// nouns, domain terms) are accepted as written before any rule runs.
+fn check_word_in(
+    word: &str,
+    lex: &Kosha,
+    suggest: bool,
+    origins: &mut OriginCache,
+) -> Option<Diagnostic> {
     if word.is_empty() {
         return None;
     }
 
+    if !std::ptr::eq(lex, kosha())
+        && lex.contains_normalized(word)
+        && !kosha().contains_normalized(word)
+    {
+        return None;
+    }
+
     // Step 1: Authoritative Academy correction rules always take priority.
-    let prakriya = derive(word);
+    let prakriya = origins.derive(word);
     if !prakriya.is_correct {
         let rule = prakriya
             .steps
@@ -214,6 +452,11 @@ pub fn check_word(word: &str) -> Option<Diagnostic> {
             .category
             .map(DiagnosticCategory::from_rule_category)
             .unwrap_or_else(|| DiagnosticCategory::from_rule(&rule));
+        let (category, origin) = if is_loanword_spelling_fix(word, &prakriya.output, origins) {
+            (DiagnosticCategory::Loanword, Origin::Aagantuk)
+        } else {
+            (category, origins.origin(word))
+        };
 
         return Some(Diagnostic {
             span: (0, word.len()),
@@ -224,6 +467,8 @@ pub fn check_word(word: &str) -> Option<Diagnostic> {
             category,
             kind: prakriya.kind,
             confidence: 1.0,
+            suggestions: Vec::new(),
+            origin: Some(origin),
         });
     }
 
@@ -231,12 +476,29 @@ pub fn check_word(word: &str) -> Option<Diagnostic> {
     // - Known word: confirmed correct.
     // - Unknown + near-match candidate: likely misspelling.
     // - Unknown without near-match: keep unflagged to avoid noisy false positives.
-    let in_lexicon = kosha().contains(word);
+    let in_lexicon = lex.contains_normalized(word);
     if in_lexicon {
         return None;
     }
 
-    if let Some(suggestion) = kosha().suggest_nearby(word, 1) {
+    if suggest {
+        let suggestions = rank_suggestions(word, lex, origins);
+        let correction = suggestions.first()?.clone();
+        return Some(Diagnostic {
+            span: (0, word.len()),
+            incorrect: word.to_string(),
+            correction,
+            rule: Rule::ShuddhaAshuddha("unknown"),
+            explanation: "शब्द शब्दकोशमा भेटिएन; सम्भावित शुद्ध रूपहरू सुझाइएका छन्".to_string(),
+            category: DiagnosticCategory::ShuddhaTable,
+            kind: DiagnosticKind::Ambiguous,
+            confidence: 0.6,
+            suggestions,
+            origin: None,
+        });
+    }
+
+    if let Some(suggestion) = lex.suggest_nearby(word, 1) {
         if suggestion == word {
             return None;
         }
@@ -249,32 +511,195 @@ pub fn check_word(word: &str) -> Option<Diagnostic> {
             category: DiagnosticCategory::ShuddhaTable,
             kind: DiagnosticKind::Ambiguous,
             confidence: 0.72,
+            suggestions: Vec::new(),
+            origin: None,
         });
     }
 
     None
 }
 
+/// Near-match candidates for `word`, best first.
+///
+/// Candidates stay grouped by edit distance; within a distance, those whose
+/// origin matches the input's classified origin come first, so a tadbhav
+/// typo prefers a tadbhav fix. Origin never outranks distance: misspelled
+/// input is mostly classified heuristically, and a closer match is the
+/// stronger signal.
+fn rank_suggestions(word: &str, lex: &Kosha, origins: &mut OriginCache) -> Vec<String> {
+    let origin = origins.origin(word);
+    let mut candidates: Vec<(String, usize, bool)> = lex
+        .nearest_with_distance(word, SUGGEST_MAX_DISTANCE, SUGGEST_POOL)
+        .into_iter()
+        .map(|(candidate, dist)| {
+            let same_origin = origins.origin(&candidate) == origin;
+            (candidate, dist, same_origin)
+        })
+        .collect();
+    candidates.sort_by_key(|&(_, dist, same_origin)| (dist, !same_origin));
+    candidates
+        .into_iter()
+        .take(SUGGEST_LIMIT)
+        .map(|(candidate, _, _)| candidate)
+        .collect()
+}
+
+/// Suggest the preferred form of a word listed in [`VARIANT_SPELLINGS`].
+fn check_variant_spelling(word: &str) -> Option<Diagnostic> {
+    let &(_, preferred) = VARIANT_SPELLINGS
+        .iter()
+        .find(|(variant, _)| *variant == word)?;
+    Some(Diagnostic {
+        span: (0, word.len()),
+        incorrect: word.to_string(),
+        correction: preferred.to_string(),
+        rule: Rule::ShuddhaAshuddha("variant-spelling"),
+        explanation: format!("{word} पनि चलनमा छ, तर {preferred} रूप सिफारिस गरिन्छ"),
+        category: DiagnosticCategory::ShuddhaTable,
+        kind: DiagnosticKind::Variant,
+        confidence: VARIANT_SPELLING_CONFIDENCE,
+        suggestions: Vec::new(),
+        origin: None,
+    })
+}
+
+/// Suggest splitting a word from [`PADABIYOG_SPLITS`] that should be written
+/// as two words (हुनसक्छ → हुन सक्छ).
+fn check_padabiyog(word: &str, lex: &Kosha) -> Option<Diagnostic> {
+    let &(_, left, right) = PADABIYOG_SPLITS.iter().find(|(joined, left, right)| {
+        *joined == word && lex.contains(left) && lex.contains(right)
+    })?;
+    Some(Diagnostic {
+        span: (0, word.len()),
+        incorrect: word.to_string(),
+        correction: format!("{left} {right}"),
+        rule: Rule::VarnaVinyasNiyam("3(घ)"),
+        explanation: "पदयोग/पदवियोग: छुट्टाछुट्टै पद भएकाले अलग लेख्नुपर्छ".to_string(),
+        category: DiagnosticCategory::ShuddhaTable,
+        kind: DiagnosticKind::Error,
+        confidence: 0.9,
+        suggestions: Vec::new(),
+        origin: None,
+    })
+}
+
+/// Check an unknown word as a compound, one component at a time.
+///
+/// Uses the best samasa split when it scores at least
+/// [`MIN_COMPOUND_SPLIT_SCORE`] and sandhi rebuilds the word from it exactly.
+/// If any component has a correction, the corrected components are joined
+/// again with sandhi; the diagnostic cites the first corrected component's
+/// rule and carries the split's score as its confidence.
+#[cfg(feature = "compound-check")]
+fn check_compound(word: &str, lex: &Kosha, origins: &mut OriginCache) -> Option<Diagnostic> {
+    if lex.contains_normalized(word) {
+        return None;
+    }
+    let split = varnavinyas_samasa::analyze_compound(word)
+        .into_iter()
+        .next()
+        .filter(|c| c.score >= MIN_COMPOUND_SPLIT_SCORE)?;
+    let join = |left: &str, right: &str| {
+        varnavinyas_sandhi::apply_or_concat(left, right)
+            .ok()
+            .map(|r| r.output)
+    };
+    if join(&split.left, &split.right).as_deref() != Some(word) {
+        return None;
+    }
+
+    let left = check_word_in(&split.left, lex, false, origins);
+    let right = check_word_in(&split.right, lex, false, origins);
+    let component = left.as_ref().or(right.as_ref())?;
+    let fixed_left = left.as_ref().map_or(split.left.as_str(), |d| &d.correction);
+    let fixed_right = right
+        .as_ref()
+        .map_or(split.right.as_str(), |d| &d.correction);
+    let correction = join(fixed_left, fixed_right)?;
+
+    Some(Diagnostic {
+        span: (0, word.len()),
+        incorrect: word.to_string(),
+        correction,
+        rule: component.rule,
+        explanation: format!(
+            "समासको अंश {} → {}: {}",
+            component.incorrect, component.correction, component.explanation
+        ),
+        category: component.category,
+        kind: component.kind,
+        confidence: split.score,
+        suggestions: Vec::new(),
+        origin: None,
+    })
+}
+
+/// An आगन्तुक correction that only swaps ष→स or ण→न (रजिष्टर → रजिस्टर).
+fn is_loanword_spelling_fix(incorrect: &str, correction: &str, origins: &mut OriginCache) -> bool {
+    if incorrect.chars().count() != correction.chars().count() || incorrect == correction {
+        return false;
+    }
+    let only_loan_swaps = incorrect
+        .chars()
+        .zip(correction.chars())
+        .all(|pair| pair.0 == pair.1 || matches!(pair, ('ष', 'स') | ('ण', 'न')));
+    only_loan_swaps && origins.origin(correction) == Origin::Aagantuk
+}
+
 /// Check full text with runtime options.
 pub fn check_text_with_options(text: &str, options: CheckOptions) -> Vec<Diagnostic> {
+    check_text_with_kosha(text, options, kosha())
+}
+
+/// Check full text against a caller-supplied lexicon.
+///
+/// Typically `lex` is a [`Kosha::layered`] overlay on top of the embedded
+/// lexicon. Words found only in the overlay are treated as correct, so
+/// injected proper nouns are not rewritten by hrasva/dirgha or halanta rules.
+pub fn check_text_with_kosha(text: &str, options: CheckOptions, lex: &Kosha) -> Vec<Diagnostic> {
     let mut diagnostics = Vec::new();
     let mut blocked_spans: HashSet<(usize, usize)> = HashSet::new();
+    let mut origins = OriginCache::default();
 
     // Word-level checks (suffix-aware: checks stem, spans full token)
-    let tokens = tokenize_analyzed(text);
-    let lex = kosha();
+    let tokens = tokenize_analyzed_with(text, lex);
     for token in &tokens {
+        let variant = if options.variant_spellings {
+            check_variant_spelling(&token.stem)
+        } else {
+            None
+        };
+
         // If the full token (stem+suffix) is a known word, skip correction.
         // e.g. "संसदमा" = संसद + मा — the stem "संसद" triggers a halanta rule,
         // but the agglutinative form "संसदमा" is a valid word in the lexicon.
-        if let Some(ref sfx) = token.suffix {
+        if let (None, Some(sfx)) = (&variant, &token.suffix) {
             let full = format!("{}{}", token.stem, sfx);
             if lex.contains(&full) {
                 continue;
             }
         }
 
-        if let Some(mut diag) = check_word(&token.stem) {
+        if let Some(mut diag) = check_padabiyog(&text[token.start..token.end], lex) {
+            diag.span = (token.start, token.end);
+            blocked_spans.insert(diag.span);
+            diagnostics.push(diag);
+            continue;
+        }
+
+        let checked = if variant.is_some() {
+            variant
+        } else if std::ptr::eq(lex, kosha()) && !options.suggest {
+            check_word_with(&token.stem, lex, &mut origins)
+        } else {
+            check_word_in(&token.stem, lex, options.suggest, &mut origins)
+        };
+        #[cfg(feature = "compound-check")]
+        let checked = match checked {
+            None if options.check_compounds => check_compound(&token.stem, lex, &mut origins),
+            checked => checked,
+        };
+        if let Some(mut diag) = checked {
             diag.span = (token.start, token.end);
 
             // If a suffix was detached, reattach it to the diagnostic strings.
@@ -283,6 +708,9 @@ pub fn check_text_with_options(text: &str, options: CheckOptions) -> Vec<Diagnos
             if let Some(ref sfx) = token.suffix {
                 diag.incorrect.push_str(sfx);
                 diag.correction.push_str(sfx);
+                for suggestion in &mut diag.suggestions {
+                    suggestion.push_str(sfx);
+                }
             }
 
             if !matches!(diag.kind, DiagnosticKind::Ambiguous) {
@@ -293,6 +721,9 @@ pub fn check_text_with_options(text: &str, options: CheckOptions) -> Vec<Diagnos
     }
 
     add_padayog_phrase_diagnostics(text, &mut blocked_spans, &mut diagnostics);
+    if options.namayogi_joins {
+        add_namayogi_join_diagnostics(text, &tokens, lex, &mut blocked_spans, &mut diagnostics);
+    }
 
     if options.grammar {
         add_style_variant_diagnostics(text, &mut blocked_spans, &mut diagnostics);
@@ -300,20 +731,20 @@ pub fn check_text_with_options(text: &str, options: CheckOptions) -> Vec<Diagnos
 
     #[cfg(feature = "grammar-pass")]
     if options.grammar {
-        add_grammar_diagnostics(&tokens, &blocked_spans, &mut diagnostics);
+        add_grammar_diagnostics(text, &tokens, lex, &blocked_spans, &mut diagnostics);
     }
 
     // Punctuation checks
     let punctuation_kind = match options.punctuation_mode {
-        PunctuationMode::Strict => DiagnosticKind::Error,
+        PunctuationMode::Strict | PunctuationMode::Lenient => DiagnosticKind::Error,
         PunctuationMode::NormalizedEditorial => DiagnosticKind::Variant,
     };
     let punctuation_confidence = match options.punctuation_mode {
-        PunctuationMode::Strict => 1.0,
+        PunctuationMode::Strict | PunctuationMode::Lenient => 1.0,
         PunctuationMode::NormalizedEditorial => 0.72,
     };
 
-    for lekhya_diag in check_punctuation(text) {
+    for lekhya_diag in check_punctuation_with_mode(text, options.punctuation_mode) {
         diagnostics.push(Diagnostic {
             span: lekhya_diag.span,
             incorrect: lekhya_diag.found,
@@ -323,6 +754,8 @@ pub fn check_text_with_options(text: &str, options: CheckOptions) -> Vec<Diagnos
             category: DiagnosticCategory::Punctuation,
             kind: punctuation_kind,
             confidence: punctuation_confidence,
+            suggestions: Vec::new(),
+            origin: None,
         });
     }
 
@@ -330,6 +763,26 @@ pub fn check_text_with_options(text: &str, options: CheckOptions) -> Vec<Diagnos
         diagnostics.retain(|d| !is_noop_heuristic_diagnostic(d));
     }
 
+    // Resolve on the checker's own severities, before the caller's
+    // promote/downgrade options reclassify them.
+    let mut diagnostics = resolve_overlaps(diagnostics);
+
+    if options.promote_variants_to_errors {
+        for diag in &mut diagnostics {
+            if matches!(diag.kind, DiagnosticKind::Variant) {
+                diag.kind = DiagnosticKind::Error;
+            }
+        }
+    }
+
+    if let Some(enforced) = &options.error_categories {
+        for diag in &mut diagnostics {
+            if matches!(diag.kind, DiagnosticKind::Error) && !enforced.contains(&diag.category) {
+                diag.kind = DiagnosticKind::Variant;
+            }
+        }
+    }
+
     diagnostics.sort_by_key(|d| d.span.0);
     diagnostics
 }
@@ -370,12 +823,88 @@ fn add_padayog_phrase_diagnostics(
                 category: DiagnosticCategory::ShuddhaTable,
                 kind: DiagnosticKind::Error,
                 confidence: 0.95,
+                suggestions: Vec::new(),
+                origin: None,
             });
             blocked_spans.insert(span);
         }
     }
 }
 
+/// Flag a noun or pronoun written apart from a following नामयोगी.
+///
+/// The preceding word must be a kosha headword tagged ना./सर्व. and not a
+/// verb, so verb forms such as भन्दा (जान्छु भन्दा) are left alone.
+fn add_namayogi_join_diagnostics(
+    text: &str,
+    tokens: &[AnalyzedToken],
+    lex: &Kosha,
+    blocked_spans: &mut HashSet<(usize, usize)>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for pair in tokens.windows(2) {
+        let (word, next) = (&pair[0], &pair[1]);
+        let head = &text[word.start..word.end];
+        let postposition = &text[next.start..next.end];
+        let Some(&(_, adverbial)) = NAMAYOGI_POSTPOSITIONS
+            .iter()
+            .find(|(p, _)| *p == postposition)
+        else {
+            continue;
+        };
+        // A single run of spaces, not punctuation or a line break.
+        let gap = &text[word.end..next.start];
+        if gap.is_empty() || gap.chars().any(|c| !c.is_whitespace() || c == '\n') {
+            continue;
+        }
+        match nominal_kind(head, lex) {
+            Some(Nominal::Noun) => {}
+            Some(Nominal::Pronoun) if !adverbial => {}
+            _ => continue,
+        }
+
+        let span = (word.start, next.end);
+        if blocked_spans.contains(&span) || overlaps_existing_span(diagnostics, span) {
+            continue;
+        }
+
+        diagnostics.push(Diagnostic {
+            span,
+            incorrect: text[span.0..span.1].to_string(),
+            correction: format!("{head}{postposition}"),
+            rule: Rule::VarnaVinyasNiyam("3(घ)"),
+            explanation: "पदयोग/पदवियोग: नामयोगी जोडेर लेख्नुपर्छ".to_string(),
+            category: DiagnosticCategory::ShuddhaTable,
+            kind: DiagnosticKind::Variant,
+            confidence: NAMAYOGI_JOIN_CONFIDENCE,
+            suggestions: Vec::new(),
+            origin: None,
+        });
+        blocked_spans.insert(span);
+    }
+}
+
+enum Nominal {
+    Noun,
+    Pronoun,
+}
+
+/// Whether the kosha tags `word` as a noun or pronoun (and not also a verb).
+fn nominal_kind(word: &str, lex: &Kosha) -> Option<Nominal> {
+    let pos = lex.lookup(word)?.pos.trim();
+    if pos.contains("क्रि") {
+        return None;
+    }
+    if pos.starts_with("सर्व") {
+        return Some(Nominal::Pronoun);
+    }
+    let postposition = ["ना.यो", "ना. यो", "नामयोगी"]
+        .iter()
+        .any(|t| pos.starts_with(t));
+    let noun = pos.starts_with("ना.") || pos.starts_with("नाम");
+    (noun && !postposition).then_some(Nominal::Noun)
+}
+
 fn add_style_variant_diagnostics(
     text: &str,
     blocked_spans: &mut HashSet<(usize, usize)>,
@@ -402,6 +931,8 @@ fn add_style_variant_diagnostics(
                 category: DiagnosticCategory::ShuddhaTable,
                 kind: DiagnosticKind::Variant,
                 confidence: 0.78,
+                suggestions: Vec::new(),
+                origin: None,
             });
             blocked_spans.insert(span);
         }
@@ -415,6 +946,46 @@ fn overlaps_existing_span(diagnostics: &[Diagnostic], candidate: (usize, usize))
         .any(|d| d.span.0 < candidate.1 && candidate.0 < d.span.1)
 }
 
+/// Among overlapping diagnostics keep one: `Error` over `Variant`, then the
+/// higher confidence, then the earlier-produced (word-level before phrase).
+///
+/// `Ambiguous` diagnostics are non-blocking and always kept, as are
+/// punctuation diagnostics: lekhya may report several distinct findings on
+/// the same mark (spacing before and after it).
+fn resolve_overlaps(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    fn severity(kind: DiagnosticKind) -> u8 {
+        match kind {
+            DiagnosticKind::Error => 2,
+            DiagnosticKind::Variant => 1,
+            DiagnosticKind::Ambiguous => 0,
+        }
+    }
+
+    let mut ranked: Vec<(usize, Diagnostic)> = diagnostics.into_iter().enumerate().collect();
+    ranked.sort_by(|(ia, a), (ib, b)| {
+        severity(b.kind)
+            .cmp(&severity(a.kind))
+            .then(b.confidence.total_cmp(&a.confidence))
+            .then(ia.cmp(ib))
+    });
+
+    let mut kept: Vec<(usize, Diagnostic)> = Vec::with_capacity(ranked.len());
+    let competes = |d: &Diagnostic| {
+        !is_non_blocking_diagnostic(d) && d.category != DiagnosticCategory::Punctuation
+    };
+    for (order, diag) in ranked {
+        let blocked = competes(&diag)
+            && kept
+                .iter()
+                .any(|(_, k)| competes(k) && k.span.0 < diag.span.1 && diag.span.0 < k.span.1);
+        if !blocked {
+            kept.push((order, diag));
+        }
+    }
+    kept.sort_by_key(|&(order, _)| order);
+    kept.into_iter().map(|(_, d)| d).collect()
+}
+
 fn is_non_blocking_diagnostic(d: &Diagnostic) -> bool {
     matches!(d.kind, DiagnosticKind::Ambiguous)
 }
@@ -458,6 +1029,9 @@ fn is_boundary_char(c: char) -> bool {
                 | '\''
                 | '/'
                 | '।'
+                | '॥'
+                | '॰'
+                | '\u{200B}'
                 | '…'
         )
 }
@@ -473,44 +1047,79 @@ pub fn check_text(text: &str) -> Vec<Diagnostic> {
     check_text_with_options(text, CheckOptions::default())
 }
 
+/// Check a whole document and report each diagnostic with its line/column.
+///
+/// Same diagnostics as [`check_text`]; see [`locate_diagnostics`] for how
+/// positions are counted.
+pub fn check_document(text: &str) -> Vec<LocatedDiagnostic> {
+    locate_diagnostics(text, check_text(text))
+}
+
+/// Check `new` and return only diagnostics that were not already present in `old`.
+///
+/// Diagnostics are matched on `(incorrect, correction, rule code)` rather than
+/// span, so edits that shift text around do not re-flag untouched errors. A
+/// pair occurring more often in `new` than in `old` reports only the surplus
+/// (the later occurrences in text order).
+pub fn check_text_diff(old: &str, new: &str) -> Vec<Diagnostic> {
+    let mut seen: HashMap<(String, String, &'static str), usize> = HashMap::new();
+    for diag in check_text(old) {
+        *seen
+            .entry((diag.incorrect, diag.correction, diag.rule.code()))
+            .or_default() += 1;
+    }
+
+    let mut fresh = Vec::new();
+    for diag in check_text(new) {
+        let key = (
+            diag.incorrect.clone(),
+            diag.correction.clone(),
+            diag.rule.code(),
+        );
+        match seen.get_mut(&key) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => fresh.push(diag),
+        }
+    }
+    fresh
+}
+
 #[cfg(feature = "grammar-pass")]
 fn add_grammar_diagnostics(
+    text: &str,
     tokens: &[AnalyzedToken],
+    lex: &Kosha,
     blocked_spans: &HashSet<(usize, usize)>,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
-    use varnavinyas_vyakaran::MorphAnalyzer;
-
-    let analyzer = varnavinyas_vyakaran::RuleBasedAnalyzer;
-
     for (idx, token) in tokens.iter().enumerate() {
         let span = (token.start, token.end);
-        if blocked_spans.contains(&span) {
+        if overlaps_blocked(blocked_spans, span) {
             continue;
         }
 
         let full = token_full_form(token);
 
-        if let Ok(analyses) = analyzer.analyze(&full) {
-            if analyses.len() > 1 {
-                diagnostics.push(Diagnostic {
-                    span,
-                    incorrect: full.clone(),
-                    correction: full.clone(),
-                    rule: Rule::Vyakaran("morph-ambiguity"),
-                    explanation: "व्याकरण विश्लेषण अस्पष्ट: एकभन्दा बढी सम्भावित संरचना".to_string(),
-                    category: DiagnosticCategory::ShuddhaTable,
-                    kind: DiagnosticKind::Ambiguous,
-                    confidence: 0.55,
-                });
-            }
+        if has_unresolved_suffix(token) {
+            diagnostics.push(Diagnostic {
+                span,
+                incorrect: full.clone(),
+                correction: full.clone(),
+                rule: Rule::Vyakaran("morph-ambiguity"),
+                explanation: "व्याकरण विश्लेषण अस्पष्ट: एकभन्दा बढी सम्भावित संरचना".to_string(),
+                category: DiagnosticCategory::ShuddhaTable,
+                kind: DiagnosticKind::Ambiguous,
+                confidence: 0.55,
+                suggestions: Vec::new(),
+                origin: None,
+            });
         }
 
-        if has_plural_suffix(&full) && idx > 0 && is_quantifier(&token_full_form(&tokens[idx - 1]))
-        {
+        if is_plural_noun(token) && idx > 0 && is_quantifier(&token_full_form(&tokens[idx - 1])) {
             let confidence = 0.62;
             if confidence >= MIN_SUFFIX_HEURISTIC_CONFIDENCE {
-                let singular = strip_plural_suffix(&full).unwrap_or(&full).to_string();
+                let singular =
+                    varnavinyas_vyakaran::singularize(&full).unwrap_or_else(|| full.clone());
                 push_best_grammar_variant(
                     diagnostics,
                     Diagnostic {
@@ -523,6 +1132,8 @@ fn add_grammar_diagnostics(
                         category: DiagnosticCategory::ShuddhaTable,
                         kind: DiagnosticKind::Variant,
                         confidence,
+                        suggestions: Vec::new(),
+                        origin: None,
                     },
                 );
             }
@@ -542,6 +1153,8 @@ fn add_grammar_diagnostics(
                         category: DiagnosticCategory::ShuddhaTable,
                         kind: DiagnosticKind::Variant,
                         confidence,
+                        suggestions: Vec::new(),
+                        origin: None,
                     },
                 );
             }
@@ -562,6 +1175,50 @@ fn add_grammar_diagnostics(
                         category: DiagnosticCategory::ShuddhaTable,
                         kind: DiagnosticKind::Variant,
                         confidence,
+                        suggestions: Vec::new(),
+                        origin: None,
+                    },
+                );
+            }
+        }
+
+        if let Some((verb, correction)) = person_agreement_fix(text, tokens, lex, idx) {
+            let verb_span = (verb.start, verb.end);
+            if !overlaps_blocked(blocked_spans, verb_span) {
+                push_best_grammar_variant(
+                    diagnostics,
+                    Diagnostic {
+                        span: verb_span,
+                        incorrect: token_full_form(verb),
+                        correction,
+                        rule: Rule::Vyakaran("subject-verb-person"),
+                        explanation: format!("कर्ता ({full}) र क्रियाको पुरुष मेल खाँदैन।"),
+                        category: DiagnosticCategory::ShuddhaTable,
+                        kind: DiagnosticKind::Variant,
+                        confidence: 0.82,
+                        suggestions: Vec::new(),
+                        origin: None,
+                    },
+                );
+            }
+        }
+
+        if let Some((verb, correction)) = honorific_register_fix(text, tokens, idx) {
+            let verb_span = (verb.start, verb.end);
+            if !overlaps_blocked(blocked_spans, verb_span) {
+                push_best_grammar_variant(
+                    diagnostics,
+                    Diagnostic {
+                        span: verb_span,
+                        incorrect: token_full_form(verb),
+                        correction,
+                        rule: Rule::Vyakaran("honorific-register"),
+                        explanation: format!("सम्बोधन ({full}) अनुसार क्रियाको आदरार्थी तह मिलाउनुपर्छ।"),
+                        category: DiagnosticCategory::ShuddhaTable,
+                        kind: DiagnosticKind::Variant,
+                        confidence: 0.6,
+                        suggestions: Vec::new(),
+                        origin: None,
                     },
                 );
             }
@@ -585,6 +1242,8 @@ fn add_grammar_diagnostics(
                         category: DiagnosticCategory::Sandhi,
                         kind: DiagnosticKind::Variant,
                         confidence: top.score.min(0.9),
+                        suggestions: Vec::new(),
+                        origin: None,
                     },
                 );
             }
@@ -592,6 +1251,14 @@ fn add_grammar_diagnostics(
     }
 }
 
+/// Whether `span` overlaps a span already claimed by a word or phrase diagnostic.
+#[cfg(feature = "grammar-pass")]
+fn overlaps_blocked(blocked_spans: &HashSet<(usize, usize)>, span: (usize, usize)) -> bool {
+    blocked_spans
+        .iter()
+        .any(|&(start, end)| start < span.1 && span.0 < end)
+}
+
 #[cfg(feature = "grammar-pass")]
 fn push_best_grammar_variant(diagnostics: &mut Vec<Diagnostic>, candidate: Diagnostic) {
     let existing = diagnostics.iter_mut().find(|d| {
@@ -609,14 +1276,21 @@ fn push_best_grammar_variant(diagnostics: &mut Vec<Diagnostic>, candidate: Diagn
     }
 }
 
+/// Plural noun: marked plural by -हरू/-हरु, not a pronoun (which carries
+/// its own person).
 #[cfg(feature = "grammar-pass")]
-fn has_plural_suffix(word: &str) -> bool {
-    word.ends_with("हरू") || word.ends_with("हरु")
+fn is_plural_noun(token: &AnalyzedToken) -> bool {
+    token.number == Some(varnavinyas_vyakaran::Number::Plural) && token.person.is_none()
 }
 
+/// A suffix was split off, but the token has no case, number or person
+/// reading: the word could be the whole form or stem + particle.
 #[cfg(feature = "grammar-pass")]
-fn strip_plural_suffix(word: &str) -> Option<&str> {
-    word.strip_suffix("हरू").or_else(|| word.strip_suffix("हरु"))
+fn has_unresolved_suffix(token: &AnalyzedToken) -> bool {
+    token.suffix.is_some()
+        && token.case.is_none()
+        && token.number.is_none()
+        && token.person.is_none()
 }
 
 #[cfg(feature = "grammar-pass")]
@@ -653,13 +1327,87 @@ fn suggested_genitive_suffix(
     }
 
     let next = next_token?;
-    if has_plural_suffix(&token_full_form(next)) {
+    if next.number == Some(varnavinyas_vyakaran::Number::Plural) {
         Some("का".to_string())
     } else {
         None
     }
 }
 
+/// For a nominative subject pronoun at `subject_idx`, find the sentence's
+/// finite verb and, if its person disagrees, the re-inflected verb form.
+#[cfg(feature = "grammar-pass")]
+fn person_agreement_fix<'a>(
+    text: &str,
+    tokens: &'a [AnalyzedToken],
+    lex: &Kosha,
+    subject_idx: usize,
+) -> Option<(&'a AnalyzedToken, String)> {
+    let subject = &tokens[subject_idx];
+    let (person, number) = (subject.person?, subject.number?);
+    if subject.case.is_some() || HONORIFIC_PRONOUNS.contains(&subject.stem.as_str()) {
+        return None;
+    }
+
+    for tok in &tokens[subject_idx + 1..] {
+        if text[subject.end..tok.start].contains(['।', '?', '!']) {
+            return None;
+        }
+        let Some(verb_person) = tok.person else {
+            continue;
+        };
+        // छ-final adjectives and nouns (तुच्छ, पुच्छ) look like finite verbs;
+        // the kosha lists them as non-verb headwords.
+        if lex
+            .lookup(&token_full_form(tok))
+            .is_some_and(|entry| !entry.pos.contains("क्रि"))
+        {
+            continue;
+        }
+        // Pronouns carry number as well; a person without number is a verb ending.
+        if tok.number.is_some() {
+            // Another nominative pronoun starts a new clause.
+            tok.case?;
+            continue;
+        }
+        if verb_person == person {
+            return None;
+        }
+        let correction =
+            varnavinyas_vyakaran::reinflect_person(&token_full_form(tok), person, number)?;
+        return Some((tok, correction));
+    }
+    None
+}
+
+/// For a second-person pronoun at `pronoun_idx`, find a known verb form later
+/// in the sentence and, if its register differs, the matching-register form.
+#[cfg(feature = "grammar-pass")]
+fn honorific_register_fix<'a>(
+    text: &str,
+    tokens: &'a [AnalyzedToken],
+    pronoun_idx: usize,
+) -> Option<(&'a AnalyzedToken, String)> {
+    let pronoun = &tokens[pronoun_idx];
+    let &(_, register) = SECOND_PERSON_REGISTERS
+        .iter()
+        .find(|(p, _)| *p == token_full_form(pronoun))?;
+
+    for tok in &tokens[pronoun_idx + 1..] {
+        if text[pronoun.end..tok.start].contains(['।', '?', '!']) {
+            return None;
+        }
+        let full = token_full_form(tok);
+        if let Some(forms) = HONORIFIC_VERB_FORMS
+            .iter()
+            .find(|f| f.contains(&full.as_str()))
+        {
+            return (forms[register] != full).then(|| (tok, forms[register].to_string()));
+        }
+    }
+    None
+}
+
 #[cfg(feature = "grammar-pass")]
 fn token_full_form(token: &AnalyzedToken) -> String {
     match &token.suffix {
@@ -682,6 +1430,8 @@ mod grammar_variant_refine_tests {
             category: DiagnosticCategory::ShuddhaTable,
             kind: DiagnosticKind::Variant,
             confidence,
+            suggestions: Vec::new(),
+            origin: None,
         }
     }
 
@@ -718,6 +1468,93 @@ mod grammar_variant_refine_tests {
 
         assert_eq!(diagnostics.len(), 2);
     }
+
+    #[test]
+    fn plural_noun_reads_token_number() {
+        let tokens = tokenize_analyzed_with("धेरै मानिसहरू हामी", kosha());
+        assert!(!is_plural_noun(&tokens[0]));
+        assert!(is_plural_noun(&tokens[1]));
+        // हामी is plural but a pronoun, not a -हरू noun.
+        assert!(!is_plural_noun(&tokens[2]));
+    }
+
+    #[test]
+    fn case_marked_token_is_not_ambiguous() {
+        let tokens = tokenize_analyzed_with("सुनारलाई केटाहरूलाई", kosha());
+        assert!(tokens.iter().all(|t| !has_unresolved_suffix(t)));
+    }
+}
+
+#[cfg(test)]
+mod overlap_resolution_tests {
+    use super::*;
+
+    fn mk(span: (usize, usize), rule: Rule, kind: DiagnosticKind, confidence: f32) -> Diagnostic {
+        Diagnostic {
+            span,
+            incorrect: "x".to_string(),
+            correction: "y".to_string(),
+            rule,
+            explanation: "x".to_string(),
+            category: DiagnosticCategory::ShuddhaTable,
+            kind,
+            confidence,
+            suggestions: Vec::new(),
+            origin: None,
+        }
+    }
+
+    #[test]
+    fn keeps_one_of_partially_overlapping_word_and_phrase() {
+        // Word-level variant on the second word; phrase error spanning both.
+        let word = mk(
+            (10, 22),
+            Rule::ShuddhaAshuddha("Section 4"),
+            DiagnosticKind::Variant,
+            0.9,
+        );
+        let phrase = mk(
+            (0, 22),
+            Rule::VarnaVinyasNiyam("3(घ)"),
+            DiagnosticKind::Error,
+            0.95,
+        );
+        let kept = resolve_overlaps(vec![word, phrase]);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].span, (0, 22));
+        assert_eq!(kept[0].kind, DiagnosticKind::Error);
+    }
+
+    #[test]
+    fn same_kind_prefers_higher_confidence() {
+        let low = mk((0, 12), Rule::Vyakaran("a"), DiagnosticKind::Variant, 0.6);
+        let high = mk((6, 18), Rule::Vyakaran("b"), DiagnosticKind::Variant, 0.8);
+        let kept = resolve_overlaps(vec![low, high]);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].rule, Rule::Vyakaran("b"));
+    }
+
+    #[test]
+    fn keeps_disjoint_and_ambiguous_diagnostics() {
+        let a = mk((0, 6), Rule::Vyakaran("a"), DiagnosticKind::Error, 1.0);
+        let b = mk((7, 12), Rule::Vyakaran("b"), DiagnosticKind::Variant, 0.7);
+        let hint = mk((0, 12), Rule::Vyakaran("c"), DiagnosticKind::Ambiguous, 0.5);
+        let kept = resolve_overlaps(vec![a, b, hint]);
+        assert_eq!(kept.len(), 3);
+        assert_eq!(kept[2].rule, Rule::Vyakaran("c"));
+    }
+
+    #[test]
+    fn keeps_overlapping_punctuation_findings() {
+        // lekhya reports both sides of a misplaced comma in "म ,तिमी आऊ".
+        let diags = check_text("म ,तिमी आऊ");
+        let spans: Vec<(usize, usize)> = diags
+            .iter()
+            .filter(|d| d.category == DiagnosticCategory::Punctuation)
+            .map(|d| d.span)
+            .collect();
+        assert_eq!(spans, vec![(3, 5), (4, 5)], "{diags:?}");
+    }
 }
 
 #[cfg(test)]
@@ -735,6 +1572,8 @@ mod noop_heuristic_tests {
             category: DiagnosticCategory::ShuddhaTable,
             kind: DiagnosticKind::Variant,
             confidence: 0.55,
+            suggestions: Vec::new(),
+            origin: None,
         };
         assert!(is_noop_heuristic_diagnostic(&d));
     }
@@ -750,7 +1589,39 @@ mod noop_heuristic_tests {
             category: DiagnosticCategory::HrasvaDirgha,
             kind: DiagnosticKind::Error,
             confidence: 1.0,
+            suggestions: Vec::new(),
+            origin: None,
         };
         assert!(!is_noop_heuristic_diagnostic(&d));
     }
 }
+
+#[cfg(test)]
+mod origin_cache_tests {
+    use super::*;
+
+    #[test]
+    fn cached_and_uncached_runs_match() {
+        let text = "अत्याधिक वर्षाले नेपालका धेरै गाउँमा क्षति भयो। नेपालका गाउँमा रजिष्टर \
+                    राखिएन र राजनैतिक दलहरूले नेपालका गाउँमा सहयोग पठाए।";
+        let tokens = tokenize_analyzed_with(text, kosha());
+
+        let mut shared = OriginCache::default();
+        let mut uncached_classifications = 0;
+        for token in &tokens {
+            let mut fresh = OriginCache::default();
+            let uncached = check_word_in(&token.stem, kosha(), false, &mut fresh);
+            uncached_classifications += fresh.classified;
+
+            let cached = check_word_in(&token.stem, kosha(), false, &mut shared);
+            assert_eq!(cached, uncached, "diagnostic changed for {}", token.stem);
+        }
+
+        assert!(
+            shared.classified < uncached_classifications,
+            "repeated stems should reuse origin decisions ({} vs {})",
+            shared.classified,
+            uncached_classifications
+        );
+    }
+}
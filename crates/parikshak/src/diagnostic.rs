@@ -1,7 +1,9 @@
+use crate::locale::{Language, english_explanation};
 use varnavinyas_prakriya::{DiagnosticKind, Rule, RuleCategory};
+use varnavinyas_shabda::Origin;
 
 /// Category of a diagnostic.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DiagnosticCategory {
     HrasvaDirgha,
     Chandrabindu,
@@ -13,6 +15,8 @@ pub enum DiagnosticCategory {
     Sandhi,
     Punctuation,
     ShuddhaTable,
+    /// आगन्तुक (loanword) spelling: ष→स, ण→न in borrowed words.
+    Loanword,
 }
 
 impl DiagnosticCategory {
@@ -30,9 +34,29 @@ impl DiagnosticCategory {
             Self::Sandhi => "Sandhi",
             Self::Punctuation => "Punctuation",
             Self::ShuddhaTable => "ShuddhaTable",
+            Self::Loanword => "Loanword",
         }
     }
 
+    /// Parse a code produced by [`as_code`](Self::as_code).
+    pub fn from_code(code: &str) -> Option<Self> {
+        let category = match code {
+            "HrasvaDirgha" => Self::HrasvaDirgha,
+            "Chandrabindu" => Self::Chandrabindu,
+            "ShaShaS" => Self::ShaShaS,
+            "RiKri" => Self::RiKri,
+            "Halanta" => Self::Halanta,
+            "YaE" => Self::YaE,
+            "KshaChhya" => Self::KshaChhya,
+            "Sandhi" => Self::Sandhi,
+            "Punctuation" => Self::Punctuation,
+            "ShuddhaTable" => Self::ShuddhaTable,
+            "Loanword" => Self::Loanword,
+            _ => return None,
+        };
+        Some(category)
+    }
+
     /// Infer category from a Rule.
     pub fn from_rule(rule: &Rule) -> Self {
         match rule {
@@ -83,6 +107,8 @@ impl DiagnosticCategory {
             RuleCategory::Sandhi => DiagnosticCategory::Sandhi,
             RuleCategory::AadhiVriddhi => DiagnosticCategory::ShuddhaTable,
             RuleCategory::YaE => DiagnosticCategory::YaE,
+            // Same bucket as the 3(ग)-बव table entries (see `from_rule`).
+            RuleCategory::BaVa => DiagnosticCategory::ShaShaS,
             RuleCategory::KshaChhya => DiagnosticCategory::KshaChhya,
             RuleCategory::GyaGyan => DiagnosticCategory::ShuddhaTable,
             RuleCategory::Structural => DiagnosticCategory::ShuddhaTable,
@@ -103,12 +129,13 @@ impl std::fmt::Display for DiagnosticCategory {
             Self::Sandhi => write!(f, "सन्धि"),
             Self::Punctuation => write!(f, "चिह्न"),
             Self::ShuddhaTable => write!(f, "शुद्ध-अशुद्ध"),
+            Self::Loanword => write!(f, "आगन्तुक"),
         }
     }
 }
 
 /// A spell-check diagnostic.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Diagnostic {
     /// Byte offset span (start, end) in the original text.
     pub span: (usize, usize),
@@ -126,6 +153,107 @@ pub struct Diagnostic {
     pub kind: DiagnosticKind,
     /// Confidence score (0.0–1.0).
     pub confidence: f32,
+    /// Ranked candidate corrections, best first (`correction` is the first).
+    ///
+    /// Only filled for unknown words when `CheckOptions::suggest` is set.
+    pub suggestions: Vec<String>,
+    /// Origin class of the word, for diagnostics from origin-driven spelling
+    /// rules; `None` for grammar, punctuation and lexicon-only findings.
+    pub origin: Option<Origin>,
+}
+
+impl Diagnostic {
+    /// Span measured in Unicode scalar values (`char`s) within `text`.
+    ///
+    /// `text` must be the same input that produced this diagnostic.
+    pub fn char_span(&self, text: &str) -> (usize, usize) {
+        byte_span_to_char_span(text, self.span)
+    }
+
+    /// The explanation in `lang`.
+    ///
+    /// [`Language::Nepali`] returns [`Diagnostic::explanation`] unchanged;
+    /// [`Language::English`] is looked up by `rule.code()` and is therefore
+    /// rule-level, without the word-specific detail of the Nepali text.
+    pub fn explanation_in(&self, lang: Language) -> String {
+        match lang {
+            Language::Nepali => self.explanation.clone(),
+            Language::English => english_explanation(&self.rule),
+        }
+    }
+}
+
+/// Convert a byte span into a span counted in Unicode scalar values.
+///
+/// Code-point indexed consumers (Python `str`, JS `Array.from`) should use
+/// this instead of byte offsets: every Devanagari letter, matra, and
+/// chandrabindu is 3 bytes in UTF-8 but one scalar value.
+///
+/// Offsets past the end of `text` are clamped; offsets inside a multi-byte
+/// character count that character as not yet reached.
+pub fn byte_span_to_char_span(text: &str, span: (usize, usize)) -> (usize, usize) {
+    (char_offset(text, span.0), char_offset(text, span.1))
+}
+
+fn char_offset(text: &str, byte_offset: usize) -> usize {
+    text[..floor_char_boundary(text, byte_offset)]
+        .chars()
+        .count()
+}
+
+fn floor_char_boundary(text: &str, byte_offset: usize) -> usize {
+    let mut idx = byte_offset.min(text.len());
+    while !text.is_char_boundary(idx) {
+        idx -= 1;
+    }
+    idx
+}
+
+/// A [`Diagnostic`] located by 1-based line and column.
+///
+/// Columns count Unicode scalar values from the start of the line, matching
+/// what editors and `file:line:col` output expect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocatedDiagnostic {
+    pub diagnostic: Diagnostic,
+    /// 1-based line of `span.0`.
+    pub line: usize,
+    /// 1-based column (in chars) of `span.0`.
+    pub column: usize,
+    /// 1-based line of `span.1`.
+    pub end_line: usize,
+    /// 1-based column (in chars) of `span.1`.
+    pub end_column: usize,
+}
+
+/// Attach line/column positions to diagnostics produced from `text`.
+///
+/// Lines break at `\n`, so CRLF input works unchanged: the `\r` stays at the
+/// end of the previous line.
+pub fn locate_diagnostics(text: &str, diagnostics: Vec<Diagnostic>) -> Vec<LocatedDiagnostic> {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let line_col = |offset: usize| {
+        let offset = floor_char_boundary(text, offset);
+        let line_idx = line_starts.partition_point(|&start| start <= offset) - 1;
+        let column = text[line_starts[line_idx]..offset].chars().count() + 1;
+        (line_idx + 1, column)
+    };
+    diagnostics
+        .into_iter()
+        .map(|diagnostic| {
+            let (line, column) = line_col(diagnostic.span.0);
+            let (end_line, end_column) = line_col(diagnostic.span.1);
+            LocatedDiagnostic {
+                diagnostic,
+                line,
+                column,
+                end_line,
+                end_column,
+            }
+        })
+        .collect()
 }
 
 impl std::fmt::Display for Diagnostic {
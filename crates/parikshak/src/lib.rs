@@ -1,11 +1,34 @@
+#[cfg(feature = "cache")]
+mod cache;
+mod catalog;
 mod checker;
 mod diagnostic;
+mod locale;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod stats;
 mod tokenizer;
 
-pub use checker::{CheckOptions, PunctuationMode, check_text, check_text_with_options, check_word};
-pub use diagnostic::{Diagnostic, DiagnosticCategory};
-pub use tokenizer::{AnalyzedToken, Token, tokenize, tokenize_analyzed};
+#[cfg(feature = "cache")]
+pub use cache::clear_cache;
+pub use catalog::{CatalogEntry, diagnostic_catalog};
+pub use checker::{
+    CheckOptions, PunctuationMode, check_document, check_text, check_text_diff,
+    check_text_with_kosha, check_text_with_options, check_word, check_words,
+};
+pub use diagnostic::{
+    Diagnostic, DiagnosticCategory, LocatedDiagnostic, byte_span_to_char_span, locate_diagnostics,
+};
+pub use locale::Language;
+#[cfg(feature = "markdown")]
+pub use markdown::check_markdown;
+pub use stats::{RuleStats, check_text_with_stats};
+pub use tokenizer::{
+    AnalyzedToken, Token, TokenizeOptions, tokenize, tokenize_analyzed, tokenize_with_options,
+};
 pub use varnavinyas_prakriya::DiagnosticKind;
+pub use varnavinyas_shabda::Origin;
+pub use varnavinyas_vyakaran::{Case, Number, Person};
 
 /// Error type for parikshak operations.
 #[derive(Debug, thiserror::Error)]
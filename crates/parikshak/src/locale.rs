@@ -0,0 +1,154 @@
+use varnavinyas_prakriya::Rule;
+
+/// Language for rendering diagnostic explanations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    /// नेपाली — the explanation as produced by the checker.
+    #[default]
+    Nepali,
+    /// English, from a per-rule lookup table.
+    English,
+}
+
+/// English explanations keyed on rule code prefixes.
+///
+/// The first matching prefix wins, so specific sub-rules (`3(ग)-ऋ`) must precede
+/// their section (`3(ग)`).
+static ENGLISH_EXPLANATIONS: &[(&str, &str)] = &[
+    // Section 3: orthography rules
+    (
+        "3(क)-suffix",
+        "Suffix vowel length: suffixes such as -हरू, -नु and -एली keep a fixed vowel length.",
+    ),
+    (
+        "3(क)-प्रत्यय",
+        "Suffix (pratyaya) vowel: the suffix is spelled the same whatever word it attaches to.",
+    ),
+    (
+        "3(क)-इक",
+        "Vowel length before the -ik suffix follows the Sanskrit base word.",
+    ),
+    (
+        "3(क)",
+        "Short/long vowel (hrasva/dirgha): tatsam words keep the Sanskrit length; other words mostly take the short vowel.",
+    ),
+    (
+        "3(ख)-पञ्चम",
+        "Nasal consonant: a half nasal of the same class is written before this consonant instead of the anusvara, or vice versa.",
+    ),
+    (
+        "3(ख)",
+        "Chandrabindu/anusvara: nasalized vowels take chandrabindu, nasal consonants take anusvara.",
+    ),
+    (
+        "3(ग)-ऋ",
+        "Vocalic ri: tatsam words are written with ऋ, not रि.",
+    ),
+    ("3(ग)-कृ", "कृ vs क्रि: tatsam words are written with कृ."),
+    (
+        "3(ग)-बव",
+        "ब/व: the two letters are not interchangeable; tatsam words keep the Sanskrit letter.",
+    ),
+    (
+        "3(ग)",
+        "Sibilants श/ष/स: tatsam words keep the Sanskrit sibilant; most others use स.",
+    ),
+    (
+        "3(घ)",
+        "Word joining (padayog/padabiyog): these words are written joined, or apart, as shown.",
+    ),
+    (
+        "3(ङ)",
+        "Halanta: a final virama marks a bare consonant ending; stray viramas inside conjuncts are dropped.",
+    ),
+    (
+        "3(इ)",
+        "य/ए: a word that begins with the vowel ए is not written with य, or the reverse.",
+    ),
+    (
+        "3(ई)",
+        "Long final vowel: Nepali endings such as भनी, खुर्सानी and भाउजू take a long ई/ऊ.",
+    ),
+    (
+        "3(उ)",
+        "क्ष/छ: derived words such as इच्छा are written with च्छ, not क्ष.",
+    ),
+    ("3(छ)-ज्ञ", "ज्ञ: the conjunct is written ज्ञ, not ग्य or ग्न."),
+    ("3(छ)-क्ष", "क्ष: tatsam words such as क्षेत्र and लक्ष्य keep क्ष."),
+    (
+        "3(छ)",
+        "Word-initial य/ए: the Academy list fixes the letter, as in यथार्थ and एकता.",
+    ),
+    // Section 4: correct/incorrect word table
+    (
+        "Section 4",
+        "Listed word: the Academy correct/incorrect table gives this spelling.",
+    ),
+    (
+        "variant-spelling",
+        "Accepted variant: both spellings are in use, but the other form is preferred.",
+    ),
+    (
+        "unknown",
+        "Word not found in the dictionary; the closest known spelling is suggested.",
+    ),
+    // Section 5: punctuation
+    (
+        "Section 5",
+        "Punctuation: the standard form of this punctuation mark is suggested.",
+    ),
+    // Grammar heuristics
+    (
+        "subject-verb-person",
+        "Subject-verb agreement: the verb ending does not match the person of the subject.",
+    ),
+    (
+        "honorific-register",
+        "Honorific register: the verb form does not match the honorific level of the subject.",
+    ),
+    (
+        "quantifier-plural-redundancy",
+        "A plural marker after a quantifier such as धेरै is usually redundant.",
+    ),
+    (
+        "ergative-le-intransitive",
+        "The ergative marker ले is not normally used with an intransitive verb.",
+    ),
+    (
+        "genitive-mismatch-plural",
+        "The genitive marker should agree with the plural noun that follows (का, not को).",
+    ),
+    (
+        "section4-phrase-style",
+        "Phrase style: the Academy table recommends a different phrasing.",
+    ),
+    (
+        "samasa-heuristic",
+        "Compound (samasa): this looks like a compound that is written joined.",
+    ),
+    (
+        "morph-ambiguity",
+        "Ambiguous form: more than one analysis is possible; review manually.",
+    ),
+];
+
+/// English explanation for a rule, looked up by [`Rule::code`].
+///
+/// Rules missing from the table get a generic English sentence naming the
+/// source and code.
+pub(crate) fn english_explanation(rule: &Rule) -> String {
+    let code = rule.code();
+    if let Some((_, text)) = ENGLISH_EXPLANATIONS
+        .iter()
+        .find(|(prefix, _)| code.starts_with(prefix))
+    {
+        return (*text).to_string();
+    }
+    let source = match rule {
+        Rule::VarnaVinyasNiyam(_) => "Nepal Academy orthography rule",
+        Rule::Vyakaran(_) => "Nepal Academy grammar rule",
+        Rule::ShuddhaAshuddha(_) => "Nepal Academy word table",
+        Rule::ChihnaNiyam(_) => "Nepal Academy punctuation rule",
+    };
+    format!("{source} {code}.")
+}
@@ -0,0 +1,135 @@
+use crate::checker::check_text;
+use crate::diagnostic::Diagnostic;
+
+/// Check Markdown text, skipping code and link targets.
+///
+/// Fenced code blocks, inline code spans, link/image URLs (`[text](url)`),
+/// autolinks (`<https://…>`) and bare `http(s)://` URLs are blanked out
+/// before running [`check_text`]. Blanking keeps byte lengths, so spans
+/// refer to the original `text`; diagnostics touching a blanked range
+/// (e.g. spacing around a code span) are dropped. Link *text* is still
+/// checked.
+pub fn check_markdown(text: &str) -> Vec<Diagnostic> {
+    let masked_ranges = markdown_masked_ranges(text);
+    if masked_ranges.is_empty() {
+        return check_text(text);
+    }
+
+    let masked = mask(text, &masked_ranges);
+    let mut diagnostics = check_text(&masked);
+    diagnostics.retain(|d| {
+        !masked_ranges
+            .iter()
+            .any(|&(start, end)| d.span.0 < end && start < d.span.1)
+    });
+    diagnostics
+}
+
+/// Replace every char in `ranges` (except newlines) with spaces of the same
+/// byte length.
+fn mask(text: &str, ranges: &[(usize, usize)]) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (offset, c) in text.char_indices() {
+        let hidden = c != '\n' && ranges.iter().any(|&(s, e)| s <= offset && offset < e);
+        if hidden {
+            out.extend(std::iter::repeat_n(' ', c.len_utf8()));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Byte ranges of code and URLs, in text order.
+fn markdown_masked_ranges(text: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut fence: Option<(char, usize, usize)> = None; // (char, run length, block start)
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        let trimmed = line.trim_start_matches(' ');
+        let indent = line.len() - trimmed.len();
+        let run = fence_run(trimmed);
+
+        match fence {
+            Some((ch, len, block_start)) => {
+                // Closing fence: same char, at least as long, nothing after it.
+                if let Some((c, n)) = run {
+                    if c == ch && n >= len && indent < 4 && trimmed[n..].trim().is_empty() {
+                        ranges.push((block_start, line_end));
+                        fence = None;
+                    }
+                }
+            }
+            None => match run {
+                Some((c, n)) if indent < 4 => fence = Some((c, n, line_start)),
+                _ => inline_ranges(line, line_start, &mut ranges),
+            },
+        }
+        line_start = line_end;
+    }
+    // An unclosed fence runs to the end of the document.
+    if let Some((_, _, block_start)) = fence {
+        ranges.push((block_start, text.len()));
+    }
+    ranges
+}
+
+/// A fence opener/closer: three or more backticks or tildes.
+fn fence_run(line: &str) -> Option<(char, usize)> {
+    let ch = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let n = line.chars().take_while(|&c| c == ch).count();
+    (n >= 3).then_some((ch, n))
+}
+
+/// Inline code spans, link targets and URLs within one line.
+fn inline_ranges(line: &str, base: usize, ranges: &mut Vec<(usize, usize)>) {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'`' => {
+                let run = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+                let closer = "`".repeat(run);
+                let close = line[i + run..]
+                    .match_indices(closer.as_str())
+                    .map(|(j, _)| i + run + j)
+                    .find(|&j| bytes.get(j + run) != Some(&b'`'));
+                match close {
+                    Some(j) => {
+                        ranges.push((base + i, base + j + run));
+                        i = j + run;
+                    }
+                    None => i += run,
+                }
+            }
+            b']' if bytes.get(i + 1) == Some(&b'(') => match line[i + 2..].find(')') {
+                Some(j) => {
+                    ranges.push((base + i + 2, base + i + 2 + j));
+                    i += 2 + j + 1;
+                }
+                None => i += 1,
+            },
+            b'<' if is_url_start(&line[i + 1..]) => match line[i..].find('>') {
+                Some(j) => {
+                    ranges.push((base + i, base + i + j + 1));
+                    i += j + 1;
+                }
+                None => i += 1,
+            },
+            b'h' if is_url_start(&line[i..]) => {
+                let len = line[i..]
+                    .find(|c: char| c.is_whitespace() || matches!(c, ')' | '>'))
+                    .unwrap_or(line.len() - i);
+                ranges.push((base + i, base + i + len));
+                i += len;
+            }
+            _ => i += 1,
+        }
+    }
+}
+
+fn is_url_start(s: &str) -> bool {
+    s.starts_with("http://") || s.starts_with("https://")
+}
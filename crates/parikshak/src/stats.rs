@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::checker::check_text;
+use crate::diagnostic::Diagnostic;
+
+/// How many times each rule fired over a document, keyed on [`Rule::code`].
+///
+/// [`Rule::code`]: varnavinyas_prakriya::Rule::code
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleStats {
+    counts: HashMap<&'static str, usize>,
+}
+
+impl RuleStats {
+    /// Tally the rules cited by `diagnostics`.
+    pub fn from_diagnostics(diagnostics: &[Diagnostic]) -> Self {
+        let mut stats = Self::default();
+        for diag in diagnostics {
+            *stats.counts.entry(diag.rule.code()).or_default() += 1;
+        }
+        stats
+    }
+
+    /// Hits for one rule code; 0 if it never fired.
+    pub fn count(&self, rule_code: &str) -> usize {
+        self.counts.get(rule_code).copied().unwrap_or(0)
+    }
+
+    /// Total number of diagnostics tallied.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Rule codes by hit count, most frequent first (ties by code).
+    pub fn most_common(&self) -> Vec<(&'static str, usize)> {
+        let mut ranked: Vec<_> = self.counts.iter().map(|(&code, &n)| (code, n)).collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        ranked
+    }
+}
+
+/// [`check_text`] plus per-rule hit counts, e.g. for a "most common mistakes
+/// in this document" report.
+///
+/// Counts cover exactly the returned diagnostics.
+pub fn check_text_with_stats(text: &str) -> (Vec<Diagnostic>, RuleStats) {
+    let diagnostics = check_text(text);
+    let stats = RuleStats::from_diagnostics(&diagnostics);
+    (diagnostics, stats)
+}
@@ -1,5 +1,6 @@
-use varnavinyas_kosha::kosha;
+use varnavinyas_kosha::{Kosha, kosha};
 use varnavinyas_prakriya::is_in_correction_table;
+use varnavinyas_vyakaran::{Case, Number, Person};
 
 /// A token extracted from text.
 #[derive(Debug, Clone)]
@@ -23,15 +24,47 @@ pub struct AnalyzedToken {
     pub start: usize,
     /// Byte offset of the end of the full token (stem+suffix) in the original text.
     pub end: usize,
+    /// Case (कारक) read from the postposition, if any.
+    ///
+    /// The inflectional features are only filled with the `grammar-pass`
+    /// feature; without it they are always `None`.
+    pub case: Option<Case>,
+    /// Number (वचन): plural from -हरू/-हरु, or from a pronoun.
+    pub number: Option<Number>,
+    /// Person (पुरुष) of a personal pronoun or finite verb ending.
+    pub person: Option<Person>,
+}
+
+impl AnalyzedToken {
+    /// Build a token and fill its inflectional features from `inflected`
+    /// (the word form minus any detached particle).
+    #[cfg_attr(not(feature = "grammar-pass"), allow(unused_variables))]
+    fn new(tok: &Token, stem: &str, suffix: Option<&str>, inflected: &str) -> Self {
+        #[cfg(feature = "grammar-pass")]
+        let features = varnavinyas_vyakaran::surface_features(inflected);
+        #[cfg(not(feature = "grammar-pass"))]
+        let features = varnavinyas_vyakaran::Features::default();
+        Self {
+            stem: stem.to_string(),
+            suffix: suffix.map(str::to_string),
+            start: tok.start,
+            end: tok.end,
+            case: features.case,
+            number: features.number,
+            person: features.person,
+        }
+    }
 }
 
 /// Known Nepali postpositions and plural markers, ordered longest-first for greedy matching.
+///
+/// The non-standard -हरु is not detached: `केटाहरु` stays whole so the
+/// plural spelling rule sees it.
 const SUFFIXES: &[&str] = &[
     "भित्र",
     "प्रति",
     "देखि",
     "हरू",
-    "हरु",
     "लाई",
     "बाट",
     "सँग",
@@ -43,6 +76,9 @@ const SUFFIXES: &[&str] = &[
     "मा",
 ];
 
+/// Plural markers that may precede a postposition (केटा + हरू + लाई).
+const PLURAL_SUFFIXES: &[&str] = &["हरू", "हरु"];
+
 /// Vocative case markers. Only active behind `vocative-tokenization` feature.
 #[cfg(feature = "vocative-tokenization")]
 const VOCATIVE_SUFFIXES: &[&str] = &["ए", "ओ"];
@@ -52,11 +88,34 @@ const VOCATIVE_SUFFIXES: &[&str] = &["ए", "ओ"];
 #[cfg(feature = "nipat-tokenization")]
 const NIPATS: &[&str] = &["क्यारे", "नै", "पो", "रे", "खै", "नि", "ल", "त"];
 
+/// Options for [`tokenize_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenizeOptions {
+    /// Keep hyphen-joined runs (`भारत-नेपाल`) as one token.
+    ///
+    /// On by default, matching [`tokenize`]. When off, each hyphen-separated
+    /// part becomes its own token.
+    pub keep_hyphenated: bool,
+}
+
+impl Default for TokenizeOptions {
+    fn default() -> Self {
+        Self {
+            keep_hyphenated: true,
+        }
+    }
+}
+
 /// Tokenize text into word tokens with byte offsets.
 ///
 /// Splits on whitespace and strips surrounding punctuation from each token.
 /// Only returns tokens that contain at least one Devanagari character.
 pub fn tokenize(text: &str) -> Vec<Token> {
+    tokenize_with_options(text, TokenizeOptions::default())
+}
+
+/// Like [`tokenize`], with control over how hyphenated compounds are split.
+pub fn tokenize_with_options(text: &str, options: TokenizeOptions) -> Vec<Token> {
     let mut tokens = Vec::new();
     let mut pos = 0;
 
@@ -66,42 +125,60 @@ pub fn tokenize(text: &str) -> Vec<Token> {
         let seg_end = seg_start + segment.len();
         pos = seg_end;
 
-        // Strip leading/trailing punctuation to get the word core
-        let (word, word_start, word_end) = strip_punctuation(segment, seg_start);
-
-        if !word.is_empty() && has_devanagari(&word) {
-            tokens.push(Token {
-                text: word,
-                start: word_start,
-                end: word_end,
-            });
+        if options.keep_hyphenated {
+            push_word(&mut tokens, segment, seg_start);
+        } else {
+            let mut part_start = seg_start;
+            for part in segment.split('-') {
+                push_word(&mut tokens, part, part_start);
+                part_start += part.len() + 1;
+            }
         }
     }
 
     tokens
 }
 
+/// Strip leading/trailing punctuation from `segment` and keep the word core
+/// if it is Devanagari.
+fn push_word(tokens: &mut Vec<Token>, segment: &str, offset: usize) {
+    let (word, word_start, word_end) = strip_punctuation(segment, offset);
+
+    if !word.is_empty() && has_devanagari(&word) {
+        tokens.push(Token {
+            text: word,
+            start: word_start,
+            end: word_end,
+        });
+    }
+}
+
 /// Tokenize text into analyzed tokens with suffix detachment.
 ///
 /// For each whitespace-delimited token, tries to detach a known suffix (longest-first).
 /// A suffix is only detached if the remaining stem exists in the kosha lexicon.
+/// Stacked plural + case suffixes are detached together, as in the vyakaran
+/// analyzer (केटाहरूलाई → `केटा` + `हरूलाई`).
 /// If no valid split is found, the full word becomes the stem with `suffix: None`.
 pub fn tokenize_analyzed(text: &str) -> Vec<AnalyzedToken> {
+    tokenize_analyzed_with(text, kosha())
+}
+
+/// Like [`tokenize_analyzed`], validating stems against `lex` instead of the
+/// global lexicon.
+pub(crate) fn tokenize_analyzed_with(text: &str, lex: &Kosha) -> Vec<AnalyzedToken> {
     let tokens = tokenize(text);
-    let lex = kosha();
 
     tokens
         .into_iter()
         .map(|tok| {
+            if let Some((stem, suffix)) = strip_plural_and_case(&tok.text, lex) {
+                return AnalyzedToken::new(&tok, stem, Some(&suffix), &tok.text);
+            }
             for sfx in SUFFIXES {
                 if let Some(stem) = tok.text.strip_suffix(sfx) {
                     if !stem.is_empty() && (lex.contains(stem) || is_in_correction_table(stem)) {
-                        return AnalyzedToken {
-                            stem: stem.to_string(),
-                            suffix: Some(sfx.to_string()),
-                            start: tok.start,
-                            end: tok.end,
-                        };
+                        return AnalyzedToken::new(&tok, stem, Some(sfx), &tok.text);
                     }
                     // Oblique form: stem ends in ा (oblique) but dictionary has ो form
                     // e.g., "केटालाई" → stem "केटा", but kosha has "केटो"
@@ -110,12 +187,7 @@ pub fn tokenize_analyzed(text: &str) -> Vec<AnalyzedToken> {
                         if let Some(base) = stem.strip_suffix('ा') {
                             let candidate = format!("{base}ो");
                             if lex.contains(&candidate) {
-                                return AnalyzedToken {
-                                    stem: stem.to_string(),
-                                    suffix: Some(sfx.to_string()),
-                                    start: tok.start,
-                                    end: tok.end,
-                                };
+                                return AnalyzedToken::new(&tok, stem, Some(sfx), &tok.text);
                             }
                         }
                     }
@@ -135,12 +207,13 @@ pub fn tokenize_analyzed(text: &str) -> Vec<AnalyzedToken> {
                             varnavinyas_akshar::is_svar(c) || varnavinyas_akshar::is_matra(c)
                         })
                     {
-                        return AnalyzedToken {
-                            stem: stem.to_string(),
-                            suffix: Some(voc.to_string()),
-                            start: tok.start,
-                            end: tok.end,
+                        let analyzed = AnalyzedToken::new(&tok, stem, Some(voc), stem);
+                        #[cfg(feature = "grammar-pass")]
+                        let analyzed = AnalyzedToken {
+                            case: Some(Case::Vocative),
+                            ..analyzed
                         };
+                        return analyzed;
                     }
                 }
             }
@@ -160,25 +233,42 @@ pub fn tokenize_analyzed(text: &str) -> Vec<AnalyzedToken> {
                         && !lex.contains(&tok.text)
                         && (!is_risky || vowel_ending)
                     {
-                        return AnalyzedToken {
-                            stem: stem.to_string(),
-                            suffix: Some(nip.to_string()),
-                            start: tok.start,
-                            end: tok.end,
-                        };
+                        return AnalyzedToken::new(&tok, stem, Some(nip), stem);
                     }
                 }
             }
-            AnalyzedToken {
-                stem: tok.text,
-                suffix: None,
-                start: tok.start,
-                end: tok.end,
-            }
+            AnalyzedToken::new(&tok, &tok.text, None, &tok.text)
         })
         .collect()
 }
 
+/// Two-stage split: a postposition, then a plural marker before it.
+///
+/// Returns the stem and the combined suffix (`हरूलाई`). The non-standard
+/// -हरु stays on the stem (`केटाहरु` + `लाई`) so the plural spelling is still
+/// checked.
+fn strip_plural_and_case<'a>(word: &'a str, lex: &Kosha) -> Option<(&'a str, String)> {
+    for sfx in SUFFIXES.iter().filter(|s| !PLURAL_SUFFIXES.contains(s)) {
+        let Some(plural_form) = word.strip_suffix(sfx) else {
+            continue;
+        };
+        for pl in PLURAL_SUFFIXES {
+            let Some(base) = plural_form.strip_suffix(pl) else {
+                continue;
+            };
+            if base.is_empty() || !(lex.contains(base) || is_in_correction_table(base)) {
+                continue;
+            }
+            return Some(if *pl == "हरू" {
+                (base, format!("{pl}{sfx}"))
+            } else {
+                (plural_form, (*sfx).to_string())
+            });
+        }
+    }
+    None
+}
+
 /// Strip leading and trailing punctuation from a token.
 /// Returns (stripped_word, adjusted_start, adjusted_end).
 fn strip_punctuation(token: &str, offset: usize) -> (String, usize, usize) {
@@ -273,6 +363,29 @@ mod tests {
         assert_eq!(tokens[0].text, "अत्याधिक");
     }
 
+    #[test]
+    fn hyphenated_compound_option() {
+        let text = "भारत-नेपाल सम्बन्ध";
+        let kept = tokenize_with_options(
+            text,
+            TokenizeOptions {
+                keep_hyphenated: true,
+            },
+        );
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].text, "भारत-नेपाल");
+
+        let split = tokenize_with_options(
+            text,
+            TokenizeOptions {
+                keep_hyphenated: false,
+            },
+        );
+        let words: Vec<_> = split.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(words, ["भारत", "नेपाल", "सम्बन्ध"]);
+        assert_eq!(&text[split[1].start..split[1].end], "नेपाल");
+    }
+
     #[test]
     fn empty_input() {
         assert!(tokenize("").is_empty());
@@ -306,13 +419,18 @@ mod tests {
         assert_eq!(tokens[0].suffix.as_deref(), Some("लाई"));
     }
 
-    /// O8.2: "घरहरु" → stem "घर", suffix "हरु"
+    /// O8.2: "घरहरु" stays whole so the -हरु spelling is checked; "घरहरू"
+    /// detaches.
     #[test]
-    fn o8_2_detach_haru() {
+    fn o8_2_keep_nonstandard_haru() {
         let tokens = tokenize_analyzed("घरहरु");
         assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].stem, "घरहरु");
+        assert_eq!(tokens[0].suffix, None);
+
+        let tokens = tokenize_analyzed("घरहरू");
         assert_eq!(tokens[0].stem, "घर");
-        assert_eq!(tokens[0].suffix.as_deref(), Some("हरु"));
+        assert_eq!(tokens[0].suffix.as_deref(), Some("हरू"));
     }
 
     /// O8.3: "नेपालमा" → stem "नेपाल", suffix "मा"
@@ -324,6 +442,49 @@ mod tests {
         assert_eq!(tokens[0].suffix.as_deref(), Some("मा"));
     }
 
+    #[cfg(feature = "grammar-pass")]
+    #[test]
+    fn plural_dative_features() {
+        let tokens = tokenize_analyzed("केटाहरूलाई");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].number, Some(Number::Plural));
+        assert_eq!(tokens[0].case, Some(Case::Dative));
+    }
+
+    #[test]
+    fn stacked_plural_and_case_suffixes() {
+        let tokens = tokenize_analyzed("केटाहरूलाई");
+        assert_eq!(tokens[0].stem, "केटा");
+        assert_eq!(tokens[0].suffix.as_deref(), Some("हरूलाई"));
+
+        let tokens = tokenize_analyzed("घरहरूमा");
+        assert_eq!(tokens[0].stem, "घर");
+        assert_eq!(tokens[0].suffix.as_deref(), Some("हरूमा"));
+
+        // Non-standard -हरु stays on the stem for the plural spelling rule.
+        let tokens = tokenize_analyzed("केटाहरुलाई");
+        assert_eq!(tokens[0].stem, "केटाहरु");
+        assert_eq!(tokens[0].suffix.as_deref(), Some("लाई"));
+    }
+
+    #[cfg(not(feature = "grammar-pass"))]
+    #[test]
+    fn features_empty_without_grammar_pass() {
+        let tokens = tokenize_analyzed("केटाहरूलाई");
+        assert_eq!(tokens[0].number, None);
+        assert_eq!(tokens[0].case, None);
+        assert_eq!(tokens[0].person, None);
+    }
+
+    #[cfg(feature = "grammar-pass")]
+    #[test]
+    fn pronoun_and_verb_person() {
+        let tokens = tokenize_analyzed("म घर जान्छु");
+        assert_eq!(tokens[0].person, Some(Person::First));
+        assert_eq!(tokens[1].person, None);
+        assert_eq!(tokens[2].person, Some(Person::First));
+    }
+
     /// O8.4: Unknown stem keeps original token unsplit.
     #[test]
     fn o8_4_unknown_stem_unsplit() {
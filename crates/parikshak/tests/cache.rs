@@ -0,0 +1,27 @@
+#[cfg(feature = "cache")]
+use varnavinyas_parikshak::{check_text, check_word, clear_cache};
+
+#[cfg(feature = "cache")]
+#[test]
+fn cached_check_word_matches_first_result() {
+    clear_cache();
+    let first = check_word("राजनैतिक");
+    let second = check_word("राजनैतिक");
+    assert!(first.is_some(), "expected a correction for राजनैतिक");
+    assert_eq!(first, second);
+
+    assert_eq!(check_word("नेपाल"), None);
+    assert_eq!(check_word("नेपाल"), None);
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn cached_text_check_is_stable_across_repeats() {
+    let text = "राजनैतिक दल र राजनैतिक नेता";
+    clear_cache();
+    let cold = check_text(text);
+    let warm = check_text(text);
+    assert_eq!(cold, warm);
+    assert_eq!(cold.len(), 2);
+    assert_ne!(cold[0].span, cold[1].span);
+}
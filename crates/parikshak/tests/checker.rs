@@ -1,5 +1,10 @@
+use std::collections::HashSet;
+
+use varnavinyas_kosha::{Kosha, KoshaBuilder, WordEntry, kosha};
 use varnavinyas_parikshak::{
-    CheckOptions, DiagnosticKind, PunctuationMode, check_text, check_text_with_options, check_word,
+    CheckOptions, DiagnosticCategory, DiagnosticKind, Language, Origin, PunctuationMode,
+    byte_span_to_char_span, check_document, check_text, check_text_diff, check_text_with_kosha,
+    check_text_with_options, check_text_with_stats, check_word, check_words, diagnostic_catalog,
 };
 
 /// C1: Paragraph with known incorrect words produces diagnostics.
@@ -158,6 +163,29 @@ fn spelling_inside_smart_quotes_is_detected() {
     );
 }
 
+#[test]
+fn punctuation_lenient_drops_quotes_keeps_period() {
+    let text = "\"नेपाल\" राम्रो देश हो.";
+    let diags = check_text_with_options(
+        text,
+        CheckOptions {
+            punctuation_mode: PunctuationMode::Lenient,
+            ..Default::default()
+        },
+    );
+    let punct_diags: Vec<_> = diags
+        .iter()
+        .filter(|d| d.category == varnavinyas_parikshak::DiagnosticCategory::Punctuation)
+        .collect();
+    assert_eq!(
+        punct_diags.len(),
+        1,
+        "Lenient mode should keep only the period diagnostic, got: {punct_diags:?}"
+    );
+    assert_eq!(punct_diags[0].correction, "।");
+    assert!(matches!(punct_diags[0].kind, DiagnosticKind::Error));
+}
+
 #[test]
 fn punctuation_normalized_editorial_emits_variant() {
     let text = "नेपाल राम्रो देश हो.";
@@ -201,6 +229,22 @@ fn suffix_preservation_in_correction() {
     assert_eq!(diag.span.1 - diag.span.0, text.len());
 }
 
+/// A bare non-standard plural is corrected on any stem, not only before a
+/// postposition.
+#[test]
+fn bare_plural_haru_is_corrected() {
+    for (text, expected) in [("केटाहरु", "केटाहरू"), ("किताबहरु", "किताबहरू")]
+    {
+        let diags = check_text(text);
+        let diag = diags
+            .iter()
+            .find(|d| d.incorrect == text)
+            .unwrap_or_else(|| panic!("Expected {text} to be flagged, got: {diags:?}"));
+        assert_eq!(diag.correction, expected);
+        assert_eq!(diag.span, (0, text.len()));
+    }
+}
+
 #[test]
 fn check_text_with_default_options_matches_check_text() {
     let text = "अत्याधिक राजनैतिक प्रशाशन भयो।";
@@ -223,6 +267,26 @@ fn padayog_phrase_join_detected() {
     assert_eq!(hit.correction, "मसँग");
 }
 
+#[test]
+fn padayog_phrase_matches_before_devanagari_boundaries() {
+    // No space between the phrase and the boundary mark.
+    for text in [
+        "ऊ घर तिर।",
+        "ऊ घर तिर॥",
+        "ऊ घर तिर॰",
+        "ऊ घर तिर\u{200B}गयो।",
+        "ऊ\u{200B}घर तिर गयो।",
+    ] {
+        let diags = check_text(text);
+        let hit = diags
+            .iter()
+            .find(|d| d.incorrect == "घर तिर")
+            .unwrap_or_else(|| panic!("Expected padayog diagnostic in {text:?}, got: {diags:?}"));
+        assert_eq!(hit.correction, "घरतिर");
+        assert_eq!(&text[hit.span.0..hit.span.1], "घर तिर");
+    }
+}
+
 #[test]
 fn padayog_phrase_multiple_detected() {
     let text = "आज्ञा अनुसार काम गर। तिमी भन्दा ऊ छिटो आयो।";
@@ -242,6 +306,69 @@ fn padayog_phrase_multiple_detected() {
     );
 }
 
+#[test]
+fn padabiyog_wrongly_joined_word_is_split() {
+    let text = "यो काम हुनसक्छ।";
+    let diags = check_text(text);
+    let hit = diags
+        .iter()
+        .find(|d| d.incorrect == "हुनसक्छ")
+        .unwrap_or_else(|| panic!("Expected padabiyog diagnostic, got: {diags:?}"));
+    assert_eq!(hit.correction, "हुन सक्छ");
+    assert_eq!(
+        hit.rule,
+        varnavinyas_prakriya::Rule::VarnaVinyasNiyam("3(घ)")
+    );
+    assert_eq!(diags.iter().filter(|d| d.span == hit.span).count(), 1);
+}
+
+fn with_namayogi_joins() -> CheckOptions {
+    CheckOptions {
+        namayogi_joins: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn namayogi_join_beyond_phrase_table() {
+    let text = "विद्यालय भित्र नियम अनुसार काम हुन्छ।";
+    let diags = check_text_with_options(text, with_namayogi_joins());
+    for (incorrect, correction) in [("विद्यालय भित्र", "विद्यालयभित्र"), ("नियम अनुसार", "नियमअनुसार")]
+    {
+        let hit = diags
+            .iter()
+            .find(|d| d.incorrect == incorrect)
+            .unwrap_or_else(|| panic!("Expected join for {incorrect:?}, got: {diags:?}"));
+        assert_eq!(hit.correction, correction);
+        assert_eq!(hit.kind, DiagnosticKind::Variant);
+        assert!(hit.confidence < 0.95);
+    }
+}
+
+#[test]
+fn namayogi_join_is_opt_in() {
+    let text = "विद्यालय भित्र नियम अनुसार काम हुन्छ।";
+    let diags = check_text(text);
+    assert!(
+        diags.iter().all(|d| !d.incorrect.contains(' ')),
+        "Expected no join diagnostics, got: {diags:?}"
+    );
+}
+
+#[test]
+fn namayogi_join_skips_verbs_and_adverbs() {
+    // भन्दा here is the verb "saying", not the comparative नामयोगी; भित्र
+    // after a pronoun is the adverb "inside".
+    for text in ["म जान्छु भन्दा उसले रोक्यो।", "तपाईँ भित्र आउनुहोस्।"]
+    {
+        let diags = check_text_with_options(text, with_namayogi_joins());
+        assert!(
+            diags.iter().all(|d| !d.incorrect.contains(' ')),
+            "Expected no join in {text:?}, got: {diags:?}"
+        );
+    }
+}
+
 #[test]
 fn section4_style_variants_are_opt_in() {
     let text = "कार्यक्रमको सम्बन्धमा छलफल भयो।";
@@ -354,3 +481,410 @@ fn section4_complex_sentence_variant_detected() {
         "Expected complex sentence style suggestion, got: {diags:?}"
     );
 }
+
+/// A proper noun injected through a custom lexicon overlay is no longer rewritten.
+#[test]
+fn custom_kosha_overlay_suppresses_rule_on_injected_name() {
+    const NAME: &str = "सरीता";
+    let text = "सरीता घर गइन्।";
+
+    let base = check_text(text);
+    assert!(
+        base.iter()
+            .any(|d| d.incorrect == NAME && d.correction == "सरिता"),
+        "Expected base lexicon to flag {NAME}, got: {base:?}"
+    );
+
+    let mut builder = KoshaBuilder::new();
+    builder.insert(
+        NAME,
+        WordEntry {
+            word: NAME,
+            pos: "ना.",
+        },
+    );
+    let layered = Kosha::layered(kosha(), builder.build().expect("overlay should build"));
+
+    let diags = check_text_with_kosha(text, CheckOptions::default(), &layered);
+    assert!(
+        !diags.iter().any(|d| d.incorrect == NAME),
+        "Injected name should not be flagged, got: {diags:?}"
+    );
+    assert_eq!(
+        check_text_with_kosha("अत्याधिक", CheckOptions::default(), &layered).len(),
+        check_text("अत्याधिक").len(),
+        "Base-lexicon behaviour should be unchanged by the overlay"
+    );
+}
+
+/// Char spans count Unicode scalar values, so they diverge from byte spans
+/// after any Devanagari text (chandrabindu included).
+#[test]
+fn char_span_differs_from_byte_span_after_chandrabindu() {
+    let text = "गाउँ सरीता";
+    let diags = check_text(text);
+    let diag = diags
+        .iter()
+        .find(|d| d.incorrect == "सरीता")
+        .expect("सरीता should be flagged");
+
+    let byte_start = "गाउँ ".len();
+    assert_eq!(diag.span, (byte_start, text.len()));
+    // ग ा उ ँ + space = 5 scalar values; सरीता = स र ी त ा = 5 more.
+    assert_eq!(diag.char_span(text), (5, 10));
+    assert_eq!(byte_span_to_char_span(text, diag.span), (5, 10));
+    assert_ne!(diag.char_span(text), diag.span);
+}
+
+#[test]
+fn byte_span_to_char_span_clamps_out_of_range() {
+    let text = "चाँद";
+    assert_eq!(byte_span_to_char_span(text, (0, 100)), (0, 4));
+    // Offset 1 is inside the first 3-byte character.
+    assert_eq!(byte_span_to_char_span(text, (1, 3)), (0, 1));
+}
+
+#[test]
+fn check_document_reports_line_and_column() {
+    for text in [
+        "नेपाल देश हो।\nम राजनैतिक कुरा गर्छु।",
+        "नेपाल देश हो।\r\nम राजनैतिक कुरा गर्छु।",
+    ] {
+        let located = check_document(text);
+        let hit = located
+            .iter()
+            .find(|d| d.diagnostic.incorrect == "राजनैतिक")
+            .unwrap_or_else(|| panic!("expected राजनैतिक to be flagged: {located:?}"));
+        assert_eq!((hit.line, hit.column), (2, 3));
+        assert_eq!((hit.end_line, hit.end_column), (2, 11));
+    }
+}
+
+#[test]
+fn explanation_in_english_differs_from_nepali() {
+    let diag = check_word("राजनैतिक").expect("राजनैतिक should be flagged");
+    assert_eq!(diag.explanation_in(Language::default()), diag.explanation);
+
+    let english = diag.explanation_in(Language::English);
+    assert!(!english.is_empty());
+    assert_ne!(english, diag.explanation);
+    assert!(english.is_ascii(), "expected English text, got: {english}");
+}
+
+#[test]
+fn explanation_in_english_for_word_joining() {
+    let diags = check_text("यो काम हुनसक्छ।");
+    let diag = diags
+        .iter()
+        .find(|d| d.incorrect == "हुनसक्छ")
+        .unwrap_or_else(|| panic!("Expected padabiyog diagnostic, got: {diags:?}"));
+    let english = diag.explanation_in(Language::English);
+    assert!(english.contains("padayog"), "got: {english}");
+    assert!(!english.contains('ऋ'), "got: {english}");
+}
+
+/// Opt-in suggestion list: an unknown word one matra away gets ranked candidates.
+#[test]
+fn suggest_option_attaches_candidates_to_unknown_word() {
+    let options = CheckOptions {
+        suggest: true,
+        ..Default::default()
+    };
+
+    let diags = check_text_with_options("सरकर", options.clone());
+    let diag = diags
+        .iter()
+        .find(|d| d.incorrect == "सरकर")
+        .expect("unknown word should receive suggestions");
+    assert!(matches!(diag.kind, DiagnosticKind::Ambiguous));
+    assert!(diag.confidence < 0.72);
+    assert!(!diag.suggestions.is_empty() && diag.suggestions.len() <= 3);
+    assert_eq!(diag.correction, diag.suggestions[0]);
+    assert!(
+        diag.suggestions.iter().any(|s| s == "सरकार"),
+        "Expected सरकार among suggestions, got: {:?}",
+        diag.suggestions
+    );
+
+    assert!(check_text_with_options("सरकार", options).is_empty());
+}
+
+/// Equally close candidates are ordered by origin agreement with the input:
+/// किताबु is classified देशज, so देशज किताबी outranks आगन्तुक किताब.
+#[test]
+fn suggestions_prefer_same_origin_candidate() {
+    let mut builder = KoshaBuilder::new();
+    for word in ["किताब", "किताबी"] {
+        builder.insert(word, WordEntry { word, pos: "" });
+    }
+    let lex = builder.build().expect("lexicon should build");
+    let options = CheckOptions {
+        suggest: true,
+        ..Default::default()
+    };
+
+    let diags = check_text_with_kosha("किताबु", options, &lex);
+    let diag = diags
+        .iter()
+        .find(|d| d.incorrect == "किताबु")
+        .expect("unknown word should receive suggestions");
+    assert_eq!(diag.suggestions, ["किताबी", "किताब"]);
+    assert_eq!(diag.correction, "किताबी");
+}
+
+#[test]
+fn suggestions_are_empty_without_opt_in() {
+    let diags = check_text("सरकर");
+    assert!(diags.iter().all(|d| d.suggestions.is_empty()));
+}
+
+#[test]
+fn aagantuk_sibilant_fix_is_loanword_category() {
+    let diag = check_word("रजिष्टर").expect("रजिष्टर should be flagged");
+    assert_eq!(diag.correction, "रजिस्टर");
+    assert_eq!(diag.category.as_code(), "Loanword");
+    assert_eq!(diag.category, DiagnosticCategory::Loanword);
+}
+
+#[test]
+fn check_text_diff_reports_only_new_errors() {
+    let old = "अत्याधिक वर्षा भयो।";
+    let new = "हिजो अत्याधिक वर्षा भयो र राजनैतिक सभा रोकियो।";
+
+    let diags = check_text_diff(old, new);
+    assert_eq!(
+        diags.len(),
+        1,
+        "Expected only the new error, got: {diags:?}"
+    );
+    assert_eq!(diags[0].incorrect, "राजनैतिक");
+    assert_eq!(&new[diags[0].span.0..diags[0].span.1], "राजनैतिक");
+
+    assert!(check_text_diff(new, new).is_empty());
+}
+
+#[test]
+fn promote_variants_to_errors_raises_kind() {
+    let text = "कार्यक्रमको सम्बन्धमा छलफल भयो।";
+    let is_style = |d: &&varnavinyas_parikshak::Diagnostic| {
+        d.rule == varnavinyas_prakriya::Rule::Vyakaran("section4-phrase-style")
+    };
+
+    let default = check_text_with_options(
+        text,
+        CheckOptions {
+            grammar: true,
+            ..Default::default()
+        },
+    );
+    let variant = default.iter().find(is_style).expect("style variant");
+    assert!(matches!(variant.kind, DiagnosticKind::Variant));
+
+    let strict = check_text_with_options(
+        text,
+        CheckOptions {
+            grammar: true,
+            promote_variants_to_errors: true,
+            ..Default::default()
+        },
+    );
+    let promoted = strict.iter().find(is_style).expect("style diagnostic");
+    assert!(matches!(promoted.kind, DiagnosticKind::Error));
+    assert_eq!(promoted.confidence, variant.confidence);
+    assert!(
+        strict
+            .iter()
+            .all(|d| !matches!(d.kind, DiagnosticKind::Variant))
+    );
+}
+
+#[test]
+fn error_categories_downgrade_other_errors() {
+    let text = "भगवान आए.";
+    let is_halanta = |d: &&varnavinyas_parikshak::Diagnostic| d.incorrect == "भगवान";
+    let is_punct =
+        |d: &&varnavinyas_parikshak::Diagnostic| d.category == DiagnosticCategory::Punctuation;
+
+    let all = check_text(text);
+    assert!(matches!(
+        all.iter()
+            .find(is_halanta)
+            .expect("halanta diagnostic")
+            .kind,
+        DiagnosticKind::Error
+    ));
+
+    let only_punctuation = check_text_with_options(
+        text,
+        CheckOptions {
+            error_categories: Some(HashSet::from([DiagnosticCategory::Punctuation])),
+            ..Default::default()
+        },
+    );
+    let halanta = only_punctuation.iter().find(is_halanta).expect("halanta");
+    assert!(matches!(halanta.kind, DiagnosticKind::Variant));
+    let punct = only_punctuation.iter().find(is_punct).expect("punctuation");
+    assert!(matches!(punct.kind, DiagnosticKind::Error));
+}
+
+#[test]
+fn diagnostic_category_code_roundtrip() {
+    for category in [
+        DiagnosticCategory::HrasvaDirgha,
+        DiagnosticCategory::Halanta,
+        DiagnosticCategory::Punctuation,
+        DiagnosticCategory::Loanword,
+    ] {
+        assert_eq!(
+            DiagnosticCategory::from_code(category.as_code()),
+            Some(category)
+        );
+    }
+    assert_eq!(DiagnosticCategory::from_code("Spelling"), None);
+}
+
+#[test]
+fn check_words_matches_check_word_per_element() {
+    let words = ["नेपाल", "अत्याधिक", "घर", "अत्याधिक"];
+    let diags = check_words(&words);
+
+    assert_eq!(diags.len(), words.len());
+    for (word, diag) in words.iter().zip(&diags) {
+        assert_eq!(diag, &check_word(word), "mismatch for {word}");
+    }
+    assert!(diags[0].is_none());
+    assert_eq!(
+        diags[1].as_ref().map(|d| d.correction.as_str()),
+        Some("अत्यधिक")
+    );
+    assert!(diags[2].is_none());
+}
+
+#[cfg(feature = "compound-check")]
+#[test]
+fn check_compounds_corrects_misspelled_component() {
+    let text = "राजनैतिकदल";
+    assert!(check_text(text).is_empty());
+
+    let diags = check_text_with_options(
+        text,
+        CheckOptions {
+            check_compounds: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(diags.len(), 1, "got: {diags:?}");
+    assert_eq!(diags[0].incorrect, "राजनैतिकदल");
+    assert_eq!(diags[0].correction, "राजनीतिकदल");
+    assert_eq!(diags[0].span, (0, text.len()));
+    assert!(diags[0].confidence < 1.0);
+
+    // Compounds whose components are already correct are left as before.
+    let text = "सामाजिकसेवा";
+    let with_compounds = check_text_with_options(
+        text,
+        CheckOptions {
+            check_compounds: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(with_compounds, check_text(text));
+}
+
+#[test]
+fn rule_stats_count_halanta_hits() {
+    let (diags, stats) = check_text_with_stats("महान व्यक्ति र श्रीमान आए।");
+    // महान → महान्, श्रीमान → श्रीमान्: both cite the halanta rule 3(ङ).
+    assert_eq!(stats.count("3(ङ)"), 2, "got: {diags:?}");
+    assert_eq!(stats.count("3(क)"), 0);
+    assert_eq!(stats.total(), diags.len());
+    assert_eq!(stats.most_common()[0], ("3(ङ)", 2));
+}
+
+#[test]
+fn variant_spellings_suggest_preferred_form() {
+    let options = CheckOptions {
+        variant_spellings: true,
+        ..Default::default()
+    };
+    let diags = check_text_with_options("काम शुरु भयो।", options.clone());
+    let hit = diags
+        .iter()
+        .find(|d| d.incorrect == "शुरु")
+        .unwrap_or_else(|| panic!("Expected variant diagnostic, got: {diags:?}"));
+    assert_eq!(hit.correction, "सुरु");
+    assert_eq!(hit.kind, DiagnosticKind::Variant);
+    assert!(hit.confidence < 0.8);
+
+    let preferred = check_text_with_options("काम सुरु भयो।", options);
+    assert!(preferred.is_empty(), "got: {preferred:?}");
+
+    // Off by default.
+    assert!(
+        check_text("हाम्रो शहर सफा छ।")
+            .iter()
+            .all(|d| d.incorrect != "शहर")
+    );
+}
+
+#[test]
+fn non_nfc_lexicon_word_is_not_flagged() {
+    // जुड़े typed with the precomposed ड़ (U+095C) is the same word.
+    assert_eq!(check_word("जु\u{095C}े"), None);
+    assert!(check_text("उनी जु\u{095C}े।").is_empty());
+}
+
+#[test]
+fn spelling_diagnostic_carries_word_origin() {
+    let d = check_word("विद्वान").expect("missing halanta should be flagged");
+    assert_eq!(d.correction, "विद्वान्");
+    assert_eq!(d.origin, Some(Origin::Tatsam));
+
+    // Loanword swaps report the origin that drove the fix.
+    let d = check_word("रजिष्टर").expect("loanword ष should be flagged");
+    assert_eq!(d.category, DiagnosticCategory::Loanword);
+    assert_eq!(d.origin, Some(Origin::Aagantuk));
+
+    // Punctuation findings are not origin-driven.
+    let diags = check_text("नेपाल राम्रो छ.");
+    assert!(
+        diags
+            .iter()
+            .filter(|d| d.category == DiagnosticCategory::Punctuation)
+            .all(|d| d.origin.is_none())
+    );
+}
+
+#[test]
+fn diagnostic_catalog_covers_every_category() {
+    let catalog = diagnostic_catalog();
+    for category in [
+        DiagnosticCategory::HrasvaDirgha,
+        DiagnosticCategory::Chandrabindu,
+        DiagnosticCategory::ShaShaS,
+        DiagnosticCategory::RiKri,
+        DiagnosticCategory::Halanta,
+        DiagnosticCategory::YaE,
+        DiagnosticCategory::KshaChhya,
+        DiagnosticCategory::Sandhi,
+        DiagnosticCategory::Punctuation,
+        DiagnosticCategory::ShuddhaTable,
+        DiagnosticCategory::Loanword,
+    ] {
+        assert!(
+            catalog.iter().any(|e| e.category == category),
+            "no catalog entry for {category:?}"
+        );
+    }
+
+    let ids: HashSet<&str> = catalog.iter().map(|e| e.id).collect();
+    assert_eq!(ids.len(), catalog.len(), "catalog ids must be unique");
+
+    for id in ["namayogi-join", "padabiyog-split", "variant-spelling"] {
+        assert!(ids.contains(id), "no catalog entry for {id}");
+    }
+
+    let plural = catalog.iter().find(|e| e.id == "hd-plural-haru").unwrap();
+    assert_eq!(plural.example, Some(("केटाहरु", "केटाहरू")));
+    assert!(plural.explanation.contains("हरू"));
+}
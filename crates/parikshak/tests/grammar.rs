@@ -41,6 +41,34 @@ fn grammar_pass_suppresses_low_confidence_plural_after_quantifier() {
     );
 }
 
+#[cfg(feature = "grammar-pass")]
+#[test]
+fn promoted_variants_keep_quantifier_plural_suppressed() {
+    // Promotion raises the kind of emitted variants; it must not surface the
+    // sub-threshold quantifier-plural hint as an error.
+    let text = "धेरै मानिसहरु आए।";
+    let diags = check_text_with_options(
+        text,
+        CheckOptions {
+            grammar: true,
+            promote_variants_to_errors: true,
+            ..Default::default()
+        },
+    );
+
+    assert!(
+        !diags.iter().any(|d| {
+            d.rule == varnavinyas_prakriya::Rule::Vyakaran("quantifier-plural-redundancy")
+        }),
+        "Suppressed quantifier-plural hint should stay suppressed, got: {diags:?}"
+    );
+    assert!(
+        diags
+            .iter()
+            .all(|d| !matches!(d.kind, DiagnosticKind::Variant))
+    );
+}
+
 #[cfg(feature = "grammar-pass")]
 #[test]
 fn grammar_pass_suppresses_low_confidence_ergative_with_intransitive_predicate() {
@@ -82,3 +110,146 @@ fn grammar_pass_suppresses_low_confidence_genitive_mismatch_before_plural() {
         "Low-confidence suffix heuristic should be suppressed, got: {diags:?}"
     );
 }
+
+#[cfg(feature = "grammar-pass")]
+#[test]
+fn grammar_pass_flags_subject_verb_person_mismatch() {
+    let text = "म जान्छ।";
+    let diags = check_text_with_options(
+        text,
+        CheckOptions {
+            grammar: true,
+            ..Default::default()
+        },
+    );
+
+    let agreement = diags
+        .iter()
+        .find(|d| d.rule == varnavinyas_prakriya::Rule::Vyakaran("subject-verb-person"))
+        .unwrap_or_else(|| panic!("Expected person agreement hint, got: {diags:?}"));
+    assert_eq!(agreement.incorrect, "जान्छ");
+    assert_eq!(agreement.correction, "जान्छु");
+    assert_eq!(&text[agreement.span.0..agreement.span.1], "जान्छ");
+}
+
+#[cfg(feature = "grammar-pass")]
+#[test]
+fn grammar_pass_accepts_agreeing_or_non_nominative_subjects() {
+    for text in [
+        "म जान्छु।",
+        "हामी घर जान्छौं।",
+        "मलाई थाहा छ।",
+        "तपाईं जानुहुन्छ।",
+        "म तुच्छ मान्छे हुँ।",
+    ] {
+        let diags = check_text_with_options(
+            text,
+            CheckOptions {
+                grammar: true,
+                ..Default::default()
+            },
+        );
+        assert!(
+            !diags
+                .iter()
+                .any(|d| d.rule == varnavinyas_prakriya::Rule::Vyakaran("subject-verb-person")),
+            "Unexpected person agreement hint for {text}: {diags:?}"
+        );
+    }
+}
+
+#[cfg(feature = "grammar-pass")]
+#[test]
+fn grammar_pass_person_agreement_reads_the_caller_lexicon() {
+    use varnavinyas_kosha::{Kosha, KoshaBuilder, WordEntry, kosha};
+    use varnavinyas_parikshak::check_text_with_kosha;
+
+    // जान्छ is a verb in the embedded lexicon; the overlay relists it as a
+    // noun, which the agreement check must then skip.
+    const NOUN: &str = "जान्छ";
+    let text = "म जान्छ।";
+    let options = CheckOptions {
+        grammar: true,
+        ..Default::default()
+    };
+    let is_agreement = |d: &varnavinyas_parikshak::Diagnostic| {
+        d.rule == varnavinyas_prakriya::Rule::Vyakaran("subject-verb-person")
+    };
+
+    let base = check_text_with_options(text, options.clone());
+    assert!(
+        base.iter().any(is_agreement),
+        "Expected person agreement hint without the overlay, got: {base:?}"
+    );
+
+    let mut builder = KoshaBuilder::new();
+    builder.insert(
+        NOUN,
+        WordEntry {
+            word: NOUN,
+            pos: "ना.",
+        },
+    );
+    let layered = Kosha::layered(kosha(), builder.build().expect("overlay should build"));
+    let diags = check_text_with_kosha(text, options, &layered);
+    assert!(
+        !diags.iter().any(is_agreement),
+        "Overlay noun should not read as a verb, got: {diags:?}"
+    );
+}
+
+#[cfg(feature = "grammar-pass")]
+#[test]
+fn grammar_pass_flags_honorific_register_mismatch() {
+    let text = "तपाईँ भित्र जा।";
+    let diags = check_text_with_options(
+        text,
+        CheckOptions {
+            grammar: true,
+            ..Default::default()
+        },
+    );
+
+    let hint = diags
+        .iter()
+        .find(|d| d.rule == varnavinyas_prakriya::Rule::Vyakaran("honorific-register"))
+        .unwrap_or_else(|| panic!("Expected honorific register hint, got: {diags:?}"));
+    assert_eq!(hint.incorrect, "जा");
+    assert_eq!(hint.correction, "जानुहोस्");
+    assert!(matches!(hint.kind, DiagnosticKind::Variant));
+    assert!(hint.confidence < 0.8);
+}
+
+#[cfg(feature = "grammar-pass")]
+#[test]
+fn grammar_pass_accepts_matching_honorific_register() {
+    let text = "तपाईँ भित्र जानुहोस्।";
+    let diags = check_text_with_options(
+        text,
+        CheckOptions {
+            grammar: true,
+            ..Default::default()
+        },
+    );
+    assert!(
+        !diags
+            .iter()
+            .any(|d| d.rule == varnavinyas_prakriya::Rule::Vyakaran("honorific-register")),
+        "Matching register should not be flagged, got: {diags:?}"
+    );
+}
+
+#[cfg(feature = "grammar-pass")]
+#[test]
+fn diagnostic_catalog_lists_grammar_agreement() {
+    let catalog = varnavinyas_parikshak::diagnostic_catalog();
+    let person = catalog
+        .iter()
+        .find(|e| e.id == "subject-verb-person")
+        .expect("subject-verb-person entry");
+    assert_eq!(
+        person.citation,
+        varnavinyas_prakriya::Rule::Vyakaran("subject-verb-person")
+    );
+    assert!(catalog.iter().any(|e| e.id == "honorific-register"));
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "markdown")]
+use varnavinyas_parikshak::check_markdown;
+
+#[cfg(feature = "markdown")]
+#[test]
+fn markdown_prose_misspelling_is_flagged() {
+    let text = "# शीर्षक\n\nयो राजनैतिक कुरा हो।\n";
+    let diags = check_markdown(text);
+    let hit = diags
+        .iter()
+        .find(|d| d.incorrect == "राजनैतिक")
+        .unwrap_or_else(|| panic!("expected राजनैतिक in prose to be flagged: {diags:?}"));
+    assert_eq!(&text[hit.span.0..hit.span.1], "राजनैतिक");
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn markdown_skips_code_and_urls() {
+    for text in [
+        "यो `राजनैतिक` शब्द हो।",
+        "```\nराजनैतिक\n```\nयो ठिक हो।",
+        "[लिङ्क](https://example.com/राजनैतिक) हेर्नुहोस्।",
+        "<https://example.com/राजनैतिक> हेर्नुहोस्।",
+        "हेर्नुहोस् https://example.com/राजनैतिक",
+    ] {
+        let diags = check_markdown(text);
+        assert!(
+            !diags.iter().any(|d| d.incorrect == "राजनैतिक"),
+            "code/URL content should be skipped in {text:?}: {diags:?}"
+        );
+    }
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn markdown_spans_point_into_original_text() {
+    let text = "`code` र राजनैतिक";
+    let diags = check_markdown(text);
+    let hit = diags
+        .iter()
+        .find(|d| d.incorrect == "राजनैतिक")
+        .unwrap_or_else(|| panic!("expected a diagnostic: {diags:?}"));
+    assert_eq!(&text[hit.span.0..hit.span.1], "राजनैतिक");
+}
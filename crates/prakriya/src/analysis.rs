@@ -1,6 +1,24 @@
 use crate::engine;
 use crate::rule::Rule;
-use varnavinyas_shabda::{Origin, OriginSource, classify_with_provenance, source_language};
+use crate::rule_spec::RuleContext;
+use varnavinyas_akshar::split_aksharas;
+use varnavinyas_samasa::{SamasaCandidate, analyze_compound};
+use varnavinyas_shabda::{
+    Origin, OriginSource, classify_with_provenance, decompose, source_language,
+};
+use varnavinyas_vyakaran::{Features, split_inflection};
+
+/// समास candidate विश्लेषणमा समावेश गर्न चाहिने न्यूनतम score।
+///
+/// यसभन्दा कम score का candidate प्रायः सामान्य शब्दको संयोगवश भएको
+/// lexical split हुन्छन् (जस्तै: नेप + आल)।
+const SAMASA_MIN_SCORE: f32 = 0.8;
+
+/// समास विश्लेषण गर्न चाहिने न्यूनतम अक्षर संख्या।
+///
+/// सबैभन्दा छोटो समास पनि एक-अक्षरे पूर्वपद र दुई-अक्षरे उत्तरपदको हुन्छ
+/// (त्रि + लोक); योभन्दा छोटा शब्दमा sandhi split चलाउनु व्यर्थ हुन्छ।
+const SAMASA_MIN_AKSHARAS: usize = 3;
 
 /// शब्दको वर्णविन्यास विश्लेषण (उत्पत्ति-आधारित व्याख्यासहित)।
 #[derive(Debug, Clone)]
@@ -21,6 +39,10 @@ pub struct WordAnalysis {
     pub correction: Option<String>,
     /// Academy नियम सन्दर्भसहित व्याख्यात्मक टिप्पणी।
     pub rule_notes: Vec<RuleNote>,
+    /// सबैभन्दा उच्च score भएको समास विश्लेषण, [`SAMASA_MIN_SCORE`] नाघेमा मात्र।
+    ///
+    /// छोटा शब्द र आगन्तुक शब्दमा समास विश्लेषण चलाइँदैन।
+    pub samasa: Option<SamasaCandidate>,
 }
 
 /// शब्द सही/गलत हुनुको कारण बताउने टिप्पणी।
@@ -47,13 +69,15 @@ pub fn analyze(input: &str) -> WordAnalysis {
             is_correct: true,
             correction: None,
             rule_notes: Vec::new(),
+            samasa: None,
         };
     }
 
     let origin_decision = classify_with_provenance(input);
     let origin = origin_decision.origin;
     let source_lang = source_language(input).map(String::from);
-    let prakriya = engine::derive(input);
+    // उत्पत्ति निर्णय एकपटक मात्र गर्ने: derive ले यही निर्णय पुनः प्रयोग गर्छ।
+    let prakriya = engine::derive_with(input, &RuleContext::with_origin(input, origin_decision));
     let mut rule_notes = Vec::new();
 
     if prakriya.is_correct {
@@ -68,6 +92,7 @@ pub fn analyze(input: &str) -> WordAnalysis {
             });
         }
     }
+    rule_notes.extend(morphology_note(input));
 
     WordAnalysis {
         word: input.to_string(),
@@ -82,7 +107,52 @@ pub fn analyze(input: &str) -> WordAnalysis {
             Some(prakriya.output)
         },
         rule_notes,
+        samasa: if origin == Origin::Aagantuk {
+            None
+        } else {
+            top_samasa_candidate(input)
+        },
+    }
+}
+
+/// `analyze_compound` को शीर्ष candidate, पर्याप्त विश्वसनीय भएमा मात्र।
+fn top_samasa_candidate(word: &str) -> Option<SamasaCandidate> {
+    if split_aksharas(word).len() < SAMASA_MIN_AKSHARAS {
+        return None;
     }
+    analyze_compound(word)
+        .into_iter()
+        .next()
+        .filter(|c| c.score > SAMASA_MIN_SCORE)
+}
+
+/// पहिचान भएका उपसर्ग, बहुवचन प्रत्यय र कारक चिह्नको टिप्पणी,
+/// जस्तै: "बहुवचन प्रत्यय -हरू, सम्प्रदान कारक -लाई"।
+///
+/// कुनै पनि रूप पहिचान नभए `None`।
+fn morphology_note(word: &str) -> Option<RuleNote> {
+    let mut parts: Vec<String> = decompose(word)
+        .prefixes
+        .iter()
+        .map(|p| format!("उपसर्ग {p}-"))
+        .collect();
+
+    let inflection = split_inflection(word);
+    if let Some(plural) = inflection.plural {
+        parts.push(format!("बहुवचन प्रत्यय -{plural}"));
+    }
+    if let Some((marker, case)) = inflection.case {
+        let label = Features {
+            case: Some(case),
+            ..Features::default()
+        };
+        parts.push(format!("{label} -{marker}"));
+    }
+
+    (!parts.is_empty()).then(|| RuleNote {
+        rule: Rule::Vyakaran("morphology"),
+        explanation: parts.join(", "),
+    })
 }
 
 /// पहिले नै सही शब्दका लागि व्याख्यात्मक टिप्पणी बनाउने।
@@ -252,6 +322,55 @@ mod tests {
         assert_eq!(notes[8].rule, Rule::VarnaVinyasNiyam("3(ङ)"));
     }
 
+    #[test]
+    fn compound_analysis_includes_samasa() {
+        let analysis = analyze("सूर्योदय");
+        let samasa = analysis
+            .samasa
+            .expect("सूर्योदय should carry a samasa candidate");
+        assert_eq!(samasa.left, "सूर्य");
+        assert_eq!(samasa.right, "उदय");
+        assert_eq!(
+            samasa.samasa_type,
+            varnavinyas_samasa::SamasaType::Tatpurusha
+        );
+        assert!(samasa.score > SAMASA_MIN_SCORE);
+    }
+
+    #[test]
+    fn short_and_loanwords_skip_samasa() {
+        assert!(analyze("घर").samasa.is_none());
+        let loan = analyze("कम्प्युटर");
+        assert_eq!(loan.origin, Origin::Aagantuk);
+        assert!(loan.samasa.is_none());
+    }
+
+    #[test]
+    fn inflected_word_carries_morphology_note() {
+        let analysis = analyze("केटाहरूलाई");
+        let note = analysis
+            .rule_notes
+            .iter()
+            .find(|n| n.rule == Rule::Vyakaran("morphology"))
+            .expect("केटाहरूलाई should carry a morphology note");
+        assert!(note.explanation.contains("बहुवचन"), "{}", note.explanation);
+        assert!(note.explanation.contains("सम्प्रदान"), "{}", note.explanation);
+
+        let bare = analyze("आमा");
+        assert!(
+            !bare
+                .rule_notes
+                .iter()
+                .any(|n| n.rule == Rule::Vyakaran("morphology"))
+        );
+    }
+
+    #[test]
+    fn simple_word_has_no_samasa() {
+        assert!(analyze("घर").samasa.is_none());
+        assert!(analyze("नेपाल").samasa.is_none());
+    }
+
     #[test]
     fn tadbhav_templates_emit_expected_notes() {
         let mut notes = Vec::new();
@@ -868,3 +868,110 @@ pub fn lookup(word: &str) -> Option<&'static CorrectionEntry> {
 pub fn contains(word: &str) -> bool {
     lookup(word).is_some()
 }
+
+/// A data-entry problem in [`CORRECTION_TABLE`].
+#[cfg(any(test, feature = "validate"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// The `correct` form is not a kosha word.
+    CorrectionNotInKosha {
+        incorrect: &'static str,
+        correct: &'static str,
+    },
+    /// The `incorrect` key is itself a kosha word.
+    IncorrectIsLexical {
+        incorrect: &'static str,
+        correct: &'static str,
+    },
+}
+
+/// Cross-check the correction table against the kosha.
+///
+/// Every `correct` form should be a lexicon word and no `incorrect` key
+/// should be one. The kosha does record some common variant spellings
+/// (राजनैतिक, महत्व) as headwords, so a non-empty result is expected; the
+/// useful signal is a new entry showing up.
+#[cfg(any(test, feature = "validate"))]
+pub fn validate_correction_table() -> Vec<Inconsistency> {
+    let lex = varnavinyas_kosha::kosha();
+    let mut problems = Vec::new();
+    for (incorrect, entry) in CORRECTION_TABLE.iter() {
+        if !lex.contains(entry.correct) {
+            problems.push(Inconsistency::CorrectionNotInKosha {
+                incorrect,
+                correct: entry.correct,
+            });
+        }
+        if lex.contains(incorrect) {
+            problems.push(Inconsistency::IncorrectIsLexical {
+                incorrect,
+                correct: entry.correct,
+            });
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Correct forms absent from the word list: inflections, compounds and
+    /// loanwords the dictionary does not enumerate.
+    const KNOWN_MISSING_CORRECTIONS: &[&str] = &["पुनःसंरचना", "फाउन्डेसन", "भनिन्", "मितिनीले"];
+
+    /// Incorrect keys the dictionary lists as variant spellings or as
+    /// unrelated words (संसद, भगवान without हलन्त).
+    const KNOWN_LEXICAL_INCORRECT: &[&str] = &[
+        "असक्षम",
+        "उपरोक्त",
+        "एकिन",
+        "औद्योगीकरण",
+        "छेत्र",
+        "जान्छौ",
+        "झण्डा",
+        "पीरो",
+        "पुनरावलोकन",
+        "पुनर्स्थापना",
+        "फुपु",
+        "बागमती",
+        "बुद्धिमान",
+        "ब्यहोरा",
+        "भएकोमा",
+        "भगवान",
+        "भनि",
+        "महत्व",
+        "मीठो",
+        "यथार्थता",
+        "रजिष्टर",
+        "राजनैतिक",
+        "वृक्षारोपण",
+        "व्यवहारिक",
+        "शहीद",
+        "श्रीमान",
+        "श्रृङ्गार",
+        "संसद",
+        "सपाङ्ग",
+        "सामाग्री",
+        "सुरुवात",
+    ];
+
+    #[test]
+    fn correction_table_has_only_known_inconsistencies() {
+        let unexpected: Vec<_> = validate_correction_table()
+            .into_iter()
+            .filter(|problem| match problem {
+                Inconsistency::CorrectionNotInKosha { correct, .. } => {
+                    !KNOWN_MISSING_CORRECTIONS.contains(correct)
+                }
+                Inconsistency::IncorrectIsLexical { incorrect, .. } => {
+                    !KNOWN_LEXICAL_INCORRECT.contains(incorrect)
+                }
+            })
+            .collect();
+        assert!(
+            unexpected.is_empty(),
+            "New correction table inconsistencies: {unexpected:?}"
+        );
+    }
+}
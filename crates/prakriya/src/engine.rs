@@ -4,9 +4,10 @@ use crate::correction_table;
 use crate::hrasva_dirgha;
 use crate::orthographic;
 use crate::prakriya::Prakriya;
-use crate::rule_spec::{DiagnosticKind, PatternRule, RuleCategory};
+use crate::rule_spec::{DiagnosticKind, PatternRule, RuleCategory, RuleContext, RuleSpec};
 use crate::step::Step;
 use crate::structural;
+use varnavinyas_shabda::Origin;
 
 /// All pattern rules, sorted by priority (lower = higher priority).
 static PATTERN_RULES: LazyLock<Vec<PatternRule>> = LazyLock::new(|| {
@@ -29,6 +30,10 @@ static PATTERN_RULES: LazyLock<Vec<PatternRule>> = LazyLock::new(|| {
             spec: hrasva_dirgha::SPEC_SUFFIX_NU,
             apply: hrasva_dirgha::rule_suffix_nu_hrasva,
         },
+        PatternRule {
+            spec: hrasva_dirgha::SPEC_PLURAL_HARU,
+            apply: hrasva_dirgha::rule_plural_haru,
+        },
         PatternRule {
             spec: hrasva_dirgha::SPEC_SUFFIX_ELI,
             apply: hrasva_dirgha::rule_suffix_eli_hrasva,
@@ -53,11 +58,23 @@ static PATTERN_RULES: LazyLock<Vec<PatternRule>> = LazyLock::new(|| {
             spec: hrasva_dirgha::SPEC_KOSHA_BACKED,
             apply: hrasva_dirgha::kosha_backed_dirgha_correction,
         },
-        // Orthographic (300–330)
+        // Orthographic (295–335)
+        PatternRule {
+            spec: orthographic::SPEC_VERB_CHANDRABINDU,
+            apply: orthographic::rule_verb_ending_chandrabindu,
+        },
         PatternRule {
             spec: orthographic::SPEC_CHANDRABINDU,
             apply: orthographic::rule_chandrabindu,
         },
+        PatternRule {
+            spec: orthographic::SPEC_PUNAH_VISARGA,
+            apply: orthographic::rule_punah_visarga,
+        },
+        PatternRule {
+            spec: orthographic::SPEC_NIH_DUH_PREFIX,
+            apply: orthographic::rule_nih_duh_prefix,
+        },
         PatternRule {
             spec: orthographic::SPEC_SIBILANT,
             apply: orthographic::rule_sibilant,
@@ -70,6 +87,10 @@ static PATTERN_RULES: LazyLock<Vec<PatternRule>> = LazyLock::new(|| {
             spec: orthographic::SPEC_HALANTA,
             apply: orthographic::rule_halanta,
         },
+        PatternRule {
+            spec: orthographic::SPEC_CONJUNCT_NORMALIZE,
+            apply: orthographic::rule_conjunct_normalize,
+        },
         // Orthographic kosha-backed (340–360)
         PatternRule {
             spec: orthographic::SPEC_AADHI_VRIDDHI,
@@ -79,6 +100,14 @@ static PATTERN_RULES: LazyLock<Vec<PatternRule>> = LazyLock::new(|| {
             spec: orthographic::SPEC_YA_E,
             apply: orthographic::rule_ya_e,
         },
+        PatternRule {
+            spec: orthographic::SPEC_BA_VA,
+            apply: orthographic::rule_ba_va,
+        },
+        PatternRule {
+            spec: orthographic::SPEC_INTERNAL_CONJUNCT,
+            apply: orthographic::rule_internal_conjunct,
+        },
         PatternRule {
             spec: orthographic::SPEC_KSHA_CHHYA,
             apply: orthographic::rule_ksha_chhya,
@@ -87,6 +116,10 @@ static PATTERN_RULES: LazyLock<Vec<PatternRule>> = LazyLock::new(|| {
             spec: orthographic::SPEC_GYA_GYAN,
             apply: orthographic::rule_gya_gyan,
         },
+        PatternRule {
+            spec: orthographic::SPEC_REDUNDANT_TAA,
+            apply: orthographic::rule_redundant_taa,
+        },
     ];
     rules.sort_by_key(|r| r.spec.priority);
     rules
@@ -100,6 +133,15 @@ static PATTERN_RULES: LazyLock<Vec<PatternRule>> = LazyLock::new(|| {
 /// 2. Pattern-based rules as fallback (generalizable heuristics)
 /// 3. If neither fires, the word is considered correct.
 pub fn derive(input: &str) -> Prakriya {
+    derive_with(input, &RuleContext::new(input))
+}
+
+/// [`derive`] with a caller-supplied [`RuleContext`].
+///
+/// Lets callers that check many words reuse origin decisions across calls:
+/// seed the context with [`RuleContext::with_origin`], and read back a
+/// freshly computed decision with [`RuleContext::computed_origin`].
+pub fn derive_with(input: &str, ctx: &RuleContext) -> Prakriya {
     if input.is_empty() {
         return Prakriya::correct("");
     }
@@ -110,7 +152,7 @@ pub fn derive(input: &str) -> Prakriya {
     }
 
     // Phase B: Try pattern rules (Heuristics)
-    if let Some(p) = try_pattern_rules(input) {
+    if let Some(p) = try_pattern_rules(input, ctx) {
         return p;
     }
 
@@ -118,16 +160,98 @@ pub fn derive(input: &str) -> Prakriya {
     Prakriya::correct(input)
 }
 
+/// Rule id reported for the static correction table in a [`DeriveReport`].
+pub const CORRECTION_TABLE_ID: &str = "correction-table";
+
+/// One rule tried by [`derive_debug`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleAttempt {
+    /// Rule id ([`CORRECTION_TABLE_ID`] or a pattern rule's `RuleSpec::id`).
+    pub id: &'static str,
+    /// Evaluation priority; `None` for the correction table, which always runs first.
+    pub priority: Option<u16>,
+    /// What the rule would have produced, or `None` if it did not fire.
+    pub output: Option<String>,
+}
+
+impl RuleAttempt {
+    /// Whether the rule fired.
+    pub fn fired(&self) -> bool {
+        self.output.is_some()
+    }
+}
+
+/// Rule-by-rule trace of a [`derive`] call, from [`derive_debug`].
+#[derive(Debug, Clone)]
+pub struct DeriveReport {
+    /// The input word.
+    pub input: String,
+    /// Every rule tried, in evaluation order.
+    pub attempts: Vec<RuleAttempt>,
+    /// Id of the rule whose result [`derive`] returns; `None` if no rule fired.
+    pub winner: Option<&'static str>,
+    /// The same result as [`derive`].
+    pub result: Prakriya,
+}
+
+/// Developer diagnostic: run every rule on `input` and report which fired.
+///
+/// Unlike [`derive`], which stops at the first rule that fires, this tries
+/// the correction table and all pattern rules, recording what each would
+/// have produced. Useful for finding out which rule won when a word gets an
+/// unexpected correction.
+pub fn derive_debug(input: &str) -> DeriveReport {
+    let ctx = RuleContext::new(input);
+    let mut attempts = Vec::with_capacity(PATTERN_RULES.len() + 1);
+    if !input.is_empty() {
+        attempts.push(RuleAttempt {
+            id: CORRECTION_TABLE_ID,
+            priority: None,
+            output: try_correction_table(input).map(|p| p.output),
+        });
+        attempts.extend(PATTERN_RULES.iter().map(|rule| RuleAttempt {
+            id: rule.spec.id,
+            priority: Some(rule.spec.priority),
+            output: (rule.apply)(input, &ctx).map(|p| p.output),
+        }));
+    }
+
+    DeriveReport {
+        input: input.to_string(),
+        winner: attempts.iter().find(|a| a.fired()).map(|a| a.id),
+        attempts,
+        result: derive_with(input, &ctx),
+    }
+}
+
 /// Try all pattern-based rules in priority order.
-fn try_pattern_rules(input: &str) -> Option<Prakriya> {
+///
+/// Rules share one [`RuleContext`], so the word's origin is classified at
+/// most once no matter how many origin-sensitive rules are consulted.
+fn try_pattern_rules(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
     for rule in PATTERN_RULES.iter() {
-        if let Some(p) = (rule.apply)(input) {
+        if let Some(p) = (rule.apply)(input, ctx) {
             return Some(p.with_metadata(rule.spec.category, rule.spec.kind));
         }
     }
     None
 }
 
+/// Specs of every pattern rule, in priority order.
+pub fn all_rule_specs() -> Vec<&'static RuleSpec> {
+    PATTERN_RULES.iter().map(|rule| &rule.spec).collect()
+}
+
+/// Specs of the pattern rules that can fire for words of `origin`, in
+/// priority order. Rules that ignore origin are listed under every origin.
+pub fn rules_for_origin(origin: Origin) -> Vec<&'static RuleSpec> {
+    PATTERN_RULES
+        .iter()
+        .map(|rule| &rule.spec)
+        .filter(|spec| spec.origins.contains(&origin))
+        .collect()
+}
+
 /// Try the static correction table.
 fn try_correction_table(input: &str) -> Option<Prakriya> {
     let entry = correction_table::lookup(input)?;
@@ -184,7 +308,8 @@ mod tests {
     fn production_priority_structural_beats_orthographic() {
         // श्रृङ्गार matches struct-shri (priority 100).
         // Verify the production path returns the structural correction.
-        let p = try_pattern_rules("श्रृङ्गार").expect("should fire a pattern rule");
+        let p = try_pattern_rules("श्रृङ्गार", &RuleContext::new("श्रृङ्गार"))
+            .expect("should fire a pattern rule");
         assert_eq!(p.output, "शृङ्गार");
         // The first step should cite ShuddhaAshuddha (structural rule), not VarnaVinyasNiyam.
         assert!(
@@ -194,6 +319,86 @@ mod tests {
         );
     }
 
+    /// Words that reach several origin-sensitive rules (chandrabindu, sibilant,
+    /// ri/kri, halanta, hrasva/dirgha) must still classify their origin once.
+    #[test]
+    fn origin_classified_at_most_once_per_word() {
+        for (word, expected) in [
+            ("नेपाल", "नेपाल"),
+            ("किताब", "किताब"),
+            ("संसार", "संसार"),
+            ("बांस", "बाँस"),
+            ("सीमा", "सीमा"),
+            ("दाजू", "दाजु"),
+            ("श्रृङ्गार", "शृङ्गार"),
+        ] {
+            let ctx = RuleContext::new(word);
+            assert_eq!(derive_with(word, &ctx).output, expected, "{word}");
+            assert!(
+                ctx.classify_calls.get() <= 1,
+                "{word}: origin classified {} times",
+                ctx.classify_calls.get()
+            );
+        }
+
+        // A correct word walks every rule, so it must hit the shared origin.
+        let ctx = RuleContext::new("नेपाल");
+        assert!(try_pattern_rules("नेपाल", &ctx).is_none());
+        assert_eq!(ctx.classify_calls.get(), 1);
+    }
+
+    #[test]
+    fn derive_debug_lists_every_attempt_and_winner() {
+        // बिद्वान is in the correction table (ब→व plus halanta); the halanta
+        // rule alone would also fire, but the table wins.
+        let report = derive_debug("बिद्वान");
+        assert_eq!(report.attempts.len(), PATTERN_RULES.len() + 1);
+        assert_eq!(report.attempts[0].id, CORRECTION_TABLE_ID);
+        assert_eq!(report.winner, Some(CORRECTION_TABLE_ID));
+        assert_eq!(report.result.output, "विद्वान्");
+
+        let fired: Vec<&str> = report
+            .attempts
+            .iter()
+            .filter(|a| a.fired())
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(fired, [CORRECTION_TABLE_ID, "ortho-halanta"]);
+        let halanta = report.attempts.iter().find(|a| a.id == "ortho-halanta");
+        assert_eq!(halanta.and_then(|a| a.output.as_deref()), Some("बिद्वान्"));
+    }
+
+    #[test]
+    fn rules_for_origin_respects_origin_gates() {
+        let ids = |origin| -> Vec<&str> {
+            rules_for_origin(origin)
+                .iter()
+                .map(|spec| spec.id)
+                .collect()
+        };
+        let tatsam = ids(Origin::Tatsam);
+        assert!(tatsam.contains(&"ortho-ri-kri"));
+        assert!(tatsam.contains(&"ortho-nih-duh-prefix"));
+        assert!(!tatsam.contains(&"ortho-sibilant"));
+        for origin in [Origin::Tadbhav, Origin::Deshaj, Origin::Aagantuk] {
+            let ids = ids(origin);
+            assert!(!ids.contains(&"ortho-ri-kri"), "{origin:?}");
+            assert!(!ids.contains(&"ortho-nih-duh-prefix"), "{origin:?}");
+            assert!(ids.contains(&"ortho-sibilant"), "{origin:?}");
+            // Ungated rules appear under every origin.
+            assert!(ids.contains(&"struct-shri"), "{origin:?}");
+        }
+        assert!(tatsam.contains(&"struct-shri"));
+    }
+
+    #[test]
+    fn derive_debug_correct_word_has_no_winner() {
+        let report = derive_debug("नेपाल");
+        assert!(report.winner.is_none());
+        assert!(report.attempts.iter().all(|a| !a.fired()));
+        assert!(report.result.is_correct);
+    }
+
     /// Guard against silent omissions: every known rule ID must be present in the registry.
     /// If you add a new SPEC_* + rule fn in a module, add its ID here — the test will
     /// fail until you also register it in PATTERN_RULES.
@@ -206,6 +411,7 @@ mod tests {
             "struct-panchham",
             // hrasva-dirgha
             "hd-suffix-nu",
+            "hd-plural-haru",
             "hd-suffix-eli",
             "hd-suffix-preserves",
             "hd-tadbhav",
@@ -213,14 +419,21 @@ mod tests {
             "hd-kinship",
             "hd-kosha-backed",
             // orthographic
+            "ortho-verb-chandrabindu",
             "ortho-chandrabindu",
+            "ortho-punah-visarga",
+            "ortho-nih-duh-prefix",
             "ortho-sibilant",
             "ortho-ri-kri",
             "ortho-halanta",
+            "ortho-conjunct-normalize",
             "ortho-aadhi-vriddhi",
             "ortho-ya-e",
+            "ortho-ba-va",
+            "ortho-internal-conjunct",
             "ortho-ksha-chhya",
             "ortho-gya-gyan",
+            "ortho-redundant-taa",
         ];
 
         let registered: Vec<&str> = PATTERN_RULES.iter().map(|r| r.spec.id).collect();
@@ -1,8 +1,11 @@
 use crate::prakriya::Prakriya;
 use crate::rule::Rule;
-use crate::rule_spec::{DiagnosticKind, RuleCategory, RuleSpec};
+use crate::rule_spec::{
+    ALL_ORIGINS, DiagnosticKind, NON_TATSAM, RuleCategory, RuleContext, RuleSpec,
+};
 use crate::step::Step;
-use varnavinyas_shabda::{Origin, classify};
+use varnavinyas_akshar::{dirgha_to_hrasva, hrasva_to_dirgha, matra_to_svar, split_aksharas};
+use varnavinyas_shabda::Origin;
 
 pub const SPEC_SUFFIX_NU: RuleSpec = RuleSpec {
     id: "hd-suffix-nu",
@@ -10,15 +13,27 @@ pub const SPEC_SUFFIX_NU: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 200,
     citation: Rule::VarnaVinyasNiyam("3(क)-suffix-नु"),
+    origins: ALL_ORIGINS,
     examples: &[("स्वीकार्नु", "स्विकार्नु")],
 };
 
+pub const SPEC_PLURAL_HARU: RuleSpec = RuleSpec {
+    id: "hd-plural-haru",
+    category: RuleCategory::HrasvaDirgha,
+    kind: DiagnosticKind::Error,
+    priority: 205,
+    citation: Rule::VarnaVinyasNiyam("3(क)-suffix-हरू"),
+    origins: ALL_ORIGINS,
+    examples: &[("केटाहरु", "केटाहरू"), ("किताबहरु", "किताबहरू")],
+};
+
 pub const SPEC_SUFFIX_ELI: RuleSpec = RuleSpec {
     id: "hd-suffix-eli",
     category: RuleCategory::HrasvaDirgha,
     kind: DiagnosticKind::Error,
     priority: 210,
     citation: Rule::VarnaVinyasNiyam("3(क)-suffix-एली"),
+    origins: ALL_ORIGINS,
     examples: &[("पूर्वेली", "पुर्वेली")],
 };
 
@@ -28,6 +43,7 @@ pub const SPEC_SUFFIX_PRESERVES: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 220,
     citation: Rule::VarnaVinyasNiyam("3(क)(उ)"),
+    origins: ALL_ORIGINS,
     examples: &[("पुर्वी", "पूर्वी"), ("पुर्वीय", "पूर्वीय")],
 };
 
@@ -37,6 +53,7 @@ pub const SPEC_TADBHAV: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 230,
     citation: Rule::VarnaVinyasNiyam("3(क)-12"),
+    origins: NON_TATSAM,
     examples: &[("मीठो", "मिठो")],
 };
 
@@ -46,6 +63,7 @@ pub const SPEC_DIRGHA_ENDINGS: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 240,
     citation: Rule::VarnaVinyasNiyam("3(ई)"),
+    origins: NON_TATSAM,
     examples: &[("भनि", "भनी"), ("गरि", "गरी")],
 };
 
@@ -55,6 +73,7 @@ pub const SPEC_KINSHIP: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 250,
     citation: Rule::VarnaVinyasNiyam("3(क)(इ)-1"),
+    origins: &[Origin::Tadbhav, Origin::Deshaj],
     examples: &[("दाजू", "दाजु"), ("भाउजु", "भाउजू")],
 };
 
@@ -64,10 +83,11 @@ pub const SPEC_KOSHA_BACKED: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 260,
     citation: Rule::VarnaVinyasNiyam("3(क)(ई)"),
+    origins: NON_TATSAM,
     examples: &[("नेपालि", "नेपाली")],
 };
 
-pub fn rule_suffix_nu_hrasva(input: &str) -> Option<Prakriya> {
+pub fn rule_suffix_nu_hrasva(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     // Guard: only applicable to words ending in -नु प्रत्यय (suffix)
     if !(input.ends_with("नु") || input.ends_with("र्नु")) {
         return None;
@@ -85,17 +105,7 @@ pub fn rule_suffix_nu_hrasva(input: &str) -> Option<Prakriya> {
 
     // Find the last ई in the part before the प्रत्यय
     let last_ii_pos = prefix_part.rfind('ी')?;
-    let mut output = String::with_capacity(input.len());
-    let mut pos = 0;
-    for ch in input.chars() {
-        let byte_pos = pos;
-        pos += ch.len_utf8();
-        if byte_pos == last_ii_pos {
-            output.push('ि');
-        } else {
-            output.push(ch);
-        }
-    }
+    let output = shorten_vowel_at(input, last_ii_pos);
 
     if output != input {
         return Some(Prakriya::corrected(
@@ -112,7 +122,28 @@ pub fn rule_suffix_nu_hrasva(input: &str) -> Option<Prakriya> {
     None
 }
 
-pub fn rule_suffix_eli_hrasva(input: &str) -> Option<Prakriya> {
+/// बहुवचन प्रत्यय -हरू मा दीर्घ ऊ: केटाहरु → केटाहरू।
+pub fn rule_plural_haru(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let stem = input.strip_suffix("हरु")?;
+    // कम्तीमा दुई अक्षरको आधार चाहिन्छ; पहरु, नहरु जस्ता शब्द बहुवचन होइनन्।
+    if split_aksharas(stem).len() < 2 {
+        return None;
+    }
+
+    let output = format!("{stem}हरू");
+    Some(Prakriya::corrected(
+        input,
+        &output,
+        vec![Step::new(
+            Rule::VarnaVinyasNiyam("3(क)-suffix-हरू"),
+            "बहुवचन प्रत्ययमा दीर्घ ऊ हुन्छ: -हरू (-हरु होइन)",
+            input,
+            &output,
+        )],
+    ))
+}
+
+pub fn rule_suffix_eli_hrasva(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     // Guard: only applicable to words ending in -एली प्रत्यय (suffix)
     if !(input.ends_with("एली") || input.ends_with("ेली")) {
         return None;
@@ -129,17 +160,7 @@ pub fn rule_suffix_eli_hrasva(input: &str) -> Option<Prakriya> {
 
     // Find the last ू in the part before the suffix
     let last_uu_pos = prefix_part.rfind('ू')?;
-    let mut output = String::with_capacity(input.len());
-    let mut pos = 0;
-    for ch in input.chars() {
-        let byte_pos = pos;
-        pos += ch.len_utf8();
-        if byte_pos == last_uu_pos {
-            output.push('ु');
-        } else {
-            output.push(ch);
-        }
-    }
+    let output = shorten_vowel_at(input, last_uu_pos);
 
     if output != input {
         return Some(Prakriya::corrected(
@@ -158,7 +179,7 @@ pub fn rule_suffix_eli_hrasva(input: &str) -> Option<Prakriya> {
 
 /// Academy 3(क)(उ) नियम १-२: प्रत्यय -ई/-ईय लाग्दा मूल शब्दको दीर्घ कायम रहन्छ।
 /// अनावश्यक false-positive नहोस् भनेर सीमित ज्ञात रूपमै लागू गरिन्छ।
-pub fn rule_suffix_preserves_dirgha(input: &str) -> Option<Prakriya> {
+pub fn rule_suffix_preserves_dirgha(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     // प्रत्यय लागेपछि दीर्घ हराएका ज्ञात गलत→सही रूपहरू।
     // अनावश्यक false-positive रोक्न यिनै रूपहरू मात्र सच्याइन्छ।
     static KNOWN_CORRECTIONS: &[(&str, &str, &str)] = &[
@@ -187,8 +208,8 @@ pub fn rule_suffix_preserves_dirgha(input: &str) -> Option<Prakriya> {
 
 /// Academy 3(क) नियम ३-१२: तद्भव/देशज/आगन्तुक शब्दमा ह्रस्व प्रयोग हुन्छ।
 /// गैर-तत्सम शब्दमा अपेक्षित ठाउँमा दीर्घ ई/ऊ आएमा सुधार गरिन्छ।
-pub fn rule_tadbhav_hrasva(input: &str) -> Option<Prakriya> {
-    let origin = classify(input);
+pub fn rule_tadbhav_hrasva(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
+    let origin = ctx.origin();
 
     // गैर-तत्सम शब्दमा मात्र लागू गर्ने
     if matches!(origin, Origin::Tatsam) {
@@ -217,29 +238,11 @@ pub fn rule_tadbhav_hrasva(input: &str) -> Option<Prakriya> {
 
     // शब्दमध्य (शब्दान्त बाहेक) मा अनपेक्षित दीर्घ जाँच्ने।
     // शब्दान्तका लागि छुट्टै नियम लागू हुन्छ।
+    // मात्रा (ी→ि, ू→ु) र स्वतन्त्र स्वर (रमाईलो → रमाइलो) दुवै।
     for i in 0..chars.len().saturating_sub(1) {
-        match chars[i] {
-            'ी' => {
-                // गैर-तत्सम शब्दको शब्दमध्यमा दीर्घ मात्रा ई→इ
-                output_chars[i] = 'ि';
-                changed = true;
-            }
-            'ू' => {
-                // गैर-तत्सम शब्दको शब्दमध्यमा दीर्घ मात्रा ऊ→उ
-                output_chars[i] = 'ु';
-                changed = true;
-            }
-            'ई' => {
-                // स्वतन्त्र स्वर ई→इ (जस्तै: रमाईलो → रमाइलो)
-                output_chars[i] = 'इ';
-                changed = true;
-            }
-            'ऊ' => {
-                // Independent vowel ऊ→उ
-                output_chars[i] = 'उ';
-                changed = true;
-            }
-            _ => {}
+        if let Some(hrasva) = dirgha_to_hrasva(chars[i]) {
+            output_chars[i] = hrasva;
+            changed = true;
         }
     }
 
@@ -270,8 +273,8 @@ pub fn rule_tadbhav_hrasva(input: &str) -> Option<Prakriya> {
 }
 
 /// Academy 3(क)(ऊ) नियम १-१६: स्त्रीलिङ्गी नामपद, -ई/-वती प्रत्यय, पेसा/स्थान/भाषा आदिमा दीर्घ।
-pub fn rule_dirgha_endings(input: &str) -> Option<Prakriya> {
-    let origin = classify(input);
+pub fn rule_dirgha_endings(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
+    let origin = ctx.origin();
 
     // यो नियम मुख्यतः तद्भव/देशज स्त्रीलिङ्गी शब्दान्तमा लागू हुन्छ।
     if matches!(origin, Origin::Tatsam) {
@@ -332,7 +335,7 @@ pub fn rule_dirgha_endings(input: &str) -> Option<Prakriya> {
             let penult = chars[char_count - 2];
             if varnavinyas_akshar::is_vyanjan(penult) {
                 let mut output_chars = chars.clone();
-                output_chars[char_count - 1] = 'ी';
+                output_chars[char_count - 1] = hrasva_to_dirgha(last)?;
                 let output: String = output_chars.into_iter().collect();
                 return Some(Prakriya::corrected(
                     input,
@@ -392,8 +395,8 @@ pub fn rule_dirgha_endings(input: &str) -> Option<Prakriya> {
 ///
 /// छुट्टै नियम: गैर-तत्सम शब्द अन्त्यमा ह्रस्व (ि/ु) भए
 /// त्यसको दीर्घ रूप शब्दकोशमा छ कि छैन जाँचिन्छ।
-pub fn kosha_backed_dirgha_correction(input: &str) -> Option<Prakriya> {
-    let origin = classify(input);
+pub fn kosha_backed_dirgha_correction(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
+    let origin = ctx.origin();
     if matches!(origin, Origin::Tatsam) {
         return None;
     }
@@ -404,30 +407,19 @@ pub fn kosha_backed_dirgha_correction(input: &str) -> Option<Prakriya> {
     }
 
     let last = *chars.last().unwrap();
-
-    if last == 'ि' {
-        return kosha_backed_dirgha_impl(input, &chars, 'ि', 'ी', "ई");
-    }
-
-    if last == 'ु' {
-        return kosha_backed_dirgha_impl(input, &chars, 'ु', 'ू', "ऊ");
+    if !matches!(last, 'ि' | 'ु') {
+        return None;
     }
 
-    None
+    kosha_backed_dirgha_impl(input, &chars, hrasva_to_dirgha(last)?)
 }
 
 /// शब्दकोश-आधारित ह्रस्व→दीर्घ सुधारको आन्तरिक कार्यान्वयन।
-fn kosha_backed_dirgha_impl(
-    input: &str,
-    chars: &[char],
-    hrasva: char,
-    dirgha: char,
-    vowel_label: &str,
-) -> Option<Prakriya> {
-    debug_assert_eq!(*chars.last().unwrap(), hrasva);
+fn kosha_backed_dirgha_impl(input: &str, chars: &[char], dirgha: char) -> Option<Prakriya> {
+    let vowel_label = matra_to_svar(dirgha)?;
 
     let kosha = varnavinyas_kosha::kosha();
-    if kosha.contains(input) {
+    if kosha.contains_normalized(input) {
         // ह्रस्व रूप आफैं शब्दकोशमा वैध छ भने त्रुटि नदेखाउने।
         return None;
     }
@@ -457,10 +449,24 @@ fn kosha_backed_dirgha_impl(
     None
 }
 
+/// `pos` (byte offset) मा रहेको दीर्घ स्वर/मात्रालाई ह्रस्व बनाउने।
+fn shorten_vowel_at(input: &str, pos: usize) -> String {
+    input
+        .char_indices()
+        .map(|(i, ch)| {
+            if i == pos {
+                dirgha_to_hrasva(ch).unwrap_or(ch)
+            } else {
+                ch
+            }
+        })
+        .collect()
+}
+
 /// Academy 3(क)(इ) नियम १: पुलिङ्ग नातागोता शब्दको अन्त्यमा ह्रस्व हुन्छ।
 /// अपवाद: खसी, सम्धी, हात्ती, स्वामीमा दीर्घ हुन्छ।
-pub fn rule_kinship_tadbhav(input: &str) -> Option<Prakriya> {
-    let origin = classify(input);
+pub fn rule_kinship_tadbhav(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
+    let origin = ctx.origin();
     if !matches!(origin, Origin::Tadbhav | Origin::Deshaj) {
         return None;
     }
@@ -11,10 +11,15 @@ mod structural;
 
 pub use analysis::{RuleNote, WordAnalysis, analyze};
 pub use correction_table::contains as is_in_correction_table;
-pub use engine::derive;
-pub use prakriya::Prakriya;
+#[cfg(any(test, feature = "validate"))]
+pub use correction_table::{Inconsistency, validate_correction_table};
+pub use engine::{
+    CORRECTION_TABLE_ID, DeriveReport, RuleAttempt, all_rule_specs, derive, derive_debug,
+    derive_with, rules_for_origin,
+};
+pub use prakriya::{Prakriya, ReplayError};
 pub use rule::Rule;
-pub use rule_spec::{DiagnosticKind, PatternRule, RuleCategory, RuleSpec};
+pub use rule_spec::{DiagnosticKind, PatternRule, RuleCategory, RuleContext, RuleSpec};
 pub use step::Step;
 
 /// Error type for prakriya operations.
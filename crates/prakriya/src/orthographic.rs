@@ -1,10 +1,13 @@
 use crate::prakriya::Prakriya;
 use crate::rule::Rule;
-use crate::rule_spec::{DiagnosticKind, RuleCategory, RuleSpec};
+use crate::rule_spec::{
+    ALL_ORIGINS, DiagnosticKind, NON_TATSAM, RuleCategory, RuleContext, RuleSpec,
+};
 use crate::step::Step;
-use varnavinyas_akshar::{is_matra, is_svar, is_vyanjan};
+use varnavinyas_akshar::{is_matra, is_svar, is_vyanjan, split_aksharas};
 use varnavinyas_kosha::kosha;
-use varnavinyas_shabda::{Origin, OriginSource, classify, classify_with_provenance};
+use varnavinyas_shabda::{Origin, OriginSource};
+use varnavinyas_vyakaran::{Person, surface_features};
 
 pub const SPEC_CHANDRABINDU: RuleSpec = RuleSpec {
     id: "ortho-chandrabindu",
@@ -12,15 +15,47 @@ pub const SPEC_CHANDRABINDU: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 300,
     citation: Rule::VarnaVinyasNiyam("3(ख)"),
+    origins: ALL_ORIGINS,
     examples: &[("सिँह", "सिंह")],
 };
 
+pub const SPEC_VERB_CHANDRABINDU: RuleSpec = RuleSpec {
+    id: "ortho-verb-chandrabindu",
+    category: RuleCategory::Chandrabindu,
+    kind: DiagnosticKind::Error,
+    priority: 295,
+    citation: Rule::VarnaVinyasNiyam("3(ख)"),
+    origins: ALL_ORIGINS,
+    examples: &[("जान्छौं", "जान्छौँ"), ("गरें", "गरेँ")],
+};
+
+pub const SPEC_PUNAH_VISARGA: RuleSpec = RuleSpec {
+    id: "ortho-punah-visarga",
+    category: RuleCategory::Sandhi,
+    kind: DiagnosticKind::Error,
+    priority: 305,
+    citation: Rule::ShuddhaAshuddha("Section 4"),
+    origins: ALL_ORIGINS,
+    examples: &[("पुनर्स्थापित", "पुनःस्थापित"), ("पुनर्समीक्षा", "पुनःसमीक्षा")],
+};
+
+pub const SPEC_NIH_DUH_PREFIX: RuleSpec = RuleSpec {
+    id: "ortho-nih-duh-prefix",
+    category: RuleCategory::Sandhi,
+    kind: DiagnosticKind::Error,
+    priority: 306,
+    citation: Rule::ShuddhaAshuddha("Section 4"),
+    origins: &[Origin::Tatsam],
+    examples: &[("निष्तेज", "निस्तेज"), ("निर्फल", "निष्फल")],
+};
+
 pub const SPEC_SIBILANT: RuleSpec = RuleSpec {
     id: "ortho-sibilant",
     category: RuleCategory::ShaShaS,
     kind: DiagnosticKind::Error,
     priority: 310,
     citation: Rule::VarnaVinyasNiyam("3(ग)(अ)"),
+    origins: NON_TATSAM,
     examples: &[("रजिष्टर", "रजिस्टर")],
 };
 
@@ -30,6 +65,7 @@ pub const SPEC_RI_KRI: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 320,
     citation: Rule::VarnaVinyasNiyam("3(ग)-ऋ"),
+    origins: &[Origin::Tatsam],
     examples: &[("रिषि", "ऋषि"), ("क्रिति", "कृति")],
 };
 
@@ -39,15 +75,27 @@ pub const SPEC_HALANTA: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 330,
     citation: Rule::VarnaVinyasNiyam("3(ङ)"),
+    origins: ALL_ORIGINS,
     examples: &[("बुद्धिमान", "बुद्धिमान्"), ("श्रीमान", "श्रीमान्")],
 };
 
+pub const SPEC_CONJUNCT_NORMALIZE: RuleSpec = RuleSpec {
+    id: "ortho-conjunct-normalize",
+    category: RuleCategory::Halanta,
+    kind: DiagnosticKind::Error,
+    priority: 335,
+    citation: Rule::VarnaVinyasNiyam("3(ङ)"),
+    origins: ALL_ORIGINS,
+    examples: &[("सत््य", "सत्य"), ("अक्क्षर", "अक्षर")],
+};
+
 pub const SPEC_AADHI_VRIDDHI: RuleSpec = RuleSpec {
     id: "ortho-aadhi-vriddhi",
     category: RuleCategory::AadhiVriddhi,
     kind: DiagnosticKind::Error,
     priority: 340,
     citation: Rule::VarnaVinyasNiyam("3(क)"),
+    origins: ALL_ORIGINS,
     examples: &[("अर्थिक", "आर्थिक"), ("इतिहासिक", "ऐतिहासिक")],
 };
 
@@ -57,15 +105,37 @@ pub const SPEC_YA_E: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 350,
     citation: Rule::VarnaVinyasNiyam("3(इ)"),
+    origins: ALL_ORIGINS,
     examples: &[("एथार्थ", "यथार्थ"), ("यकता", "एकता")],
 };
 
+pub const SPEC_BA_VA: RuleSpec = RuleSpec {
+    id: "ortho-ba-va",
+    category: RuleCategory::BaVa,
+    kind: DiagnosticKind::Error,
+    priority: 355,
+    citation: Rule::VarnaVinyasNiyam("3(ग)-बव"),
+    origins: &[Origin::Tatsam],
+    examples: &[("बिषय", "विषय"), ("बेग", "वेग")],
+};
+
+pub const SPEC_INTERNAL_CONJUNCT: RuleSpec = RuleSpec {
+    id: "ortho-internal-conjunct",
+    category: RuleCategory::Halanta,
+    kind: DiagnosticKind::Error,
+    priority: 357,
+    citation: Rule::VarnaVinyasNiyam("3(ङ)"),
+    origins: &[Origin::Tatsam],
+    examples: &[("पदम", "पद्म"), ("आतमा", "आत्मा")],
+};
+
 pub const SPEC_KSHA_CHHYA: RuleSpec = RuleSpec {
     id: "ortho-ksha-chhya",
     category: RuleCategory::KshaChhya,
     kind: DiagnosticKind::Error,
     priority: 360,
     citation: Rule::VarnaVinyasNiyam("3(उ)"),
+    origins: ALL_ORIGINS,
     examples: &[("लछ्य", "लक्ष्य"), ("छेत्र", "क्षेत्र")],
 };
 
@@ -75,14 +145,58 @@ pub const SPEC_GYA_GYAN: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 365,
     citation: Rule::VarnaVinyasNiyam("3(ग)(ऊ)"),
-    examples: &[("अग्यान", "अज्ञान"), ("प्रग्या", "प्रज्ञा")],
+    origins: ALL_ORIGINS,
+    examples: &[
+        ("अग्यान", "अज्ञान"),
+        ("प्रग्या", "प्रज्ञा"),
+        ("विग्यप्ति", "विज्ञप्ति"),
+        ("ज्ञारेज", "ग्यारेज"),
+    ],
 };
 
+pub const SPEC_REDUNDANT_TAA: RuleSpec = RuleSpec {
+    id: "ortho-redundant-taa",
+    category: RuleCategory::Structural,
+    kind: DiagnosticKind::Error,
+    priority: 370,
+    citation: Rule::ShuddhaAshuddha("Section 4"),
+    origins: ALL_ORIGINS,
+    examples: &[("सौजन्यता", "सौजन्य"), ("महत्त्वता", "महत्त्व")],
+};
+
+/// Academy 3(ख): प्रथम पुरुषका क्रियान्त (-छौँ, -एँ, -यौँ) मा चन्द्रबिन्दु (ँ)।
+///
+/// क्रियारूप vyakaran को पुरुष-अन्त्य तालिकाबाट चिनिन्छ, शब्दकोशबाट होइन;
+/// त्यसैले कोशमा नभएका क्रियामा पनि लागू हुन्छ (लेखें → लेखेँ)।
+pub fn rule_verb_ending_chandrabindu(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let base = input.strip_suffix('ं')?;
+    if base.chars().count() < 3 || !matches!(base.chars().last(), Some('े' | 'ौ')) {
+        return None;
+    }
+
+    let output = format!("{base}ँ");
+    let features = surface_features(&output);
+    if features.tense.is_none() || features.person != Some(Person::First) {
+        return None;
+    }
+
+    Some(Prakriya::corrected(
+        input,
+        &output,
+        vec![Step::new(
+            Rule::VarnaVinyasNiyam("3(ख)"),
+            "प्रथम पुरुषको क्रियापदको अन्त्यमा चन्द्रबिन्दु (ँ) लेखिन्छ, शिरबिन्दु (ं) होइन",
+            input,
+            &output,
+        )],
+    ))
+}
+
 /// Academy 3(ख): शब्दउत्पत्तिअनुसार चन्द्रबिन्दु/शिरबिन्दु प्रयोग।
 /// - तत्सम: चन्द्रबिन्दु (ँ) होइन, शिरबिन्दु (ं)।
 /// - तद्भव/आगन्तुक: अनुनासिकमा शिरबिन्दु (ं) होइन, चन्द्रबिन्दु (ँ)।
-pub fn rule_chandrabindu(input: &str) -> Option<Prakriya> {
-    let origin_decision = classify_with_provenance(input);
+pub fn rule_chandrabindu(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
+    let origin_decision = *ctx.origin_decision();
     let origin = origin_decision.origin;
     let source = origin_decision.source;
 
@@ -184,8 +298,8 @@ pub fn rule_chandrabindu(input: &str) -> Option<Prakriya> {
 /// - आगन्तुक: सामान्यतः स (ष→स अनिवार्य, श→स सन्दर्भअनुसार)।
 /// - तद्भव/देशज: ष→स।
 /// - तत्सम: मूल श/ष/स कायम।
-pub fn rule_sibilant(input: &str) -> Option<Prakriya> {
-    let origin = classify(input);
+pub fn rule_sibilant(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
+    let origin = ctx.origin();
 
     match origin {
         Origin::Aagantuk => {
@@ -235,7 +349,7 @@ pub fn rule_sibilant(input: &str) -> Option<Prakriya> {
             if input.contains('ष') {
                 let output = input.replace('ष', "स");
                 let lex = kosha();
-                if lex.contains(input) && !lex.contains(&output) {
+                if lex.contains_normalized(input) && !lex.contains(&output) {
                     return None;
                 }
                 return Some(Prakriya::corrected(
@@ -258,10 +372,10 @@ pub fn rule_sibilant(input: &str) -> Option<Prakriya> {
     None
 }
 
-pub fn rule_ri_kri(input: &str) -> Option<Prakriya> {
+pub fn rule_ri_kri(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
     // ऋ/कृ नियम तत्सम वर्गीकृत शब्दमा मात्र लागू।
     // क्रिकेटजस्ता आगन्तुक शब्दमा रूपान्तरण नगर्ने।
-    let origin = classify(input);
+    let origin = ctx.origin();
     if !matches!(origin, Origin::Tatsam) {
         return None;
     }
@@ -309,7 +423,7 @@ pub fn rule_ri_kri(input: &str) -> Option<Prakriya> {
 /// -मान, -वान, -वत अन्त्य भएका तत्सम रूपहरूमा हलन्त अनिवार्य हुन्छ
 /// (-मान्, -वान्, -वत्)।
 /// अन्य केही हलन्त सुधार (जस्तै महान→महान्) correction table मा राखिएका छन्।
-pub fn rule_halanta(input: &str) -> Option<Prakriya> {
+pub fn rule_halanta(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
     let lex = kosha();
 
     // अजन्त-पक्ष: ...छ मा अन्त्य हुने समापक क्रियापदमा हलन्त आउँदैन।
@@ -317,7 +431,7 @@ pub fn rule_halanta(input: &str) -> Option<Prakriya> {
     // अनावश्यक सुधार नहोस् भनेर शब्दकोश-प्रमाणित अवस्थामा मात्र लागू।
     if let Some(stem) = input.strip_suffix("छ्") {
         let output = format!("{stem}छ");
-        if lex.contains(&output) && !lex.contains(input) {
+        if lex.contains(&output) && !lex.contains_normalized(input) {
             return Some(Prakriya::corrected(
                 input,
                 &output,
@@ -359,7 +473,7 @@ pub fn rule_halanta(input: &str) -> Option<Prakriya> {
         }
     }
 
-    let origin = classify(input);
+    let origin = ctx.origin();
     if !matches!(origin, Origin::Tatsam) {
         return None;
     }
@@ -379,7 +493,7 @@ pub fn rule_halanta(input: &str) -> Option<Prakriya> {
             // Guard: इनपुट शब्द शब्दकोशमा छ तर हलन्त-रूप छैन भने
             // यो प्रत्ययरूप होइन, मूल नामपद हुन सक्छ (जस्तै: सम्मान)।
             // त्यसैले हलन्त-रूप पनि वैध हुँदा मात्र सुधार गर्ने।
-            if lex.contains(input) && !lex.contains(&output) {
+            if lex.contains_normalized(input) && !lex.contains(&output) {
                 return None;
             }
 
@@ -454,7 +568,7 @@ fn apply_vriddhi(chars: &[char]) -> Option<Vec<char>> {
 ///
 /// When -इक is added to a root, the first vowel undergoes vr̥ddhi:
 /// अ→आ, इ/ई→ऐ, उ/ऊ→औ. The root must exist in kosha.
-pub fn rule_aadhi_vriddhi(input: &str) -> Option<Prakriya> {
+pub fn rule_aadhi_vriddhi(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     let chars: Vec<char> = input.chars().collect();
     let len = chars.len();
     if len < 3 {
@@ -501,7 +615,7 @@ pub fn rule_aadhi_vriddhi(input: &str) -> Option<Prakriya> {
 ///
 /// तत्सम words use य (यज्ञ, यथार्थ). एक-derived words use ए (एक, एकता).
 /// Swaps initial ए↔य and validates against kosha.
-pub fn rule_ya_e(input: &str) -> Option<Prakriya> {
+pub fn rule_ya_e(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     let chars: Vec<char> = input.chars().collect();
     if chars.is_empty() {
         return None;
@@ -514,7 +628,7 @@ pub fn rule_ya_e(input: &str) -> Option<Prakriya> {
     };
 
     let kosha = varnavinyas_kosha::kosha();
-    if kosha.contains(input) {
+    if kosha.contains_normalized(input) {
         return None;
     }
 
@@ -538,17 +652,193 @@ pub fn rule_ya_e(input: &str) -> Option<Prakriya> {
     None
 }
 
+/// पुनः उपसर्गपछि श/ष/स आए विसर्ग कायम रहन्छ: पुनर्स्थापित → पुनःस्थापित।
+///
+/// पुनर् रूप स्वर वा घोष व्यञ्जनअघि मात्र हुन्छ (पुनरवलोकन, पुनर्जन्म), त्यसैले
+/// ती अछुतै रहन्छन्। शब्दकोशमा पुनर्स्थापना जस्ता प्रचलित अशुद्ध रूप पनि भएकाले
+/// यो नियम शब्दकोश-उपस्थितिमा भर पर्दैन।
+pub fn rule_punah_visarga(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let rest = input.strip_prefix("पुनर्")?;
+    if !rest.starts_with(['श', 'ष', 'स']) {
+        return None;
+    }
+
+    let output = format!("पुनः{rest}");
+    Some(Prakriya::corrected(
+        input,
+        &output,
+        vec![Step::new(
+            Rule::ShuddhaAshuddha("Section 4"),
+            "विसर्ग कायम: श/ष/स अघि पुनः (पुनर् होइन)",
+            input,
+            &output,
+        )],
+    ))
+}
+
+/// निः/दुः उपसर्गको रूप पछिल्लो वर्णले तय गर्छ: च/छ अघि श् (निश्चय), ट/ठ र
+/// क/ख/प/फ अघि ष् (निष्फल), त/थ अघि स् (निस्तेज), श/ष/स अघि विसर्ग वा दोहोरो
+/// ऊष्म (निःसन्देह, निस्सन्देह), र अघि दीर्घ स्वर (नीरस) र अरू घोष वर्णअघि र्
+/// (निर्धन)। स्वरअघिको र मात्रासहित लेखिन्छ (निराकरण), त्यसैले त्यो अछुतै रहन्छ।
+///
+/// शब्दकोशमा निष्तेज जस्ता प्रचलित अशुद्ध रूप पनि भएकाले इनपुटको उपस्थितिमा
+/// भर परिँदैन; सुधारिएको रूप शब्दकोशमा तत्सम भनी चिनिएको हुनुपर्छ।
+pub fn rule_nih_duh_prefix(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let (prefix, lengthened, marked) = [("नि", "नी"), ("दु", "दू")]
+        .into_iter()
+        .find_map(|(prefix, long)| input.strip_prefix(prefix).map(|rest| (prefix, long, rest)))?;
+    let (form, rest) = ["ः", "र्", "स्", "ष्", "श्"]
+        .into_iter()
+        .find_map(|form| marked.strip_prefix(form).map(|rest| (form, rest)))?;
+    let next = rest.chars().next().filter(|&c| is_vyanjan(c))?;
+    if split_aksharas(rest).len() < 2 {
+        return None;
+    }
+
+    // ऊष्मसहितको संयुक्त वर्ण मूल शब्दकै हुन सक्छ: नि + श्वास, नि + स्पृह।
+    // त्यस्तो अंश आफैं शब्द हो, वा स्क/स्प जस्तो संयुक्त वर्णसहितको शब्द
+    // कोशमा छ भने छोइँदैन।
+    let lex = kosha();
+    let sibilant = form != "ः" && form != "र्";
+    let stem_cluster = form == "स्" && matches!(next, 'क' | 'ख' | 'प' | 'फ');
+    if sibilant && (lex.contains(marked) || (stem_cluster && lex.contains_normalized(input))) {
+        return None;
+    }
+
+    let correct = if next == 'र' {
+        lengthened.to_string()
+    } else {
+        let expected = match next {
+            'च' | 'छ' => "श्",
+            'ट' | 'ठ' | 'क' | 'ख' | 'प' | 'फ' => "ष्",
+            'त' | 'थ' => "स्",
+            'श' | 'ष' | 'स' => "ः",
+            _ => "र्",
+        };
+        let doubled_sibilant = expected == "ः" && form.starts_with(next);
+        // ऊष्म + घोष (स्म, श्व, स्न) विसर्गको रूप होइन, मूल शब्दको वर्ण हो।
+        if form == expected || doubled_sibilant || (sibilant && expected == "र्") {
+            return None;
+        }
+        format!("{prefix}{expected}")
+    };
+
+    let output = format!("{correct}{rest}");
+    if !lex.contains(&output) {
+        return None;
+    }
+    let decision = varnavinyas_shabda::classify_with_provenance(&output);
+    if decision.origin != Origin::Tatsam || decision.source == OriginSource::Heuristic {
+        return None;
+    }
+
+    Some(Prakriya::corrected(
+        input,
+        &output,
+        vec![Step::new(
+            Rule::ShuddhaAshuddha("Section 4"),
+            format!("विसर्ग सन्धि: {next} अघि {correct} ({prefix}{form} होइन)"),
+            input,
+            &output,
+        )],
+    ))
+}
+
+/// Academy 3(ग): ब/व भेद — तत्सम शब्दमा व (बिषय → विषय, बेग → वेग)।
+///
+/// शब्दादिको ब लाई व बनाइन्छ, र त्यो रूप शब्दकोशमा तत्सम भनी चिनिएको भए
+/// मात्र सुधार गरिन्छ। heuristic वर्गीकरणलाई भर नपरिने हुँदा बिहान, बिरालो
+/// जस्ता ब भएका मौलिक शब्द अछुतै रहन्छन्।
+pub fn rule_ba_va(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let rest = input.strip_prefix('ब')?;
+    let lex = kosha();
+    if rest.is_empty() || lex.contains_normalized(input) {
+        return None;
+    }
+
+    let candidate = format!("व{rest}");
+    if !lex.contains(&candidate) {
+        return None;
+    }
+    let decision = varnavinyas_shabda::classify_with_provenance(&candidate);
+    if decision.origin != Origin::Tatsam || decision.source == OriginSource::Heuristic {
+        return None;
+    }
+
+    Some(Prakriya::corrected(
+        input,
+        &candidate,
+        vec![Step::new(
+            Rule::VarnaVinyasNiyam("3(ग)-बव"),
+            "ब/व भेद: तत्सम शब्दमा व (ब होइन)",
+            input,
+            &candidate,
+        )],
+    ))
+}
+
+/// Academy 3(ङ): तत्सम शब्दभित्रको संयुक्त व्यञ्जनमा हलन्त छुट्नु (आतमा → आत्मा)।
+///
+/// Tries a halanta after each word-internal bare consonant that is followed
+/// by another consonant. Fires only when the input is not in the kosha and
+/// exactly one candidate is a kosha word whose origin is attested तत्सम.
+pub fn rule_internal_conjunct(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let lex = kosha();
+    if lex.contains_normalized(input) {
+        return None;
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    let mut found: Option<String> = None;
+    for i in 1..chars.len().saturating_sub(1) {
+        if !is_vyanjan(chars[i]) || !is_vyanjan(chars[i + 1]) {
+            continue;
+        }
+        let candidate: String = chars[..=i]
+            .iter()
+            .chain(std::iter::once(&'्'))
+            .chain(&chars[i + 1..])
+            .collect();
+        if !lex.contains(&candidate) {
+            continue;
+        }
+        let decision = varnavinyas_shabda::classify_with_provenance(&candidate);
+        if decision.origin != Origin::Tatsam || decision.source == OriginSource::Heuristic {
+            continue;
+        }
+        if found.is_some() {
+            return None;
+        }
+        found = Some(candidate);
+    }
+    let output = found?;
+
+    Some(Prakriya::corrected(
+        input,
+        &output,
+        vec![Step::new(
+            Rule::VarnaVinyasNiyam("3(ङ)"),
+            "तत्सम शब्दको संयुक्त व्यञ्जनमा हलन्त (्) लेखिन्छ",
+            input,
+            &output,
+        )],
+    ))
+}
+
 /// Academy 3(उ): क्ष/छ distinction.
 ///
 /// क्ष/क्षे/क्ष्य is तत्सम-only. छ/छे/छ्य is used in all origins.
-/// Tries character substitutions and validates against kosha.
-pub fn rule_ksha_chhya(input: &str) -> Option<Prakriya> {
+/// Tries character substitutions — across the whole word, then at each
+/// occurrence on its own (परिछेद → परिच्छेद) — and validates against kosha.
+/// If none fits, the vowel sign just before क्ष/छ is also flipped between
+/// ह्रस्व and दीर्घ, since the two errors often come together (परिक्षा → परीक्षा).
+pub fn rule_ksha_chhya(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     let kosha = varnavinyas_kosha::kosha();
-    if kosha.contains(input) {
+    if kosha.contains_normalized(input) {
         return None;
     }
 
-    if !input.contains("क्ष") && !input.contains('छ') && !input.contains("च्छ") {
+    if !input.contains("क्ष") && !input.contains('छ') {
         return None;
     }
 
@@ -562,22 +852,56 @@ pub fn rule_ksha_chhya(input: &str) -> Option<Prakriya> {
         ("च्छ", "क्ष"),
         ("छ", "क्ष"),
         ("क्ष", "छ"),
+        ("छ", "च्छ"),
     ];
 
     for &(from, to) in SUBS {
-        if input.contains(from) {
-            let candidate = input.replace(from, to);
+        for candidate in substitution_candidates(input, from, to) {
             if kosha.contains(&candidate) {
                 return Some(Prakriya::corrected(
                     input,
                     &candidate,
-                    vec![Step::new(
-                        Rule::VarnaVinyasNiyam("3(उ)"),
-                        format!("क्ष/छ भेद: {} → {}", from, to),
+                    vec![ksha_chhya_step(from, to, input, &candidate)],
+                ));
+            }
+        }
+    }
+
+    // क्ष/छ अघिको मात्रा ह्रस्व↔दीर्घ, अक्षर उही राखेर वा बदलेर।
+    let positions = input
+        .match_indices("क्ष")
+        .chain(input.match_indices('छ'))
+        .map(|(i, _)| i);
+    for idx in positions {
+        let Some(vowel) = input[..idx].chars().next_back() else {
+            continue;
+        };
+        let Some(flipped) = flip_vowel_length(vowel) else {
+            continue;
+        };
+        let vowel_start = idx - vowel.len_utf8();
+        let toggled = format!("{}{flipped}{}", &input[..vowel_start], &input[idx..]);
+        let vowel_step = Step::new(
+            Rule::VarnaVinyasNiyam("3(क)"),
+            format!("ह्रस्व/दीर्घ: {vowel} → {flipped}"),
+            input,
+            &toggled,
+        );
+
+        if kosha.contains(&toggled) {
+            return Some(Prakriya::corrected(input, &toggled, vec![vowel_step]));
+        }
+        for &(from, to) in SUBS {
+            if let Some(tail) = toggled[idx..].strip_prefix(from) {
+                let candidate = format!("{}{to}{tail}", &toggled[..idx]);
+                if kosha.contains(&candidate) {
+                    let sub_step = ksha_chhya_step(from, to, &toggled, &candidate);
+                    return Some(Prakriya::corrected(
                         input,
                         &candidate,
-                    )],
-                ));
+                        vec![vowel_step, sub_step],
+                    ));
+                }
             }
         }
     }
@@ -585,46 +909,193 @@ pub fn rule_ksha_chhya(input: &str) -> Option<Prakriya> {
     None
 }
 
+/// `from → to` across the whole word, then at each occurrence separately
+/// when there is more than one.
+fn substitution_candidates(input: &str, from: &str, to: &str) -> Vec<String> {
+    let positions: Vec<usize> = input.match_indices(from).map(|(i, _)| i).collect();
+    if positions.is_empty() {
+        return Vec::new();
+    }
+    let mut candidates = vec![input.replace(from, to)];
+    if positions.len() > 1 {
+        candidates.extend(
+            positions
+                .into_iter()
+                .map(|i| format!("{}{to}{}", &input[..i], &input[i + from.len()..])),
+        );
+    }
+    candidates
+}
+
+fn ksha_chhya_step(from: &str, to: &str, before: &str, after: &str) -> Step {
+    Step::new(
+        Rule::VarnaVinyasNiyam("3(उ)"),
+        format!("क्ष/छ भेद: {} → {}", from, to),
+        before,
+        after,
+    )
+}
+
+/// ि↔ी, ु↔ू
+fn flip_vowel_length(matra: char) -> Option<char> {
+    match matra {
+        'ि' => Some('ी'),
+        'ी' => Some('ि'),
+        'ु' => Some('ू'),
+        'ू' => Some('ु'),
+        _ => None,
+    }
+}
+
 /// Academy 3(ग)(ऊ): ज्ञ / ग्याँ / ग्या distinction.
 ///
 /// - तत्सम words use ज्ञ.
 /// - Nepali/loan words may use ग्याँ or ग्या.
 ///
-/// This rule is intentionally kosha-backed to avoid aggressive rewrites.
-pub fn rule_gya_gyan(input: &str) -> Option<Prakriya> {
+/// Both directions are tried, at any position in the word:
+/// - ग्य → ज्ञ when the ज्ञ form is a kosha word (अग्यान → अज्ञान,
+///   विग्यप्ति → विज्ञप्ति).
+/// - ज्ञ → ग्य when the ग्य form is a kosha word that is not तत्सम
+///   (ज्ञारेज → ग्यारेज).
+///
+/// This rule is intentionally kosha-backed to avoid aggressive rewrites;
+/// kosha words such as ग्यारेज are never touched.
+pub fn rule_gya_gyan(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     let kosha = varnavinyas_kosha::kosha();
-    if kosha.contains(input) {
+    if kosha.contains_normalized(input) {
         return None;
     }
 
-    if !input.contains("ज्ञ") && !input.contains("ग्या") && !input.contains("ग्याँ")
-    {
+    // ग्याँ first, so a stray chandrabindu goes along with the ग्य.
+    const TO_GYA: &[(&str, &str)] = &[("ग्याँ", "ज्ञा"), ("ग्य", "ज्ञ")];
+    for &(from, to) in TO_GYA {
+        for candidate in substitution_candidates(input, from, to) {
+            if kosha.contains(&candidate) {
+                return Some(gya_gyan_correction(input, &candidate, from, to));
+            }
+        }
+    }
+
+    for candidate in substitution_candidates(input, "ज्ञ", "ग्य") {
+        if kosha.contains(&candidate) && varnavinyas_shabda::classify(&candidate) != Origin::Tatsam
+        {
+            return Some(gya_gyan_correction(input, &candidate, "ज्ञ", "ग्य"));
+        }
+    }
+
+    None
+}
+
+fn gya_gyan_correction(input: &str, candidate: &str, from: &str, to: &str) -> Prakriya {
+    Prakriya::corrected(
+        input,
+        candidate,
+        vec![Step::new(
+            Rule::VarnaVinyasNiyam("3(ग)(ऊ)"),
+            format!("ज्ञ/ग्याँ/ग्या भेद: {} → {}", from, to),
+            input,
+            candidate,
+        )],
+    )
+}
+
+/// Academy 3(ङ): संयुक्ताक्षरमा आएको अनावश्यक हलन्त हटाउने।
+///
+/// - लगातार दोहोरिएको हलन्त (्््) एउटै हलन्तमा झारिन्छ (सत््य → सत्य)।
+/// - मात्राअघि आएको हलन्त हटाइन्छ (क्ा → का)।
+/// - उही व्यञ्जन हलन्तसहित दोहोरिएमा (क्क्ष) एउटै राखिन्छ (अक्क्षर → अक्षर)।
+///
+/// सफा रूप शब्दकोशमा भेटिएमा मात्र सुधार गरिन्छ, त्यसैले सच्चा, उज्ज्वल
+/// जस्ता वैध द्वित्व रूपहरू अछुतै रहन्छन्।
+pub fn rule_conjunct_normalize(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let lex = kosha();
+    if lex.contains_normalized(input) || !input.contains('्') {
         return None;
     }
 
-    // Conservative direction only: common misspelling ग्याँ/ग्या in तत्सम words.
-    // Map to ज्ञा and accept only if kosha confirms the candidate.
-    const SUBS: &[(&str, &str)] = &[("ग्याँ", "ज्ञा"), ("ग्या", "ज्ञा")];
+    let output = normalize_conjunct_noise(input);
+    if output == input || !lex.contains(&output) {
+        return None;
+    }
 
-    for &(from, to) in SUBS {
-        if input.contains(from) {
-            let candidate = input.replace(from, to);
-            if candidate != input && kosha.contains(&candidate) {
-                return Some(Prakriya::corrected(
-                    input,
-                    &candidate,
-                    vec![Step::new(
-                        Rule::VarnaVinyasNiyam("3(ग)(ऊ)"),
-                        format!("ज्ञ/ग्याँ/ग्या भेद: {} → {}", from, to),
-                        input,
-                        &candidate,
-                    )],
-                ));
+    Some(Prakriya::corrected(
+        input,
+        &output,
+        vec![Step::new(
+            Rule::VarnaVinyasNiyam("3(ङ)"),
+            "संयुक्ताक्षरमा दोहोरिएको वा अनावश्यक हलन्त हटाइयो",
+            input,
+            &output,
+        )],
+    ))
+}
+
+/// भाववाचक नाममा फेरि -ता लगाउनु अनावश्यक: सौजन्यता → सौजन्य, महत्त्वता → महत्त्व।
+///
+/// आधार शब्दकोशमा हुनुपर्छ र भाववाचक ढाँचाको हुनुपर्छ: -त्व, वा आदिवृद्धिसहितको
+/// -य (सौजन्य, वैशिष्ट्य)। पूरा शब्द शब्दकोशमै भए (सुन्दरता, मान्यता) नछोइने।
+pub fn rule_redundant_taa(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    let stem = input.strip_suffix("ता")?;
+    if !is_abstract_noun_shape(stem) {
+        return None;
+    }
+    let lex = kosha();
+    if lex.contains_normalized(input) || !lex.contains(stem) {
+        return None;
+    }
+
+    Some(Prakriya::corrected(
+        input,
+        stem,
+        vec![Step::new(
+            Rule::ShuddhaAshuddha("Section 4"),
+            "-ता अनावश्यक: आधार शब्द आफैं भाववाचक नाम हो",
+            input,
+            stem,
+        )],
+    ))
+}
+
+/// -त्व वा आदिवृद्धि (आ/ऐ/औ) सहितको -य प्रत्ययान्त भाववाचक रूप।
+fn is_abstract_noun_shape(stem: &str) -> bool {
+    if stem.ends_with("त्व") {
+        return true;
+    }
+    if !stem.ends_with("्य") {
+        return false;
+    }
+    let mut chars = stem.chars();
+    match chars.next() {
+        Some('आ' | 'ऐ' | 'औ') => true,
+        Some(c) if is_vyanjan(c) => matches!(chars.next(), Some('ा' | 'ै' | 'ौ')),
+        _ => false,
+    }
+}
+
+/// दोहोरिएको हलन्त, मात्राअघिको हलन्त र उही व्यञ्जनको हलन्त-द्वित्व झार्ने।
+fn normalize_conjunct_noise(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '्' {
+            if out.last() == Some(&'्') {
+                continue;
+            }
+            if chars.get(i + 1).is_some_and(|&next| is_matra(next)) {
+                continue;
+            }
+            // क्क् → क्: अघिल्लो अक्षर पनि उही व्यञ्जन + हलन्त भए यो जोडी फालिन्छ।
+            let n = out.len();
+            if n >= 3 && out[n - 2] == '्' && out[n - 3] == out[n - 1] && is_vyanjan(out[n - 1]) {
+                out.pop();
+                continue;
             }
         }
+        out.push(c);
     }
 
-    None
+    out.into_iter().collect()
 }
 
 /// वर्ण स्पर्श-व्यञ्जन (क-वर्गदेखि म-वर्ग) हो कि होइन जाँच्ने।
@@ -685,58 +1156,59 @@ fn should_replace_shirbindu(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rule_spec::run_rule;
 
     #[test]
     fn test_halanta_maan() {
-        let p = rule_halanta("बुद्धिमान").expect("should correct बुद्धिमान");
+        let p = run_rule(rule_halanta, "बुद्धिमान").expect("should correct बुद्धिमान");
         assert_eq!(p.output, "बुद्धिमान्");
 
-        let p = rule_halanta("शक्तिमान").expect("should correct शक्तिमान");
+        let p = run_rule(rule_halanta, "शक्तिमान").expect("should correct शक्तिमान");
         assert_eq!(p.output, "शक्तिमान्");
     }
 
     #[test]
     fn test_halanta_vaan() {
         // विद्वान contains द्व conjunct → तत्सम heuristic
-        let p = rule_halanta("विद्वान").expect("should correct विद्वान");
+        let p = run_rule(rule_halanta, "विद्वान").expect("should correct विद्वान");
         assert_eq!(p.output, "विद्वान्");
     }
 
     #[test]
     fn test_halanta_vat() {
-        let p = rule_halanta("आत्मवत").expect("should correct आत्मवत");
+        let p = run_rule(rule_halanta, "आत्मवत").expect("should correct आत्मवत");
         assert_eq!(p.output, "आत्मवत्");
     }
 
     #[test]
     fn test_halanta_skips_non_tatsam() {
-        assert!(rule_halanta("नेपाल").is_none());
+        assert!(run_rule(rule_halanta, "नेपाल").is_none());
     }
 
     #[test]
     fn test_halanta_verb_second_person_disrespect() {
-        let p = rule_halanta("गर्छस").expect("should correct गर्छस");
+        let p = run_rule(rule_halanta, "गर्छस").expect("should correct गर्छस");
         assert_eq!(p.output, "गर्छस्");
     }
 
     #[test]
     fn test_halanta_verb_third_person_plural() {
-        let p = rule_halanta("जान्छन").expect("should correct जान्छन");
+        let p = run_rule(rule_halanta, "जान्छन").expect("should correct जान्छन");
         assert_eq!(p.output, "जान्छन्");
     }
 
     #[test]
     fn test_halanta_verb_irregular_is() {
-        let p = rule_halanta("आइस").expect("should correct आइस");
+        let p = run_rule(rule_halanta, "आइस").expect("should correct आइस");
         assert_eq!(p.output, "आइस्");
     }
 
     #[test]
     fn test_ajanta_terminal_chha_without_halanta() {
-        let p = rule_halanta("जान्छ्").expect("should correct जान्छ्");
+        let p = run_rule(rule_halanta, "जान्छ्").expect("should correct जान्छ्");
         assert_eq!(p.output, "जान्छ");
 
-        let p = rule_halanta("गर्छ्").expect("should correct गर्छ्");
+        let p = run_rule(rule_halanta, "गर्छ्").expect("should correct गर्छ्");
         assert_eq!(p.output, "गर्छ");
     }
 
@@ -745,41 +1217,41 @@ mod tests {
     #[test]
     fn test_aadhi_vriddhi_standalone_a() {
         // अर्थिक → आर्थिक (standalone अ → आ)
-        let p = rule_aadhi_vriddhi("अर्थिक").expect("should correct अर्थिक");
+        let p = run_rule(rule_aadhi_vriddhi, "अर्थिक").expect("should correct अर्थिक");
         assert_eq!(p.output, "आर्थिक");
     }
 
     #[test]
     fn test_aadhi_vriddhi_standalone_i() {
         // इतिहासिक → ऐतिहासिक (standalone इ → ऐ)
-        let p = rule_aadhi_vriddhi("इतिहासिक").expect("should correct इतिहासिक");
+        let p = run_rule(rule_aadhi_vriddhi, "इतिहासिक").expect("should correct इतिहासिक");
         assert_eq!(p.output, "ऐतिहासिक");
     }
 
     #[test]
     fn test_aadhi_vriddhi_matra_i() {
         // दिनिक → दैनिक (matra ि → ै)
-        let p = rule_aadhi_vriddhi("दिनिक").expect("should correct दिनिक");
+        let p = run_rule(rule_aadhi_vriddhi, "दिनिक").expect("should correct दिनिक");
         assert_eq!(p.output, "दैनिक");
     }
 
     #[test]
     fn test_aadhi_vriddhi_standalone_u() {
         // उद्योगिक → औद्योगिक (standalone उ → औ)
-        let p = rule_aadhi_vriddhi("उद्योगिक").expect("should correct उद्योगिक");
+        let p = run_rule(rule_aadhi_vriddhi, "उद्योगिक").expect("should correct उद्योगिक");
         assert_eq!(p.output, "औद्योगिक");
     }
 
     #[test]
     fn test_aadhi_vriddhi_already_correct() {
         // आर्थिक → None (root "आर्थ" not in kosha)
-        assert!(rule_aadhi_vriddhi("आर्थिक").is_none());
+        assert!(run_rule(rule_aadhi_vriddhi, "आर्थिक").is_none());
     }
 
     #[test]
     fn test_aadhi_vriddhi_no_suffix() {
         // संगीत → None (no -इक suffix)
-        assert!(rule_aadhi_vriddhi("संगीत").is_none());
+        assert!(run_rule(rule_aadhi_vriddhi, "संगीत").is_none());
     }
 
     // --- Ya/E distinction tests ---
@@ -787,27 +1259,27 @@ mod tests {
     #[test]
     fn test_ya_e_e_to_ya() {
         // एथार्थ → यथार्थ
-        let p = rule_ya_e("एथार्थ").expect("should correct एथार्थ");
+        let p = run_rule(rule_ya_e, "एथार्थ").expect("should correct एथार्थ");
         assert_eq!(p.output, "यथार्थ");
     }
 
     #[test]
     fn test_ya_e_ya_to_e() {
         // यकता → एकता
-        let p = rule_ya_e("यकता").expect("should correct यकता");
+        let p = run_rule(rule_ya_e, "यकता").expect("should correct यकता");
         assert_eq!(p.output, "एकता");
     }
 
     #[test]
     fn test_ya_e_already_valid() {
         // एक → None (in kosha)
-        assert!(rule_ya_e("एक").is_none());
+        assert!(run_rule(rule_ya_e, "एक").is_none());
     }
 
     #[test]
     fn test_ya_e_no_match() {
         // नेपाल → None (doesn't start with ए/य)
-        assert!(rule_ya_e("नेपाल").is_none());
+        assert!(run_rule(rule_ya_e, "नेपाल").is_none());
     }
 
     // --- Ksha/Chhya distinction tests ---
@@ -815,28 +1287,75 @@ mod tests {
     #[test]
     fn test_ksha_chhya_chhy_to_kshy() {
         // लछ्य → लक्ष्य
-        let p = rule_ksha_chhya("लछ्य").expect("should correct लछ्य");
+        let p = run_rule(rule_ksha_chhya, "लछ्य").expect("should correct लछ्य");
         assert_eq!(p.output, "लक्ष्य");
     }
 
     #[test]
     fn test_ksha_chhya_chh_to_ksh() {
         // छमा → क्षमा (छ→क्ष)
-        let p = rule_ksha_chhya("छमा").expect("should correct छमा");
+        let p = run_rule(rule_ksha_chhya, "छमा").expect("should correct छमा");
         assert_eq!(p.output, "क्षमा");
     }
 
     #[test]
     fn test_ksha_chhya_ksh_to_chchh() {
         // इक्षा → इच्छा
-        let p = rule_ksha_chhya("इक्षा").expect("should correct इक्षा");
+        let p = run_rule(rule_ksha_chhya, "इक्षा").expect("should correct इक्षा");
         assert_eq!(p.output, "इच्छा");
     }
 
     #[test]
     fn test_ksha_chhya_already_valid() {
         // क्षेत्र → None (in kosha)
-        assert!(rule_ksha_chhya("क्षेत्र").is_none());
+        assert!(run_rule(rule_ksha_chhya, "क्षेत्र").is_none());
+    }
+
+    // --- Conjunct normalization tests ---
+
+    #[test]
+    fn test_conjunct_normalize_double_halanta() {
+        let p = run_rule(rule_conjunct_normalize, "सत््य").expect("should correct सत््य");
+        assert_eq!(p.output, "सत्य");
+    }
+
+    #[test]
+    fn test_conjunct_normalize_repeated_consonant_halanta() {
+        let p = run_rule(rule_conjunct_normalize, "अक्क्षर").expect("should correct अक्क्षर");
+        assert_eq!(p.output, "अक्षर");
+    }
+
+    #[test]
+    fn test_conjunct_normalize_keeps_geminates() {
+        assert!(run_rule(rule_conjunct_normalize, "सच्चा").is_none());
+        assert!(run_rule(rule_conjunct_normalize, "पक्का").is_none());
+
+        // A tripled geminate collapses back to the valid doubled form.
+        let p = run_rule(rule_conjunct_normalize, "सच्च्चा").expect("should correct सच्च्चा");
+        assert_eq!(p.output, "सच्चा");
+        let p = run_rule(rule_conjunct_normalize, "पक्क््का").expect("should correct पक्क््का");
+        assert_eq!(p.output, "पक्का");
+    }
+
+    #[test]
+    fn test_redundant_taa_on_abstract_nouns() {
+        let p = run_rule(rule_redundant_taa, "सौजन्यता").expect("should drop -ता");
+        assert_eq!(p.output, "सौजन्य");
+        let p = run_rule(rule_redundant_taa, "वैशिष्ट्यता").expect("should drop -ता");
+        assert_eq!(p.output, "वैशिष्ट्य");
+        let p = run_rule(rule_redundant_taa, "महत्त्वता").expect("should drop -ता");
+        assert_eq!(p.output, "महत्त्व");
+    }
+
+    #[test]
+    fn test_redundant_taa_keeps_valid_taa_words() {
+        for word in ["सुन्दरता", "मान्यता", "योग्यता", "सत्यता", "रम्यता"]
+        {
+            assert!(
+                run_rule(rule_redundant_taa, word).is_none(),
+                "{word} must be kept"
+            );
+        }
     }
 
     // --- Gya/Gyan distinction tests ---
@@ -844,57 +1363,159 @@ mod tests {
     #[test]
     fn test_gya_gyan_gya_to_gya_nya() {
         // अग्यान -> अज्ञान
-        let p = rule_gya_gyan("अग्यान").expect("should correct अग्यान");
+        let p = run_rule(rule_gya_gyan, "अग्यान").expect("should correct अग्यान");
         assert_eq!(p.output, "अज्ञान");
     }
 
     #[test]
     fn test_gya_gyan_another_gya_to_gya_nya() {
         // प्रग्या -> प्रज्ञा
-        let p = rule_gya_gyan("प्रग्या").expect("should correct प्रग्या");
+        let p = run_rule(rule_gya_gyan, "प्रग्या").expect("should correct प्रग्या");
         assert_eq!(p.output, "प्रज्ञा");
     }
 
+    #[test]
+    fn test_gya_gyan_medial_and_final() {
+        // Medial ज्ञ without आ: विग्यप्ति -> विज्ञप्ति
+        let p = run_rule(rule_gya_gyan, "विग्यप्ति").expect("should correct विग्यप्ति");
+        assert_eq!(p.output, "विज्ञप्ति");
+        // Word-final ज्ञ: सर्वग्य -> सर्वज्ञ
+        let p = run_rule(rule_gya_gyan, "सर्वग्य").expect("should correct सर्वग्य");
+        assert_eq!(p.output, "सर्वज्ञ");
+    }
+
+    #[test]
+    fn test_gya_gyan_reverse_for_loanword() {
+        // ज्ञारेज -> ग्यारेज: the ग्य form is an आगन्तुक kosha word
+        let p = run_rule(rule_gya_gyan, "ज्ञारेज").expect("should correct ज्ञारेज");
+        assert_eq!(p.output, "ग्यारेज");
+        assert!(run_rule(rule_gya_gyan, "ग्यारेज").is_none());
+        assert!(run_rule(rule_gya_gyan, "ज्ञान").is_none());
+    }
+
     #[test]
     fn test_gya_gyan_keeps_valid_loanword() {
         // ग्यारेज is a valid loanword form
-        assert!(rule_gya_gyan("ग्यारेज").is_none());
+        assert!(run_rule(rule_gya_gyan, "ग्यारेज").is_none());
     }
 
     #[test]
     fn test_gya_gyan_keeps_valid_tatsam() {
         // अज्ञान is valid तत्सम form
-        assert!(rule_gya_gyan("अज्ञान").is_none());
+        assert!(run_rule(rule_gya_gyan, "अज्ञान").is_none());
     }
 
     #[test]
     fn test_chandrabindu_does_not_overflag_tatsam_shirbindu() {
-        assert!(rule_chandrabindu("अंश").is_none());
-        assert!(rule_chandrabindu("अंशु").is_none());
-        assert!(rule_chandrabindu("संसार").is_none());
+        assert!(run_rule(rule_chandrabindu, "अंश").is_none());
+        assert!(run_rule(rule_chandrabindu, "अंशु").is_none());
+        assert!(run_rule(rule_chandrabindu, "संसार").is_none());
         // Tatsam words where Anusvara is before a stop consonant (classic pancham varna logic)
         // These should also be ignored by the rule, as Shirbindu is valid here.
-        assert!(rule_chandrabindu("संघर्ष").is_none());
-        assert!(rule_chandrabindu("संघीय").is_none());
+        assert!(run_rule(rule_chandrabindu, "संघर्ष").is_none());
+        assert!(run_rule(rule_chandrabindu, "संघीय").is_none());
     }
 
     #[test]
     fn test_chandrabindu_keeps_common_corrections() {
-        let p = rule_chandrabindu("बांस").expect("should correct बांस");
+        let p = run_rule(rule_chandrabindu, "बांस").expect("should correct बांस");
         assert_eq!(p.output, "बाँस");
 
-        let p = rule_chandrabindu("हांस").expect("should correct हांस");
+        let p = run_rule(rule_chandrabindu, "हांस").expect("should correct हांस");
         assert_eq!(p.output, "हाँस");
 
-        let p = rule_chandrabindu("गरें").expect("should correct गरें");
+        let p = run_rule(rule_chandrabindu, "गरें").expect("should correct गरें");
         assert_eq!(p.output, "गरेँ");
 
-        let p = rule_chandrabindu("जान्छौं").expect("should correct जान्छौं");
+        let p = run_rule(rule_chandrabindu, "जान्छौं").expect("should correct जान्छौं");
         assert_eq!(p.output, "जान्छौँ");
     }
 
+    #[test]
+    fn test_verb_ending_chandrabindu() {
+        let p = run_rule(rule_verb_ending_chandrabindu, "जान्छौं").expect("should correct जान्छौं");
+        assert_eq!(p.output, "जान्छौँ");
+
+        let p = run_rule(rule_verb_ending_chandrabindu, "गरें").expect("should correct गरें");
+        assert_eq!(p.output, "गरेँ");
+    }
+
+    #[test]
+    fn test_verb_ending_chandrabindu_without_lexicon() {
+        // Neither form is in the kosha; the verb ending alone decides.
+        assert!(!kosha().contains("चिप्लिन्छौँ"));
+        let p = run_rule(rule_verb_ending_chandrabindu, "चिप्लिन्छौं").expect("should correct");
+        assert_eq!(p.output, "चिप्लिन्छौँ");
+
+        assert!(!kosha().contains("लेखेँ"));
+        let p = run_rule(rule_verb_ending_chandrabindu, "लेखें").expect("should correct");
+        assert_eq!(p.output, "लेखेँ");
+    }
+
+    #[test]
+    fn test_verb_ending_chandrabindu_skips_non_verbs() {
+        assert!(run_rule(rule_verb_ending_chandrabindu, "संघीय").is_none());
+        assert!(run_rule(rule_verb_ending_chandrabindu, "जान्छौ").is_none());
+        assert!(run_rule(rule_verb_ending_chandrabindu, "जान्छौँ").is_none());
+    }
+
+    #[test]
+    fn test_punah_visarga_before_sibilant() {
+        let p = run_rule(rule_punah_visarga, "पुनर्स्थापित").expect("should correct पुनर्स्थापित");
+        assert_eq!(p.output, "पुनःस्थापित");
+
+        let p = run_rule(rule_punah_visarga, "पुनर्समीक्षा").expect("should correct पुनर्समीक्षा");
+        assert_eq!(p.output, "पुनःसमीक्षा");
+    }
+
+    #[test]
+    fn test_punah_visarga_keeps_punar_before_vowel_or_voiced() {
+        assert!(run_rule(rule_punah_visarga, "पुनरवलोकन").is_none());
+        assert!(run_rule(rule_punah_visarga, "पुनर्जन्म").is_none());
+        assert!(run_rule(rule_punah_visarga, "पुनःस्थापित").is_none());
+    }
+
+    #[test]
+    fn test_nih_duh_prefix_takes_form_of_following_sound() {
+        for (input, expected) in [
+            ("निष्तेज", "निस्तेज"),
+            ("निस्फल", "निष्फल"),
+            ("निर्फल", "निष्फल"),
+            ("निर्चय", "निश्चय"),
+            ("दुर्कर्म", "दुष्कर्म"),
+            ("दुष्चरित्र", "दुश्चरित्र"),
+            ("निर्रस", "नीरस"),
+        ] {
+            let p = run_rule(rule_nih_duh_prefix, input)
+                .unwrap_or_else(|| panic!("should correct {input}"));
+            assert_eq!(p.output, expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_nih_duh_prefix_keeps_correct_forms() {
+        // निर् + स्वर: र मात्रासहित लेखिन्छ।
+        assert!(run_rule(rule_nih_duh_prefix, "निराकरण").is_none());
+        for word in [
+            "निस्तेज",
+            "निष्फल",
+            "निश्चय",
+            "निर्धन",
+            "दुर्गम",
+            "निःसन्देह",
+            "निस्सन्देह",
+        ] {
+            assert!(run_rule(rule_nih_duh_prefix, word).is_none(), "{word}");
+        }
+        // दुःख उपसर्ग-सन्धि होइन; श्वास, स्पृह मूल शब्दका संयुक्त वर्ण हुन्।
+        for word in ["दुःख", "निश्वास", "निस्पृह", "दुस्मन"]
+        {
+            assert!(run_rule(rule_nih_duh_prefix, word).is_none(), "{word}");
+        }
+    }
+
     #[test]
     fn test_chandrabindu_does_not_rewrite_notice_example_bhainsi() {
-        assert!(rule_chandrabindu("भैंसी").is_none());
+        assert!(run_rule(rule_chandrabindu, "भैंसी").is_none());
     }
 }
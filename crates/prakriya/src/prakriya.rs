@@ -43,6 +43,31 @@ impl Prakriya {
         }
     }
 
+    /// Re-apply the recorded steps to `input` and check they reach `output`.
+    ///
+    /// Each step must start from the text the previous step left behind;
+    /// the first step starts from `input`. Returns the replayed output.
+    pub fn replay(&self) -> Result<String, ReplayError> {
+        let mut current = self.input.clone();
+        for (index, step) in self.steps.iter().enumerate() {
+            if step.before != current {
+                return Err(ReplayError::StepMismatch {
+                    index,
+                    expected: step.before.clone(),
+                    found: current,
+                });
+            }
+            current.clone_from(&step.after);
+        }
+        if current != self.output {
+            return Err(ReplayError::OutputMismatch {
+                expected: self.output.clone(),
+                found: current,
+            });
+        }
+        Ok(current)
+    }
+
     /// Attach typed metadata from rule dispatch.
     pub fn with_metadata(mut self, category: RuleCategory, kind: DiagnosticKind) -> Self {
         self.category = Some(category);
@@ -51,6 +76,21 @@ impl Prakriya {
     }
 }
 
+/// Error from [`Prakriya::replay`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ReplayError {
+    /// A step's `before` does not match the text reached so far.
+    #[error("step {index} expects '{expected}' but the derivation is at '{found}'")]
+    StepMismatch {
+        index: usize,
+        expected: String,
+        found: String,
+    },
+    /// The steps end somewhere other than `output`.
+    #[error("replay ends at '{found}', expected output '{expected}'")]
+    OutputMismatch { expected: String, found: String },
+}
+
 impl std::fmt::Display for Prakriya {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.is_correct {
@@ -1,3 +1,7 @@
+use std::cell::OnceCell;
+
+use varnavinyas_shabda::{Origin, OriginDecision, classify_with_provenance};
+
 use crate::prakriya::Prakriya;
 use crate::rule::Rule;
 
@@ -36,10 +40,22 @@ pub enum RuleCategory {
     Sandhi,
     AadhiVriddhi,
     YaE,
+    BaVa,
     KshaChhya,
     GyaGyan,
 }
 
+/// Every origin class, for rules that fire regardless of origin.
+pub const ALL_ORIGINS: &[Origin] = &[
+    Origin::Tatsam,
+    Origin::Tadbhav,
+    Origin::Deshaj,
+    Origin::Aagantuk,
+];
+
+/// Every origin except तत्सम, for rules that leave तत्सम words untouched.
+pub const NON_TATSAM: &[Origin] = &[Origin::Tadbhav, Origin::Deshaj, Origin::Aagantuk];
+
 /// Metadata for a single pattern rule.
 #[derive(Debug, Clone, Copy)]
 pub struct RuleSpec {
@@ -53,6 +69,9 @@ pub struct RuleSpec {
     pub priority: u16,
     /// Academy standard citation.
     pub citation: Rule,
+    /// Word origins the rule is gated to fire for; [`ALL_ORIGINS`] when the
+    /// rule does not look at origin.
+    pub origins: &'static [Origin],
     /// Example (incorrect, correct) pairs.
     pub examples: &'static [(&'static str, &'static str)],
 }
@@ -62,5 +81,63 @@ pub struct PatternRule {
     /// Rule metadata.
     pub spec: RuleSpec,
     /// The rule function: takes input, returns corrected Prakriya if applicable.
-    pub apply: fn(&str) -> Option<Prakriya>,
+    pub apply: fn(&str, &RuleContext) -> Option<Prakriya>,
+}
+
+/// Per-word facts shared by every pattern rule during one `derive` call.
+///
+/// The origin is classified lazily, at most once per word, the first time a
+/// rule asks for it; rules that never look at origin cost nothing extra.
+pub struct RuleContext<'a> {
+    word: &'a str,
+    origin: OnceCell<OriginDecision>,
+    #[cfg(test)]
+    pub(crate) classify_calls: std::cell::Cell<usize>,
+}
+
+impl<'a> RuleContext<'a> {
+    /// Create a context for `word`, the same input passed to each rule.
+    pub fn new(word: &'a str) -> Self {
+        Self {
+            word,
+            origin: OnceCell::new(),
+            #[cfg(test)]
+            classify_calls: std::cell::Cell::new(0),
+        }
+    }
+
+    /// Create a context whose origin decision is already known.
+    pub fn with_origin(word: &'a str, decision: OriginDecision) -> Self {
+        let ctx = Self::new(word);
+        let _ = ctx.origin.set(decision);
+        ctx
+    }
+
+    /// The origin decision, if a rule has asked for it (or it was seeded).
+    pub fn computed_origin(&self) -> Option<&OriginDecision> {
+        self.origin.get()
+    }
+
+    /// Origin decision (with provenance) for the word.
+    pub fn origin_decision(&self) -> &OriginDecision {
+        self.origin.get_or_init(|| {
+            #[cfg(test)]
+            self.classify_calls.set(self.classify_calls.get() + 1);
+            classify_with_provenance(self.word)
+        })
+    }
+
+    /// Origin class of the word.
+    pub fn origin(&self) -> Origin {
+        self.origin_decision().origin
+    }
+}
+
+/// Run a single rule on `input` with a fresh context.
+#[cfg(test)]
+pub(crate) fn run_rule(
+    rule: fn(&str, &RuleContext) -> Option<Prakriya>,
+    input: &str,
+) -> Option<Prakriya> {
+    rule(input, &RuleContext::new(input))
 }
@@ -1,8 +1,8 @@
 use crate::prakriya::Prakriya;
 use crate::rule::Rule;
-use crate::rule_spec::{DiagnosticKind, RuleCategory, RuleSpec};
+use crate::rule_spec::{ALL_ORIGINS, DiagnosticKind, RuleCategory, RuleContext, RuleSpec};
 use crate::step::Step;
-use varnavinyas_shabda::{Origin, classify};
+use varnavinyas_shabda::Origin;
 
 pub const SPEC_SHRI: RuleSpec = RuleSpec {
     id: "struct-shri",
@@ -10,6 +10,7 @@ pub const SPEC_SHRI: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 100,
     citation: Rule::ShuddhaAshuddha("Section 4"),
+    origins: ALL_ORIGINS,
     examples: &[("श्रृङ्गार", "शृङ्गार")],
 };
 
@@ -19,6 +20,7 @@ pub const SPEC_REDUNDANT_SUFFIX: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 110,
     citation: Rule::ShuddhaAshuddha("Section 4"),
+    origins: ALL_ORIGINS,
     examples: &[("सौन्दर्यता", "सौन्दर्य"), ("औचित्यता", "औचित्य")],
 };
 
@@ -28,11 +30,13 @@ pub const SPEC_PANCHHAM: RuleSpec = RuleSpec {
     kind: DiagnosticKind::Error,
     priority: 120,
     citation: Rule::VarnaVinyasNiyam("3(ख)-पञ्चम"),
+    origins: &[Origin::Tatsam],
     examples: &[("संकेत", "सङ्केत"), ("संघीय", "सङ्घीय")],
 };
 
-pub fn rule_shri_correction(input: &str) -> Option<Prakriya> {
-    // श्रृ → शृ (common error pattern)
+pub fn rule_shri_correction(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
+    // श्र + ृ is never a valid sequence: ऋ attaches to श directly (शृ), so
+    // any श्रृ is a misspelling regardless of the word or the lexicon.
     if input.contains("श्रृ") {
         let output = input.replace("श्रृ", "शृ");
         return Some(Prakriya::corrected(
@@ -49,7 +53,7 @@ pub fn rule_shri_correction(input: &str) -> Option<Prakriya> {
     None
 }
 
-pub fn rule_redundant_suffix(input: &str) -> Option<Prakriya> {
+pub fn rule_redundant_suffix(input: &str, _ctx: &RuleContext) -> Option<Prakriya> {
     // Words ending in -र्यता or -त्यता → remove -ता
     // e.g., सौन्दर्यता → सौन्दर्य, औचित्यता → औचित्य
     if input.chars().count() < 6 {
@@ -79,8 +83,8 @@ pub fn rule_redundant_suffix(input: &str) -> Option<Prakriya> {
 /// - Before ट/ठ/ड/ढ/ण → ण् (e.g., कंटक→कण्टक)
 /// - Before त/थ/द/ध/न/त्र → न् (e.g., संतोष→सन्तोष)
 /// - Before प/फ/ब/भ/म → म् (e.g., संपन्न→सम्पन्न)
-pub fn rule_panchham_varna(input: &str) -> Option<Prakriya> {
-    let origin = classify(input);
+pub fn rule_panchham_varna(input: &str, ctx: &RuleContext) -> Option<Prakriya> {
+    let origin = ctx.origin();
 
     // Only for तत्सम words (तद्भव/आगन्तुक write as-pronounced)
     if !matches!(origin, Origin::Tatsam) {
@@ -1,4 +1,4 @@
-use varnavinyas_prakriya::{Rule, derive};
+use varnavinyas_prakriya::{ReplayError, Rule, Step, derive};
 
 // P1: Corrects अत्याधिक → अत्यधिक
 #[test]
@@ -69,6 +69,55 @@ fn p8_suffix_eli_hrasva() {
     assert!(!p.is_correct);
 }
 
+// Plural -हरु → -हरू on any stem
+#[test]
+fn plural_haru_dirgha() {
+    for (input, expected) in [("केटाहरु", "केटाहरू"), ("किताबहरु", "किताबहरू")]
+    {
+        let p = derive(input);
+        assert_eq!(p.output, expected);
+        assert!(!p.is_correct);
+    }
+}
+
+#[test]
+fn plural_haru_ignores_non_plural_ru() {
+    for word in ["गुरु", "पहरु"] {
+        let p = derive(word);
+        assert!(
+            !p.steps
+                .iter()
+                .any(|s| s.rule == Rule::VarnaVinyasNiyam("3(क)-suffix-हरू")),
+            "{word} must not get the plural rule: {p:?}"
+        );
+    }
+    assert!(derive("गुरु").is_correct);
+}
+
+// Generic -ता redundancy beyond the static table
+#[test]
+fn redundant_taa_generic() {
+    let p = derive("सौजन्यता");
+    assert_eq!(p.output, "सौजन्य");
+    assert!(derive("सुन्दरता").is_correct);
+}
+
+// Section titles for the codes the correction table emits
+#[test]
+fn correction_table_rules_resolve_to_their_section() {
+    let cases = [
+        ("एथार्थ", "नियम ३(इ): य र ए"),
+        ("यकता", "नियम ३(इ): य र ए"),
+        ("लछ्य", "नियम ३(छ): क्ष र ज्ञ"),
+        ("छेत्र", "नियम ३(छ): क्ष र ज्ञ"),
+    ];
+    for (word, section) in cases {
+        let p = derive(word);
+        assert!(!p.is_correct, "Expected '{word}' to be corrected");
+        assert_eq!(p.steps[0].rule.academy_section(), Some(section), "{word}");
+    }
+}
+
 // Additional tests
 #[test]
 fn correct_word_passes_through() {
@@ -266,6 +315,40 @@ fn o7_ksha_chhya_kshetra() {
     assert_eq!(p.output, "क्षेत्र");
 }
 
+#[test]
+fn o7_ksha_chhya_medial_chchha() {
+    let p = derive("परिछेद");
+    assert_eq!(p.output, "परिच्छेद");
+    let p = derive("इछा");
+    assert_eq!(p.output, "इच्छा");
+}
+
+#[test]
+fn o7_ksha_chhya_with_dirgha_before_ksha() {
+    let p = derive("परिक्षा");
+    assert_eq!(p.output, "परीक्षा");
+    assert_eq!(p.steps[0].rule, Rule::VarnaVinyasNiyam("3(क)"));
+
+    // क्ष/छ and ह्रस्व/दीर्घ errors in the same word.
+    let p = derive("परिछा");
+    assert_eq!(p.output, "परीक्षा");
+    assert_eq!(p.steps.len(), 2);
+    assert_eq!(p.steps[1].rule, Rule::VarnaVinyasNiyam("3(उ)"));
+}
+
+#[test]
+fn o7_ksha_chhya_keeps_valid_words() {
+    for word in ["परीक्षा", "परिच्छेद", "इच्छा", "पक्ष", "बिछ्यौना", "निरीक्षण"]
+    {
+        let p = derive(word);
+        assert!(
+            p.is_correct,
+            "{word} should stay unchanged, got {}",
+            p.output
+        );
+    }
+}
+
 #[test]
 fn o7_gya_gyan_agyan() {
     let p = derive("अग्यान");
@@ -312,3 +395,61 @@ fn notice_section4_uparokta_gets_corrected() {
     assert_eq!(p.output, "उपर्युक्त");
     assert!(!p.is_correct);
 }
+
+// श्रृ → शृ applies to any word, not just the correction-table entries
+#[test]
+fn shri_ri_corrected_outside_table() {
+    let p = derive("श्रृगाल");
+    assert_eq!(p.output, "शृगाल");
+    assert!(!p.is_correct);
+
+    let p = derive("श्रृङ्खलाबद्ध");
+    assert_eq!(p.output, "शृङ्खलाबद्ध");
+}
+
+#[test]
+fn shri_ri_leaves_valid_shra_words() {
+    for word in ["श्रम", "श्रद्धा", "श्रीमती"] {
+        let p = derive(word);
+        assert!(p.is_correct, "{word} must not be changed, got {}", p.output);
+    }
+}
+
+#[test]
+fn replay_multi_step_derivation() {
+    let p = derive("परिछा");
+    assert_eq!(p.steps.len(), 2);
+    assert_eq!(p.replay(), Ok("परीक्षा".to_string()));
+
+    assert_eq!(derive("नेपाल").replay(), Ok("नेपाल".to_string()));
+}
+
+#[test]
+fn replay_rejects_broken_chain() {
+    let mut p = derive("परिछा");
+    p.steps[1] = Step::new(Rule::VarnaVinyasNiyam("3(उ)"), "", "परिछा", "परीक्षा");
+    assert!(matches!(
+        p.replay(),
+        Err(ReplayError::StepMismatch { index: 1, .. })
+    ));
+}
+
+// 3(ङ): dropped halanta inside a tatsam conjunct
+#[test]
+fn internal_conjunct_halanta_restored() {
+    for (input, expected) in [("आतमा", "आत्मा"), ("पुसतक", "पुस्तक"), ("अवसथा", "अवस्था")]
+    {
+        let p = derive(input);
+        assert_eq!(p.output, expected);
+        assert_eq!(p.steps[0].rule, Rule::VarnaVinyasNiyam("3(ङ)"));
+    }
+}
+
+#[test]
+fn internal_conjunct_leaves_kosha_words() {
+    // पदमा is पद + मा, not a misspelt पद्मा.
+    for word in ["पदमा", "धरम", "कलम", "नेपाल"] {
+        let p = derive(word);
+        assert!(p.is_correct, "{word} must not change, got {}", p.output);
+    }
+}
@@ -19,3 +19,25 @@ fn o7_bv_bidesh() {
     let p = derive("बिदेश");
     assert_eq!(p.output, "विदेश");
 }
+
+// Not in the correction table: caught by the kosha-backed ब/व rule.
+#[test]
+fn o7_bv_rule_vishaya() {
+    let p = derive("बिषय");
+    assert_eq!(p.output, "विषय");
+    assert!(!p.is_correct);
+}
+
+#[test]
+fn o7_bv_rule_vibhaag_veg() {
+    assert_eq!(derive("बिभाग").output, "विभाग");
+    assert_eq!(derive("बेग").output, "वेग");
+}
+
+#[test]
+fn o7_bv_rule_keeps_native_ba_words() {
+    for word in ["बिहान", "बिरालो", "बजार", "बल"] {
+        let p = derive(word);
+        assert_eq!(p.output, word, "{word} should stay unchanged");
+    }
+}
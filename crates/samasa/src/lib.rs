@@ -1,6 +1,12 @@
+use varnavinyas_akshar::split_aksharas;
 use varnavinyas_kosha::WordEntry;
 use varnavinyas_kosha::kosha;
 
+/// Default upper bound, in aksharas, on the words [`analyze_compound`] will
+/// analyze. Longer tokens return no candidates instead of paying for a
+/// boundary scan that cannot produce a plausible compound.
+pub const MAX_COMPOUND_AKSHARAS: usize = varnavinyas_sandhi::MAX_SPLIT_AKSHARAS;
+
 /// Initial samasa taxonomy for MVP.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SamasaType {
@@ -29,9 +35,19 @@ pub struct SamasaCandidate {
 /// - generate candidates from sandhi split
 /// - add direct lexical boundary candidates (for compounds with no explicit sandhi mutation)
 /// - assign heuristic samasa type + score
+/// - keep the best type per boundary (ties within an epsilon are kept)
 /// - return ranked candidates
+///
+/// Words longer than [`MAX_COMPOUND_AKSHARAS`] return no candidates; use
+/// [`analyze_compound_with_limit`] to choose a different bound.
 pub fn analyze_compound(word: &str) -> Vec<SamasaCandidate> {
-    if word.is_empty() {
+    analyze_compound_with_limit(word, MAX_COMPOUND_AKSHARAS)
+}
+
+/// [`analyze_compound`] with an explicit upper bound on the word length in
+/// aksharas. Longer words return an empty list without being analyzed.
+pub fn analyze_compound_with_limit(word: &str, max_aksharas: usize) -> Vec<SamasaCandidate> {
+    if word.is_empty() || split_aksharas(word).len() > max_aksharas {
         return Vec::new();
     }
 
@@ -39,7 +55,7 @@ pub fn analyze_compound(word: &str) -> Vec<SamasaCandidate> {
     let mut out = Vec::new();
 
     // Strategy 1: sandhi-backed candidates.
-    for (left, right, _res) in varnavinyas_sandhi::split(word) {
+    for (left, right, _res) in varnavinyas_sandhi::split_with_limit(word, max_aksharas) {
         push_candidate(&mut out, &left, &right, 0.0);
     }
 
@@ -67,6 +83,28 @@ pub fn analyze_compound(word: &str) -> Vec<SamasaCandidate> {
             .then_with(|| a.right.cmp(&b.right))
     });
     out.dedup_by(|a, b| a.left == b.left && a.right == b.right && a.samasa_type == b.samasa_type);
+    prune_weaker_types(out)
+}
+
+/// Score gap below which two types for the same boundary count as a tie.
+const TYPE_TIE_EPSILON: f32 = 0.01;
+
+/// For each `(left, right)` boundary keep only the best-scoring type, plus
+/// any other type within [`TYPE_TIE_EPSILON`] of it (genuine ambiguity).
+///
+/// Expects `candidates` sorted by descending score, so the first candidate
+/// seen for a boundary is its best.
+fn prune_weaker_types(candidates: Vec<SamasaCandidate>) -> Vec<SamasaCandidate> {
+    let mut best: Vec<(String, String, f32)> = Vec::new();
+    let mut out = Vec::with_capacity(candidates.len());
+    for c in candidates {
+        match best.iter().find(|(l, r, _)| *l == c.left && *r == c.right) {
+            Some(&(_, _, top)) if top - c.score > TYPE_TIE_EPSILON => continue,
+            Some(_) => {}
+            None => best.push((c.left.clone(), c.right.clone(), c.score)),
+        }
+        out.push(c);
+    }
     out
 }
 
@@ -101,7 +139,7 @@ fn classify_candidate(
     let right_pos = right_entry.map(|e| e.pos).unwrap_or("");
 
     // Dvigu: numeral-led compounds.
-    if is_numeral(left) {
+    if is_dvigu_head(left, right_pos) {
         return (SamasaType::Dvigu, 0.92);
     }
 
@@ -143,7 +181,10 @@ fn make_vigraha(left: &str, right: &str, t: SamasaType) -> String {
     match t {
         SamasaType::Tatpurusha => format!("{left} को {right}"),
         SamasaType::Karmadharaya => format!("{left} {right}"),
-        SamasaType::Dvigu => format!("{left} वटा {right}"),
+        SamasaType::Dvigu => match cardinal_value(left).and_then(nepali_count_word) {
+            Some(count) => format!("{count} वटा {right}"),
+            None => format!("{left} वटा {right}"),
+        },
         SamasaType::Bahuvrihi => format!("{left} {right} भएको"),
         SamasaType::Dvandva => format!("{left} र {right}"),
         SamasaType::Avyayibhava => format!("{left} {right}"),
@@ -152,20 +193,138 @@ fn make_vigraha(left: &str, right: &str, t: SamasaType) -> String {
 }
 
 fn is_numeral(s: &str) -> bool {
-    matches!(
-        s,
-        "एक" | "द्वि"
-            | "त्रि"
-            | "चतुर"
-            | "चतु"
-            | "पञ्च"
-            | "षड"
-            | "षट"
-            | "सप्त"
-            | "अष्ट"
-            | "नव"
-            | "दश"
-    )
+    numeral_value(s).is_some()
+}
+
+/// Numeric value of a numeral word: a cardinal or an ordinal.
+///
+/// Covers the Sanskrit combining forms (त्रि, पञ्च, …) and Nepali numeral words
+/// (तीन, पाँच, …) from 1 to 20, the round numbers शत/सय and सहस्र/हजार, and
+/// the ordinals from 1 to 10 (प्रथम, द्वितीय, …; पहिलो, दोस्रो, …), which
+/// yield their position.
+pub fn numeral_value(s: &str) -> Option<u32> {
+    cardinal_value(s).or_else(|| ordinal_value(s))
+}
+
+/// Cardinal numeral that can head a dvigu compound.
+fn cardinal_value(s: &str) -> Option<u32> {
+    tatsam_cardinal_value(s).or_else(|| nepali_cardinal_value(s))
+}
+
+fn tatsam_cardinal_value(s: &str) -> Option<u32> {
+    let value = match s {
+        "एक" => 1,
+        "द्वि" => 2,
+        "त्रि" => 3,
+        "चतुर" | "चतु" | "चतुः" => 4,
+        "पञ्च" => 5,
+        "षड" | "षट" | "षष्" => 6,
+        "सप्त" => 7,
+        "अष्ट" => 8,
+        "नव" => 9,
+        "दश" => 10,
+        "एकादश" => 11,
+        "द्वादश" => 12,
+        "त्रयोदश" => 13,
+        "चतुर्दश" => 14,
+        "पञ्चदश" => 15,
+        "षोडश" => 16,
+        "सप्तदश" => 17,
+        "अष्टादश" => 18,
+        "ऊनविंशति" => 19,
+        "विंशति" => 20,
+        "शत" => 100,
+        "सहस्र" => 1000,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn nepali_cardinal_value(s: &str) -> Option<u32> {
+    let value = match s {
+        "दुई" => 2,
+        "तीन" => 3,
+        "चार" => 4,
+        "पाँच" => 5,
+        // छ is left out: it is far more often the copula than "six".
+        "सात" => 7,
+        "आठ" => 8,
+        "नौ" => 9,
+        "दस" => 10,
+        "एघार" => 11,
+        "बाह्र" => 12,
+        "तेह्र" => 13,
+        "चौध" => 14,
+        "पन्ध्र" => 15,
+        "सोह्र" => 16,
+        "सत्र" => 17,
+        "अठार" => 18,
+        "उन्नाइस" => 19,
+        "बीस" => 20,
+        "सय" => 100,
+        "हजार" => 1000,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn ordinal_value(s: &str) -> Option<u32> {
+    let value = match s {
+        "प्रथम" | "पहिलो" => 1,
+        "द्वितीय" | "दोस्रो" => 2,
+        "तृतीय" | "तेस्रो" => 3,
+        "चतुर्थ" | "चौथो" => 4,
+        "पञ्चम" | "पाँचौँ" => 5,
+        "षष्ठ" | "छैटौँ" => 6,
+        "सप्तम" | "सातौँ" => 7,
+        "अष्टम" | "आठौँ" => 8,
+        "नवम" | "नवौँ" => 9,
+        "दशम" | "दसौँ" => 10,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Whether `left` heads a dvigu compound with `right`.
+///
+/// Tatsam cardinals (त्रि, पञ्च, शत) always do. Nepali cardinals (सात, नौ, सय)
+/// also start ordinary words, so they count only before a kosha noun.
+/// Ordinals form karmadharaya (प्रथम पुरुष), not dvigu.
+fn is_dvigu_head(left: &str, right_pos: &str) -> bool {
+    tatsam_cardinal_value(left).is_some()
+        || (nepali_cardinal_value(left).is_some() && right_pos.contains("ना."))
+}
+
+/// Nepali cardinal word used in dvigu vigraha (त्रिलोक → तीन वटा लोक).
+fn nepali_count_word(n: u32) -> Option<&'static str> {
+    const WORDS: [&str; 20] = [
+        "एक",
+        "दुई",
+        "तीन",
+        "चार",
+        "पाँच",
+        "छ",
+        "सात",
+        "आठ",
+        "नौ",
+        "दस",
+        "एघार",
+        "बाह्र",
+        "तेह्र",
+        "चौध",
+        "पन्ध्र",
+        "सोह्र",
+        "सत्र",
+        "अठार",
+        "उन्नाइस",
+        "बीस",
+    ];
+    match n {
+        1..=20 => Some(WORDS[n as usize - 1]),
+        100 => Some("सय"),
+        1000 => Some("हजार"),
+        _ => None,
+    }
 }
 
 fn is_adjectival_prefix(s: &str) -> bool {
@@ -190,6 +349,57 @@ mod tests {
         assert!(score > 0.9);
     }
 
+    #[test]
+    fn numeral_value_covers_sanskrit_and_round_numbers() {
+        assert_eq!(numeral_value("त्रि"), Some(3));
+        assert_eq!(numeral_value("पञ्च"), Some(5));
+        assert_eq!(numeral_value("शत"), Some(100));
+        assert_eq!(numeral_value("विंशति"), Some(20));
+    }
+
+    #[test]
+    fn numeral_value_covers_nepali_numeral_words() {
+        assert_eq!(numeral_value("सात"), Some(7));
+        assert_eq!(numeral_value("नौ"), Some(9));
+        assert_eq!(numeral_value("दस"), Some(10));
+        assert_eq!(numeral_value("सय"), Some(100));
+        assert_eq!(numeral_value("हजार"), Some(1000));
+        assert_eq!(numeral_value("छ"), None);
+    }
+
+    #[test]
+    fn numeral_value_covers_ordinals() {
+        assert_eq!(numeral_value("प्रथम"), Some(1));
+        assert_eq!(numeral_value("द्वितीय"), Some(2));
+        assert_eq!(numeral_value("तृतीय"), Some(3));
+        assert_eq!(numeral_value("दोस्रो"), Some(2));
+        assert_eq!(numeral_value("दशम"), Some(10));
+    }
+
+    #[test]
+    fn nepali_numeral_needs_noun_for_dvigu() {
+        let noun = WordEntry {
+            word: "घर",
+            pos: "ना.",
+        };
+        let (t, _) = classify_candidate("सात", "घर", None, Some(&noun));
+        assert_eq!(t, SamasaType::Dvigu);
+        let (t, _) = classify_candidate("सात", "घर", None, None);
+        assert_ne!(t, SamasaType::Dvigu);
+        let (t, _) = classify_candidate("प्रथम", "घर", None, Some(&noun));
+        assert_ne!(t, SamasaType::Dvigu);
+    }
+
+    #[test]
+    fn dvigu_vigraha_uses_nepali_count() {
+        assert_eq!(make_vigraha("त्रि", "लोक", SamasaType::Dvigu), "तीन वटा लोक");
+        assert_eq!(
+            make_vigraha("पञ्च", "पात्र", SamasaType::Dvigu),
+            "पाँच वटा पात्र"
+        );
+        assert_eq!(make_vigraha("शत", "अब्द", SamasaType::Dvigu), "सय वटा अब्द");
+    }
+
     #[test]
     fn classify_avyayibhava_from_pos() {
         let left = WordEntry {
@@ -246,6 +456,37 @@ mod tests {
         assert_eq!(t, SamasaType::Dvandva);
     }
 
+    fn candidate(left: &str, right: &str, samasa_type: SamasaType, score: f32) -> SamasaCandidate {
+        SamasaCandidate {
+            left: left.to_string(),
+            right: right.to_string(),
+            samasa_type,
+            score,
+            vigraha: make_vigraha(left, right, samasa_type),
+        }
+    }
+
+    #[test]
+    fn prune_keeps_only_top_type_for_same_boundary() {
+        let pruned = prune_weaker_types(vec![
+            candidate("नील", "कण्ठ", SamasaType::Karmadharaya, 0.84),
+            candidate("नील", "कण्ठ", SamasaType::Bahuvrihi, 0.74),
+        ]);
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].samasa_type, SamasaType::Karmadharaya);
+    }
+
+    #[test]
+    fn prune_keeps_tied_types_as_ambiguity() {
+        let pruned = prune_weaker_types(vec![
+            candidate("नील", "कण्ठ", SamasaType::Karmadharaya, 0.8),
+            candidate("नील", "कण्ठ", SamasaType::Bahuvrihi, 0.8),
+            candidate("नी", "लकण्ठ", SamasaType::Unknown, 0.5),
+        ]);
+        assert_eq!(pruned.len(), 3);
+        assert_eq!(pruned[1].samasa_type, SamasaType::Bahuvrihi);
+    }
+
     #[test]
     fn analyze_compound_returns_ranked_candidates() {
         let candidates = analyze_compound("सूर्योदय");
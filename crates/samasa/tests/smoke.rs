@@ -1,4 +1,5 @@
-use varnavinyas_samasa::analyze_compound;
+use varnavinyas_akshar::split_aksharas;
+use varnavinyas_samasa::{MAX_COMPOUND_AKSHARAS, analyze_compound, analyze_compound_with_limit};
 
 #[test]
 fn known_compound_has_candidate() {
@@ -9,3 +10,16 @@ fn known_compound_has_candidate() {
             .any(|c| c.left == "सूर्य" && c.right == "उदय")
     );
 }
+
+#[test]
+fn overlong_token_has_no_candidates() {
+    let word = "सूर्योदय".repeat(60);
+    assert!(split_aksharas(&word).len() > MAX_COMPOUND_AKSHARAS);
+    assert!(analyze_compound(&word).is_empty());
+
+    // The bound is inclusive: a word exactly at the limit is still analyzed,
+    // one akshara over it is not.
+    let len = split_aksharas("सूर्योदय").len();
+    assert!(!analyze_compound_with_limit("सूर्योदय", len).is_empty());
+    assert!(analyze_compound_with_limit("सूर्योदय", len - 1).is_empty());
+}
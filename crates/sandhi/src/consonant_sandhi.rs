@@ -1,4 +1,4 @@
-use crate::{SandhiResult, SandhiType};
+use crate::{Rule, SandhiResult, SandhiType};
 
 /// Apply consonant sandhi at the boundary of two morphemes.
 pub fn apply_consonant_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
@@ -11,6 +11,8 @@ pub fn apply_consonant_sandhi(first: &str, second: &str) -> Option<SandhiResult>
                     output: result,
                     sandhi_type: SandhiType::ConsonantSandhi,
                     rule_citation: citation,
+                    rule: Rule::Vyakaran("sandhi-vyanjan"),
+                    confidence: 1.0,
                 });
             }
         }
@@ -30,6 +32,8 @@ pub fn apply_consonant_sandhi(first: &str, second: &str) -> Option<SandhiResult>
                     output: result,
                     sandhi_type: SandhiType::ConsonantSandhi,
                     rule_citation: "व्यञ्जन सन्धि: gemination (same consonant doubling)",
+                    rule: Rule::Vyakaran("sandhi-vyanjan"),
+                    confidence: 1.0,
                 });
             }
 
@@ -45,6 +49,8 @@ pub fn apply_consonant_sandhi(first: &str, second: &str) -> Option<SandhiResult>
                             output: result,
                             sandhi_type: SandhiType::ConsonantSandhi,
                             rule_citation: "व्यञ्जन सन्धि: stop→nasal before nasal (panchham assimilation)",
+                            rule: Rule::Vyakaran("sandhi-vyanjan"),
+                            confidence: 1.0,
                         });
                     }
                 }
@@ -63,6 +69,8 @@ pub fn apply_consonant_sandhi(first: &str, second: &str) -> Option<SandhiResult>
                         output: result,
                         sandhi_type: SandhiType::ConsonantSandhi,
                         rule_citation: "व्यञ्जन सन्धि: voiceless→voiced before voiced consonant",
+                        rule: Rule::Vyakaran("sandhi-vyanjan"),
+                        confidence: 1.0,
                     });
                 }
             }
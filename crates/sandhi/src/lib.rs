@@ -4,7 +4,8 @@ mod visarga_sandhi;
 mod vowel_sandhi;
 
 pub use consonant_sandhi::apply_consonant_sandhi;
-pub use split::split;
+pub use split::{MAX_SPLIT_AKSHARAS, split, split_with_limit};
+pub use varnavinyas_types::Rule;
 pub use visarga_sandhi::apply_visarga_sandhi;
 pub use vowel_sandhi::apply_vowel_sandhi;
 
@@ -17,6 +18,11 @@ pub enum SandhiType {
     VisargaSandhi,
     /// Consonant sandhi (हल् सन्धि): consonant assimilations.
     ConsonantSandhi,
+    /// No transformation: the morphemes join by plain concatenation.
+    ///
+    /// Only produced by [`apply_or_concat`]; [`apply`] reports this case as
+    /// [`SandhiError::NoRuleApplies`].
+    NoChange,
 }
 
 impl SandhiType {
@@ -26,6 +32,7 @@ impl SandhiType {
             Self::VowelSandhi => "स्वर सन्धि",
             Self::VisargaSandhi => "विसर्ग सन्धि",
             Self::ConsonantSandhi => "व्यञ्जन सन्धि",
+            Self::NoChange => "सन्धिरहित संयोग",
         }
     }
 }
@@ -35,7 +42,18 @@ impl SandhiType {
 pub struct SandhiResult {
     pub output: String,
     pub sandhi_type: SandhiType,
+    /// Human-readable citation of the rule applied, e.g.
+    /// "यण् सन्धि: इ/ई + स्वर → य".
     pub rule_citation: &'static str,
+    /// The same rule as a structured [`Rule`] with a stable code such as
+    /// `sandhi-yan`, for consumers that key on rule codes.
+    pub rule: Rule,
+    /// How much to trust this result, in `0.0..=1.0`.
+    ///
+    /// Always `1.0` from [`apply`], which only runs a rule forward. [`split`]
+    /// scores each reconstruction from how well attested its parts are in the
+    /// kosha and how specific the sandhi is.
+    pub confidence: f32,
 }
 
 /// Error type for sandhi operations.
@@ -77,6 +95,25 @@ pub fn apply(first: &str, second: &str) -> Result<SandhiResult, SandhiError> {
     })
 }
 
+/// Apply sandhi, falling back to plain concatenation.
+///
+/// Same as [`apply`], except that when no rule applies the morphemes are
+/// joined unchanged and reported as [`SandhiType::NoChange`] instead of
+/// [`SandhiError::NoRuleApplies`]. Useful where "valid but unchanged" is an
+/// expected outcome, such as composing compounds or rebuilding a split.
+pub fn apply_or_concat(first: &str, second: &str) -> Result<SandhiResult, SandhiError> {
+    match apply(first, second) {
+        Err(SandhiError::NoRuleApplies { .. }) => Ok(SandhiResult {
+            output: format!("{first}{second}"),
+            sandhi_type: SandhiType::NoChange,
+            rule_citation: "सन्धिरहित संयोग: कुनै ध्वनि परिवर्तन बिना सीधा जोड",
+            rule: Rule::Vyakaran("sandhi-none"),
+            confidence: 1.0,
+        }),
+        result => result,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SandhiType;
@@ -86,5 +123,6 @@ mod tests {
         assert_eq!(SandhiType::VowelSandhi.display_label(), "स्वर सन्धि");
         assert_eq!(SandhiType::VisargaSandhi.display_label(), "विसर्ग सन्धि");
         assert_eq!(SandhiType::ConsonantSandhi.display_label(), "व्यञ्जन सन्धि");
+        assert_eq!(SandhiType::NoChange.display_label(), "सन्धिरहित संयोग");
     }
 }
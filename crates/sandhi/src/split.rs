@@ -1,5 +1,5 @@
-use crate::{SandhiResult, apply};
-use varnavinyas_akshar::split_aksharas;
+use crate::{Rule, SandhiResult, SandhiType, apply};
+use varnavinyas_akshar::{is_matra, matra_to_svar, split_aksharas};
 use varnavinyas_kosha::kosha;
 use varnavinyas_kosha::origin_tag::OriginTag;
 
@@ -11,6 +11,13 @@ use varnavinyas_kosha::origin_tag::OriginTag;
 const ONE_AKSHARA_UPASARGAS: &[&str] = &["प्र", "वि", "सु", "नि", "आ"];
 const DIRECT_JOIN_UPASARGAS: &[&str] = &["प्र", "वि"];
 
+/// Default upper bound, in aksharas, on the words [`split`] will analyze.
+///
+/// Splitting tries several reconstructions at every character boundary, so
+/// a long run-on or nonsense token gets expensive quickly while no real
+/// sandhi compound comes anywhere near this length.
+pub const MAX_SPLIT_AKSHARAS: usize = 40;
+
 fn valid_split_parts(left: &str, right: &str) -> bool {
     let left_len = split_aksharas(left).len();
     let right_len = split_aksharas(right).len();
@@ -32,18 +39,36 @@ fn valid_split_parts(left: &str, right: &str) -> bool {
 ///    that would result in `word` when combined via sandhi.
 /// 4. Validate candidates against the kosha lexicon.
 /// 5. Filter results where either part has fewer than 2 aksharas.
+/// 6. Score each split (see [`SandhiResult::confidence`]) and return them
+///    most confident first.
+///
+/// Words longer than [`MAX_SPLIT_AKSHARAS`] return no candidates; use
+/// [`split_with_limit`] to choose a different bound.
 pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
+    split_with_limit(word, MAX_SPLIT_AKSHARAS)
+}
+
+/// [`split`] with an explicit upper bound on the word length in aksharas.
+/// Longer words return an empty list without being analyzed.
+pub fn split_with_limit(word: &str, max_aksharas: usize) -> Vec<(String, String, SandhiResult)> {
+    let len = split_aksharas(word).len();
     // Guard: stems shorter than 3 aksharas are atomic roots, not compounds.
-    if split_aksharas(word).len() < 3 {
+    if len < 3 || len > max_aksharas {
         return Vec::new();
     }
 
     let mut results = Vec::new();
+    // Reconstructions that only make sense as Sanskrit sandhi (a vowel hidden
+    // as a matra, a lengthened इ/उ, ayadi). Native words such as
+    // -याउनु causatives look the same on the surface, so these are kept only
+    // when both parts are tatsam.
+    let mut sanskrit_only = Vec::new();
     let lex = kosha();
 
     // Iterate over all internal character boundaries
     for (i, _) in word.char_indices().skip(1) {
         let (raw_left, raw_right) = word.split_at(i);
+        let right_from_matra = raw_right.chars().next().is_some_and(is_matra);
 
         // Strategy 0: Direct upasarga + stem concatenation (no sandhi mutation)
         // e.g., प्र + गति → प्रगति, वि + देश → विदेश
@@ -62,8 +87,10 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 raw_right.to_string(),
                 SandhiResult {
                     output: word.to_string(),
-                    sandhi_type: crate::SandhiType::ConsonantSandhi,
+                    sandhi_type: SandhiType::ConsonantSandhi,
                     rule_citation: "उपसर्ग संयोग: direct prefix-stem concatenation",
+                    rule: Rule::Vyakaran("sandhi-upasarga"),
+                    confidence: 1.0,
                 },
             ));
         }
@@ -112,7 +139,8 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
         }
 
         // Strategy 3: Yan Sandhi Reconstruction (इ/ई -> य, उ/ऊ -> व)
-        // If left ends in ्य, try replacing with ि/ी and prepending vowel to right.
+        // If left ends in ्य, try replacing with ि/ी and restoring the right's
+        // initial vowel (रव्य|ालय → रवि + आलय, अत्य|धिक → अति + अधिक).
         if let Some(base) = raw_left.strip_suffix("्य") {
             let left_candidates = [format!("{}ि", base), format!("{}ी", base)];
 
@@ -120,14 +148,16 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 if !lex.contains(&left) {
                     continue;
                 }
-
-                // Try prepending vowels to right
-                for v in vowels {
-                    let right = format!("{v}{raw_right}");
+                let target = if right_from_matra {
+                    &mut sanskrit_only
+                } else {
+                    &mut results
+                };
+                for right in vowel_initial_candidates(raw_right, &vowels) {
                     if lex.contains(&right) {
                         if let Ok(res) = apply(&left, &right) {
                             if res.output == word {
-                                results.push((left.clone(), right, res));
+                                target.push((left.clone(), right, res));
                             }
                         }
                     }
@@ -135,7 +165,8 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
             }
         }
 
-        // If left ends in ्व, try replacing with ु/ू and prepending vowel to right.
+        // If left ends in ्व, try replacing with ु/ू and restoring the right's
+        // initial vowel.
         if let Some(base) = raw_left.strip_suffix("्व") {
             let left_candidates = [format!("{}ु", base), format!("{}ू", base)];
 
@@ -143,13 +174,16 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 if !lex.contains(&left) {
                     continue;
                 }
-
-                for v in vowels {
-                    let right = format!("{v}{raw_right}");
+                let target = if right_from_matra {
+                    &mut sanskrit_only
+                } else {
+                    &mut results
+                };
+                for right in vowel_initial_candidates(raw_right, &vowels) {
                     if lex.contains(&right) {
                         if let Ok(res) = apply(&left, &right) {
                             if res.output == word {
-                                results.push((left.clone(), right, res));
+                                target.push((left.clone(), right, res));
                             }
                         }
                     }
@@ -228,6 +262,8 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
         // ए+vowel→अय, ऐ+vowel→आय, ओ+vowel→अव, औ+vowel→आव
         // Reverse: if raw_left ends in य, try ए/े; if ends in ाय, try ऐ/ै;
         //          if raw_left ends in व, try ओ/ो; if ends in ाव, try औ/ौ.
+        // Ayadi only joins Sanskrit stems; native words ending in -य/-व
+        // (करवट, भयभीत) would otherwise read as ए/ओ-final stems.
 
         // ऐ→आय: raw_left ends in ाय (longer pattern, check first)
         if let Some(base) = raw_left.strip_suffix("ाय") {
@@ -236,12 +272,11 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 if !lex.contains(&left) {
                     continue;
                 }
-                for v in vowels {
-                    let right = format!("{v}{raw_right}");
+                for right in vowel_initial_candidates(raw_right, &vowels) {
                     if lex.contains(&right) {
                         if let Ok(res) = apply(&left, &right) {
                             if res.output == word {
-                                results.push((left.clone(), right, res));
+                                sanskrit_only.push((left.clone(), right, res));
                             }
                         }
                     }
@@ -255,12 +290,11 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 if !lex.contains(&left) {
                     continue;
                 }
-                for v in vowels {
-                    let right = format!("{v}{raw_right}");
+                for right in vowel_initial_candidates(raw_right, &vowels) {
                     if lex.contains(&right) {
                         if let Ok(res) = apply(&left, &right) {
                             if res.output == word {
-                                results.push((left.clone(), right, res));
+                                sanskrit_only.push((left.clone(), right, res));
                             }
                         }
                     }
@@ -275,12 +309,11 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 if !lex.contains(&left) {
                     continue;
                 }
-                for v in vowels {
-                    let right = format!("{v}{raw_right}");
+                for right in vowel_initial_candidates(raw_right, &vowels) {
                     if lex.contains(&right) {
                         if let Ok(res) = apply(&left, &right) {
                             if res.output == word {
-                                results.push((left.clone(), right, res));
+                                sanskrit_only.push((left.clone(), right, res));
                             }
                         }
                     }
@@ -294,12 +327,11 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 if !lex.contains(&left) {
                     continue;
                 }
-                for v in vowels {
-                    let right = format!("{v}{raw_right}");
+                for right in vowel_initial_candidates(raw_right, &vowels) {
                     if lex.contains(&right) {
                         if let Ok(res) = apply(&left, &right) {
                             if res.output == word {
-                                results.push((left.clone(), right, res));
+                                sanskrit_only.push((left.clone(), right, res));
                             }
                         }
                     }
@@ -327,6 +359,32 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
                 _ => None,
             };
 
+            // दीर्घ इ/उ: रव|ीन्द्र → रवि + इन्द्र, गुर|ूपदेश → गुरु + उपदेश
+            let long_vowel: Option<(&[&str], &[&str])> = match first_char {
+                'ी' => Some((&["ि", "ी"], &["इ", "ई"])),
+                'ू' => Some((&["ु", "ू"], &["उ", "ऊ"])),
+                _ => None,
+            };
+            if let Some((left_matras, right_vowels)) = long_vowel {
+                let remainder = right_chars.as_str();
+                for matra in left_matras {
+                    let left = format!("{raw_left}{matra}");
+                    if !lex.contains(&left) {
+                        continue;
+                    }
+                    for v in right_vowels {
+                        let candidate_right = format!("{v}{remainder}");
+                        if lex.contains(&candidate_right) {
+                            if let Ok(res) = apply(&left, &candidate_right) {
+                                if res.output == word {
+                                    sanskrit_only.push((left.clone(), candidate_right, res));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             if let Some(vowels) = candidate_vowels {
                 let remainder = right_chars.as_str();
 
@@ -364,6 +422,11 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
         }
     }
 
+    results.extend(sanskrit_only.into_iter().filter(|(left, right, _)| {
+        lex.origin_of(left) == Some(OriginTag::Tatsam)
+            && lex.origin_of(right) == Some(OriginTag::Tatsam)
+    }));
+
     // Filter out degenerate splits where either part has fewer than 2 aksharas.
     // e.g. "रा + आम → राम" is technically valid दीर्घ sandhi but linguistically
     // meaningless — "राम" is a single morpheme, not a compound.
@@ -390,9 +453,68 @@ pub fn split(word: &str) -> Vec<(String, String, SandhiResult)> {
     results.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
     results.dedup_by(|a, b| a.0 == b.0 && a.1 == b.1);
 
+    // Rank: most confident split first, ties by (left, right) for stable output.
+    for (left, right, res) in &mut results {
+        res.confidence = split_confidence(left, right, res.sandhi_type);
+    }
+    results.sort_by(|a, b| {
+        b.2.confidence
+            .total_cmp(&a.2.confidence)
+            .then_with(|| (&a.0, &a.1).cmp(&(&b.0, &b.1)))
+    });
+
     results
 }
 
+/// Pre-sandhi forms of a right segment whose initial vowel was absorbed.
+///
+/// A leading matra is the right's own initial vowel (ालय → आलय); otherwise
+/// the vowel was अ or left no trace, so every vowel is tried (धिक → अधिक).
+fn vowel_initial_candidates(raw_right: &str, vowels: &[&str]) -> Vec<String> {
+    let mut chars = raw_right.chars();
+    if let Some(svar) = chars.next().and_then(matra_to_svar) {
+        return vec![format!("{svar}{}", chars.as_str())];
+    }
+    vowels.iter().map(|v| format!("{v}{raw_right}")).collect()
+}
+
+/// Heuristic confidence for a split `left + right`.
+///
+/// - sandhi specificity: a vowel or visarga mutation is stronger evidence of a
+///   real boundary than a consonant join or plain concatenation
+/// - attestation: parts that are kosha headwords (not just inflected forms)
+/// - balance: both parts have at least two aksharas
+/// - tatsam agreement: Sanskrit sandhi between two tatsam parts
+fn split_confidence(left: &str, right: &str, sandhi_type: SandhiType) -> f32 {
+    let lex = kosha();
+    let mut score: f32 = match sandhi_type {
+        SandhiType::VowelSandhi | SandhiType::VisargaSandhi => 0.5,
+        SandhiType::ConsonantSandhi => 0.45,
+        SandhiType::NoChange => 0.3,
+    };
+    for part in [left, right] {
+        score += if lex.lookup(part).is_some() {
+            0.15
+        } else {
+            0.05
+        };
+    }
+    if split_aksharas(left).len() >= 2 && split_aksharas(right).len() >= 2 {
+        score += 0.1;
+    }
+    let sanskrit_sandhi = matches!(
+        sandhi_type,
+        SandhiType::VowelSandhi | SandhiType::VisargaSandhi
+    );
+    if sanskrit_sandhi
+        && lex.origin_of(left) == Some(OriginTag::Tatsam)
+        && lex.origin_of(right) == Some(OriginTag::Tatsam)
+    {
+        score += 0.1;
+    }
+    score.min(1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-use crate::{SandhiResult, SandhiType};
+use crate::{Rule, SandhiResult, SandhiType};
 use varnavinyas_akshar::{is_svar, svar_to_matra};
 
 /// Apply visarga sandhi at the boundary of two morphemes.
@@ -25,6 +25,8 @@ pub fn apply_visarga_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
             output: result,
             sandhi_type: SandhiType::VisargaSandhi,
             rule_citation: "विसर्ग सन्धि: ः → श् before palatal (च/छ)",
+            rule: Rule::Vyakaran("sandhi-visarga"),
+            confidence: 1.0,
         });
     }
     if matches!(first_of_second, 'ट' | 'ठ') {
@@ -33,6 +35,8 @@ pub fn apply_visarga_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
             output: result,
             sandhi_type: SandhiType::VisargaSandhi,
             rule_citation: "विसर्ग सन्धि: ः → ष् before retroflex (ट/ठ)",
+            rule: Rule::Vyakaran("sandhi-visarga"),
+            confidence: 1.0,
         });
     }
     if matches!(first_of_second, 'त' | 'थ') {
@@ -41,16 +45,34 @@ pub fn apply_visarga_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
             output: result,
             sandhi_type: SandhiType::VisargaSandhi,
             rule_citation: "विसर्ग सन्धि: ः → स् before dental (त/थ)",
+            rule: Rule::Vyakaran("sandhi-visarga"),
+            confidence: 1.0,
         });
     }
 
-    // Visarga retained before sibilants (स, श, ष) and guttural/labial stops
-    if matches!(first_of_second, 'स' | 'श' | 'ष' | 'क' | 'ख' | 'प' | 'फ') {
+    // Visarga retained before sibilants (स, श, ष)
+    if matches!(first_of_second, 'स' | 'श' | 'ष') {
         let result = format!("{first}{second}");
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VisargaSandhi,
-            rule_citation: "विसर्ग सन्धि: विसर्ग retained before स/श/ष/guttural/labial stops",
+            rule_citation: "विसर्ग सन्धि: विसर्ग retained before स/श/ष",
+            rule: Rule::Vyakaran("sandhi-visarga"),
+            confidence: 1.0,
+        });
+    }
+
+    // Visarga retained before unvoiced guttural/labial stops (क/ख, प/फ).
+    // Sanskrit optionally writes जिह्वामूलीय/उपध्मानीय here; Nepali keeps ः
+    // (e.g., अन्तः + करण → अन्तःकरण, पुनः + प्राप्ति → पुनःप्राप्ति).
+    if matches!(first_of_second, 'क' | 'ख' | 'प' | 'फ') {
+        let result = format!("{first}{second}");
+        return Some(SandhiResult {
+            output: result,
+            sandhi_type: SandhiType::VisargaSandhi,
+            rule_citation: "विसर्ग सन्धि: विसर्ग retained before क/ख/प/फ",
+            rule: Rule::Vyakaran("sandhi-visarga"),
+            confidence: 1.0,
         });
     }
 
@@ -83,6 +105,8 @@ pub fn apply_visarga_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
             output: result,
             sandhi_type: SandhiType::VisargaSandhi,
             rule_citation: "विसर्ग सन्धि: विसर्ग → र before vowel",
+            rule: Rule::Vyakaran("sandhi-visarga"),
+            confidence: 1.0,
         });
     }
 
@@ -109,21 +133,56 @@ pub fn apply_visarga_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
                 output: result,
                 sandhi_type: SandhiType::VisargaSandhi,
                 rule_citation: "विसर्ग सन्धि: अः + घोष वर्ण → ओ",
+                rule: Rule::Vyakaran("sandhi-visarga"),
+                confidence: 1.0,
             });
         }
 
-        // Default case (preceded by i/u OR specific words like Punah): Visarga → र
-        let result = format!("{prefix}र{second}");
+        // Before र an इ/उ-visarga drops and lengthens the vowel instead of
+        // doubling र: निः + रस → नीरस, दुः + राज → दूराज.
+        if first_of_second == 'र' && !is_punah_antah {
+            if let Some(long) = lengthened(prefix) {
+                return Some(SandhiResult {
+                    output: format!("{long}{second}"),
+                    sandhi_type: SandhiType::VisargaSandhi,
+                    rule_citation: "विसर्ग सन्धि: इः/उः + र → ई/ऊ + र",
+                    rule: Rule::Vyakaran("sandhi-visarga"),
+                    confidence: 1.0,
+                });
+            }
+        }
+
+        // Default case (preceded by i/u OR specific words like Punah): Visarga
+        // → र्, joined to the following consonant (निः + धन → निर्धन).
+        let result = format!("{prefix}र्{second}");
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VisargaSandhi,
             rule_citation: "विसर्ग सन्धि: विसर्ग → र before voiced consonant",
+            rule: Rule::Vyakaran("sandhi-visarga"),
+            confidence: 1.0,
         });
     }
 
     None
 }
 
+/// `prefix` with a final इ/उ (matra or svar) lengthened to ई/ऊ.
+fn lengthened(prefix: &str) -> Option<String> {
+    let last = prefix.chars().next_back()?;
+    let long = match last {
+        'ि' => 'ी',
+        'ु' => 'ू',
+        'इ' => 'ई',
+        'उ' => 'ऊ',
+        _ => return None,
+    };
+    Some(format!(
+        "{}{long}",
+        &prefix[..prefix.len() - last.len_utf8()]
+    ))
+}
+
 fn is_voiced_consonant(c: char) -> bool {
     matches!(
         c,
@@ -1,5 +1,5 @@
-use crate::{SandhiResult, SandhiType};
-use varnavinyas_akshar::{is_matra, is_svar, is_vyanjan};
+use crate::{Rule, SandhiResult, SandhiType};
+use varnavinyas_akshar::{guna_of, is_matra, is_svar, is_vyanjan, svar_to_matra, vriddhi_of};
 
 /// Apply vowel sandhi at the boundary of two morphemes.
 ///
@@ -41,6 +41,8 @@ pub fn apply_vowel_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "दीर्घ सन्धि: इ/ई + इ/ई → ई",
+            rule: Rule::Vyakaran("sandhi-dirgha"),
+            confidence: 1.0,
         });
     }
 
@@ -58,40 +60,40 @@ pub fn apply_vowel_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "दीर्घ सन्धि: उ/ऊ + उ/ऊ → ऊ",
+            rule: Rule::Vyakaran("sandhi-dirgha"),
+            confidence: 1.0,
         });
     }
 
     // यण् sandhi: इ/ई + vowel → य + (vowel as matra, or consumed if अ)
+    // अति + अधिक → अत्यधिक, प्रति + एक → प्रत्येक
     if matches!(last, 'ि' | 'ी' | 'इ' | 'ई') && is_vowel_start(first_of_second) {
         let prefix: String = first_chars[..first_chars.len() - 1].iter().collect();
-        let second_remainder: String = if first_of_second == 'अ' {
-            second_chars[1..].iter().collect()
-        } else {
-            second.to_string()
-        };
+        let second_remainder = vowel_as_matra(&second_chars);
         let ya_form = if is_matra(last) { "्य" } else { "य" };
         let result = format!("{prefix}{ya_form}{second_remainder}");
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "यण् सन्धि: इ/ई + स्वर → य",
+            rule: Rule::Vyakaran("sandhi-yan"),
+            confidence: 1.0,
         });
     }
 
     // यण् sandhi: उ/ऊ + vowel → व + vowel
+    // सु + आगत → स्वागत, मधु + अरि → मध्वरि
     if matches!(last, 'ु' | 'ू' | 'उ' | 'ऊ') && is_vowel_start(first_of_second) {
         let prefix: String = first_chars[..first_chars.len() - 1].iter().collect();
-        let second_remainder: String = if first_of_second == 'अ' {
-            second_chars[1..].iter().collect()
-        } else {
-            second.to_string()
-        };
+        let second_remainder = vowel_as_matra(&second_chars);
         let va_form = if is_matra(last) { "्व" } else { "व" };
         let result = format!("{prefix}{va_form}{second_remainder}");
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "यण् सन्धि: उ/ऊ + स्वर → व",
+            rule: Rule::Vyakaran("sandhi-yan"),
+            confidence: 1.0,
         });
     }
 
@@ -106,95 +108,104 @@ pub fn apply_vowel_sandhi(first: &str, second: &str) -> Option<SandhiResult> {
             output: emit_a_sandhi(first, &first_chars, inherent, &rest, "आ", "ा"),
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "दीर्घ सन्धि: अ/आ + अ/आ → आ",
+            rule: Rule::Vyakaran("sandhi-dirgha"),
+            confidence: 1.0,
         });
     }
 
-    // गुण सन्धि: अ/आ + इ/ई → ए
-    if matches!(last, 'अ' | 'आ' | 'ा') && matches!(first_of_second, 'इ' | 'ई') {
-        return Some(SandhiResult {
-            output: emit_a_sandhi(first, &first_chars, inherent, &rest, "ए", "े"),
-            sandhi_type: SandhiType::VowelSandhi,
-            rule_citation: "गुण सन्धि: अ/आ + इ/ई → ए",
-        });
-    }
-
-    // गुण सन्धि: अ/आ + उ/ऊ → ओ
-    if matches!(last, 'अ' | 'आ' | 'ा') && matches!(first_of_second, 'उ' | 'ऊ') {
-        return Some(SandhiResult {
-            output: emit_a_sandhi(first, &first_chars, inherent, &rest, "ओ", "ो"),
-            sandhi_type: SandhiType::VowelSandhi,
-            rule_citation: "गुण सन्धि: अ/आ + उ/ऊ → ओ",
-        });
-    }
-
-    // गुण सन्धि: अ/आ + ऋ → अर्
-    if matches!(last, 'अ' | 'आ' | 'ा') && first_of_second == 'ऋ' {
-        return Some(SandhiResult {
-            output: emit_a_sandhi(first, &first_chars, inherent, &rest, "अर्", "र्"),
-            sandhi_type: SandhiType::VowelSandhi,
-            rule_citation: "गुण सन्धि: अ/आ + ऋ → अर्",
-        });
-    }
-
-    // वृद्धि सन्धि: अ/आ + ए/ऐ → ऐ
-    if matches!(last, 'अ' | 'आ' | 'ा') && matches!(first_of_second, 'ए' | 'ऐ') {
-        return Some(SandhiResult {
-            output: emit_a_sandhi(first, &first_chars, inherent, &rest, "ऐ", "ै"),
-            sandhi_type: SandhiType::VowelSandhi,
-            rule_citation: "वृद्धि सन्धि: अ/आ + ए/ऐ → ऐ",
-        });
-    }
+    if matches!(last, 'अ' | 'आ' | 'ा') {
+        // गुण सन्धि: अ/आ + इ/ई → ए, उ/ऊ → ओ, ऋ → अर्, ऌ → अल्
+        if let Some(grade) = guna_of(first_of_second) {
+            return Some(SandhiResult {
+                output: emit_a_sandhi(
+                    first,
+                    &first_chars,
+                    inherent,
+                    &rest,
+                    grade,
+                    &matra_form(grade),
+                ),
+                sandhi_type: SandhiType::VowelSandhi,
+                rule_citation: guna_citation(grade),
+                rule: Rule::Vyakaran("sandhi-guna"),
+                confidence: 1.0,
+            });
+        }
 
-    // वृद्धि सन्धि: अ/आ + ओ/औ → औ
-    if matches!(last, 'अ' | 'आ' | 'ा') && matches!(first_of_second, 'ओ' | 'औ') {
-        return Some(SandhiResult {
-            output: emit_a_sandhi(first, &first_chars, inherent, &rest, "औ", "ौ"),
-            sandhi_type: SandhiType::VowelSandhi,
-            rule_citation: "वृद्धि सन्धि: अ/आ + ओ/औ → औ",
-        });
+        // वृद्धि सन्धि: अ/आ + ए/ऐ → ऐ, ओ/औ → औ
+        if matches!(first_of_second, 'ए' | 'ऐ' | 'ओ' | 'औ') {
+            let grade = vriddhi_of(first_of_second)?;
+            let rule_citation = if grade == "ऐ" {
+                "वृद्धि सन्धि: अ/आ + ए/ऐ → ऐ"
+            } else {
+                "वृद्धि सन्धि: अ/आ + ओ/औ → औ"
+            };
+            return Some(SandhiResult {
+                output: emit_a_sandhi(
+                    first,
+                    &first_chars,
+                    inherent,
+                    &rest,
+                    grade,
+                    &matra_form(grade),
+                ),
+                sandhi_type: SandhiType::VowelSandhi,
+                rule_citation,
+                rule: Rule::Vyakaran("sandhi-vriddhi"),
+                confidence: 1.0,
+            });
+        }
     }
 
-    // अयादि सन्धि: ए/े + vowel → अय + vowel
+    // अयादि सन्धि: ए/े + vowel → अय + vowel (ने + अन → नयन)
     if matches!(last, 'ए' | 'े') && is_vowel_start(first_of_second) {
         let prefix: String = first_chars[..first_chars.len() - 1].iter().collect();
-        let result = format!("{prefix}य{second}");
+        let result = format!("{prefix}य{}", vowel_as_matra(&second_chars));
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "अयादि सन्धि: ए + स्वर → अय्",
+            rule: Rule::Vyakaran("sandhi-ayadi"),
+            confidence: 1.0,
         });
     }
 
     // अयादि सन्धि: ऐ/ै + vowel → आय + vowel
     if matches!(last, 'ऐ' | 'ै') && is_vowel_start(first_of_second) {
         let prefix: String = first_chars[..first_chars.len() - 1].iter().collect();
-        let result = format!("{prefix}ाय{second}");
+        let result = format!("{prefix}ाय{}", vowel_as_matra(&second_chars));
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "अयादि सन्धि: ऐ + स्वर → आय्",
+            rule: Rule::Vyakaran("sandhi-ayadi"),
+            confidence: 1.0,
         });
     }
 
     // अयादि सन्धि: ओ/ो + vowel → अव + vowel
     if matches!(last, 'ओ' | 'ो') && is_vowel_start(first_of_second) {
         let prefix: String = first_chars[..first_chars.len() - 1].iter().collect();
-        let result = format!("{prefix}व{second}");
+        let result = format!("{prefix}व{}", vowel_as_matra(&second_chars));
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "अयादि सन्धि: ओ + स्वर → अव्",
+            rule: Rule::Vyakaran("sandhi-ayadi"),
+            confidence: 1.0,
         });
     }
 
     // अयादि सन्धि: औ/ौ + vowel → आव + vowel
     if matches!(last, 'औ' | 'ौ') && is_vowel_start(first_of_second) {
         let prefix: String = first_chars[..first_chars.len() - 1].iter().collect();
-        let result = format!("{prefix}ाव{second}");
+        let result = format!("{prefix}ाव{}", vowel_as_matra(&second_chars));
         return Some(SandhiResult {
             output: result,
             sandhi_type: SandhiType::VowelSandhi,
             rule_citation: "अयादि सन्धि: औ + स्वर → आव्",
+            rule: Rule::Vyakaran("sandhi-ayadi"),
+            confidence: 1.0,
         });
     }
 
@@ -235,6 +246,35 @@ fn emit_a_sandhi(
     }
 }
 
+/// Rewrite a vowel-initial morpheme to follow a consonant: the inherent अ is
+/// absorbed and any other initial svar becomes its matra (आगत → ागत).
+fn vowel_as_matra(second_chars: &[char]) -> String {
+    let rest = second_chars[1..].iter();
+    match second_chars[0] {
+        'अ' => rest.collect(),
+        c => svar_to_matra(c).into_iter().chain(rest.copied()).collect(),
+    }
+}
+
+/// Form of a गुण/वृद्धि grade written after a consonant: ए→े, अर्→र्, आर्→ार्.
+fn matra_form(grade: &str) -> String {
+    let mut chars = grade.chars();
+    match chars.next() {
+        Some('अ') => chars.collect(),
+        Some(c) => svar_to_matra(c).into_iter().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn guna_citation(grade: &str) -> &'static str {
+    match grade {
+        "ए" => "गुण सन्धि: अ/आ + इ/ई → ए",
+        "ओ" => "गुण सन्धि: अ/आ + उ/ऊ → ओ",
+        "अर्" => "गुण सन्धि: अ/आ + ऋ → अर्",
+        _ => "गुण सन्धि: अ/आ + ऌ → अल्",
+    }
+}
+
 fn is_vowel_start(c: char) -> bool {
     is_svar(c)
 }
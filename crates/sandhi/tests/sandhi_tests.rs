@@ -1,4 +1,7 @@
-use varnavinyas_sandhi::{SandhiType, apply, split};
+use varnavinyas_sandhi::{
+    MAX_SPLIT_AKSHARAS, Rule, SandhiError, SandhiType, apply, apply_or_concat, apply_vowel_sandhi,
+    split, split_with_limit,
+};
 
 // D1: Vowel sandhi: apply
 #[test]
@@ -8,6 +11,25 @@ fn d1_vowel_sandhi_yan() {
     assert_eq!(result.sandhi_type, SandhiType::VowelSandhi);
 }
 
+// यण् सन्धि: the following vowel is written as a matra on य/व
+#[test]
+fn yan_sandhi_forward() {
+    let cases = [
+        ("अति", "अधिक", "अत्यधिक", "यण् सन्धि: इ/ई + स्वर → य"),
+        ("प्रति", "एक", "प्रत्येक", "यण् सन्धि: इ/ई + स्वर → य"),
+        ("इति", "आदि", "इत्यादि", "यण् सन्धि: इ/ई + स्वर → य"),
+        ("सु", "आगत", "स्वागत", "यण् सन्धि: उ/ऊ + स्वर → व"),
+        ("मधु", "अरि", "मध्वरि", "यण् सन्धि: उ/ऊ + स्वर → व"),
+        ("अनु", "एषण", "अन्वेषण", "यण् सन्धि: उ/ऊ + स्वर → व"),
+    ];
+    for (first, second, expected, citation) in cases {
+        let result = apply(first, second).unwrap();
+        assert_eq!(result.output, expected, "{first} + {second}");
+        assert_eq!(result.sandhi_type, SandhiType::VowelSandhi);
+        assert_eq!(result.rule_citation, citation);
+    }
+}
+
 // D2: Visarga sandhi: apply (visarga → र before vowel)
 #[test]
 fn d2_visarga_sandhi_to_ra() {
@@ -16,6 +38,21 @@ fn d2_visarga_sandhi_to_ra() {
     assert_eq!(result.sandhi_type, SandhiType::VisargaSandhi);
 }
 
+// Visarga before a voiced consonant is half र्; before र the vowel lengthens
+#[test]
+fn visarga_before_voiced_consonant_and_ra() {
+    assert_eq!(apply("निः", "धन").unwrap().output, "निर्धन");
+    assert_eq!(apply("दुः", "बल").unwrap().output, "दुर्बल");
+    assert_eq!(apply("निः", "रस").unwrap().output, "नीरस");
+}
+
+// अयादि सन्धि: the following vowel is written as a matra on य/व
+#[test]
+fn ayadi_sandhi_forward() {
+    assert_eq!(apply("ने", "अन").unwrap().output, "नयन");
+    assert_eq!(apply("पो", "इत्र").unwrap().output, "पवित्र");
+}
+
 // D3: Visarga retained before sibilant
 #[test]
 fn d3_visarga_retained() {
@@ -24,6 +61,50 @@ fn d3_visarga_retained() {
     assert_eq!(result.sandhi_type, SandhiType::VisargaSandhi);
 }
 
+// Visarga retained before unvoiced guttural/labial stops (no जिह्वामूलीय/उपध्मानीय)
+#[test]
+fn visarga_retained_before_ka_kha_pa_pha() {
+    for (first, second, expected) in [
+        ("अन्तः", "करण", "अन्तःकरण"),
+        ("दुः", "ख", "दुःख"),
+        ("पुनः", "प्राप्ति", "पुनःप्राप्ति"),
+        ("मनः", "फल", "मनःफल"),
+    ] {
+        let result = apply(first, second).unwrap();
+        assert_eq!(result.output, expected, "{first} + {second}");
+        assert_eq!(result.sandhi_type, SandhiType::VisargaSandhi);
+        assert!(
+            result.rule_citation.contains("क/ख/प/फ"),
+            "{first} + {second}"
+        );
+    }
+}
+
+// Unlike क/ख/प/फ, the other unvoiced stops assimilate visarga to a sibilant
+#[test]
+fn visarga_assimilates_before_palatal_retroflex_dental_stops() {
+    for (first, second, expected) in [
+        ("निः", "चय", "निश्चय"),
+        ("धनुः", "टङ्कार", "धनुष्टङ्कार"),
+        ("नमः", "ते", "नमस्ते"),
+    ] {
+        let result = apply(first, second).unwrap();
+        assert_eq!(result.output, expected, "{first} + {second}");
+        assert!(!result.output.contains('ः'), "{first} + {second}");
+    }
+}
+
+#[test]
+fn visarga_retained_split_roundtrip() {
+    let results = split("अन्तःकरण");
+    assert!(
+        results
+            .iter()
+            .any(|(first, second, _)| first == "अन्तः" && second == "करण"),
+        "Expected to find split (अन्तः, करण) in results: {results:?}"
+    );
+}
+
 // D4: Consonant assimilation
 #[test]
 fn d4_consonant_assimilation() {
@@ -56,6 +137,32 @@ fn d6_split_visarga_sandhi() {
     );
 }
 
+// Split ranking: preferred reconstruction comes first
+#[test]
+fn split_ranks_preferred_reconstruction_first() {
+    for (word, first, second) in [("हिमालय", "हिम", "आलय"), ("महेन्द्र", "महा", "इन्द्र")]
+    {
+        let results = split(word);
+        assert!(
+            results.len() > 1,
+            "expected several splits for {word}: {results:?}"
+        );
+        let (l, r, top) = &results[0];
+        assert_eq!((l.as_str(), r.as_str()), (first, second), "{results:?}");
+        assert!(
+            results
+                .iter()
+                .all(|(_, _, res)| res.confidence <= top.confidence),
+            "split output not sorted by confidence: {results:?}"
+        );
+    }
+}
+
+#[test]
+fn apply_reports_full_confidence() {
+    assert_eq!(apply("हिम", "आलय").unwrap().confidence, 1.0);
+}
+
 // Additional sandhi tests
 #[test]
 fn visarga_before_sa() {
@@ -129,3 +236,279 @@ fn consonant_dus_charitr() {
     assert_eq!(result.output, "दुश्चरित्र");
     assert_eq!(result.sandhi_type, SandhiType::ConsonantSandhi);
 }
+
+// गुण सन्धि: अ/आ × इ/ई/उ/ऊ/ऋ, with explicit and inherent अ
+#[test]
+fn guna_sandhi_matrix() {
+    let cases = [
+        ("देव", "इन्द्र", "देवेन्द्र", "गुण सन्धि: अ/आ + इ/ई → ए"),
+        ("महा", "इन्द्र", "महेन्द्र", "गुण सन्धि: अ/आ + इ/ई → ए"),
+        ("गण", "ईश", "गणेश", "गुण सन्धि: अ/आ + इ/ई → ए"),
+        ("महा", "ईश", "महेश", "गुण सन्धि: अ/आ + इ/ई → ए"),
+        ("सूर्य", "उदय", "सूर्योदय", "गुण सन्धि: अ/आ + उ/ऊ → ओ"),
+        ("महा", "उत्सव", "महोत्सव", "गुण सन्धि: अ/आ + उ/ऊ → ओ"),
+        ("जल", "ऊर्मि", "जलोर्मि", "गुण सन्धि: अ/आ + उ/ऊ → ओ"),
+        ("गङ्गा", "ऊर्मि", "गङ्गोर्मि", "गुण सन्धि: अ/आ + उ/ऊ → ओ"),
+        ("देव", "ऋषि", "देवर्षि", "गुण सन्धि: अ/आ + ऋ → अर्"),
+        ("महा", "ऋषि", "महर्षि", "गुण सन्धि: अ/आ + ऋ → अर्"),
+    ];
+    for (first, second, expected, citation) in cases {
+        let result = apply_vowel_sandhi(first, second)
+            .unwrap_or_else(|| panic!("no sandhi for {first} + {second}"));
+        assert_eq!(result.output, expected, "{first} + {second}");
+        assert_eq!(result.rule_citation, citation, "{first} + {second}");
+        assert_eq!(result.sandhi_type, SandhiType::VowelSandhi);
+    }
+}
+
+// वृद्धि सन्धि outputs are unchanged by the grade-based refactor
+#[test]
+fn vriddhi_sandhi_pairs() {
+    let cases = [
+        ("एक", "एक", "एकैक"),
+        ("सदा", "एव", "सदैव"),
+        ("वन", "ओषधि", "वनौषधि"),
+        ("महा", "औषध", "महौषध"),
+    ];
+    for (first, second, expected) in cases {
+        let result = apply_vowel_sandhi(first, second).unwrap();
+        assert_eq!(result.output, expected, "{first} + {second}");
+    }
+}
+
+// Plain concatenation is an error for `apply` but a result for `apply_or_concat`
+#[test]
+fn apply_or_concat_joins_without_rule() {
+    assert!(matches!(
+        apply("एक", "चक्र"),
+        Err(SandhiError::NoRuleApplies { .. })
+    ));
+
+    let result = apply_or_concat("एक", "चक्र").unwrap();
+    assert_eq!(result.output, "एकचक्र");
+    assert_eq!(result.sandhi_type, SandhiType::NoChange);
+}
+
+#[test]
+fn apply_or_concat_prefers_real_sandhi() {
+    let result = apply_or_concat("अति", "अधिक").unwrap();
+    assert_eq!(result.output, "अत्यधिक");
+    assert_eq!(result.sandhi_type, SandhiType::VowelSandhi);
+
+    assert!(matches!(
+        apply_or_concat("", "चक्र"),
+        Err(SandhiError::EmptyInput)
+    ));
+}
+
+#[test]
+fn overlong_token_returns_no_splits() {
+    let word = "सूर्योदय".repeat(60);
+    assert!(varnavinyas_akshar::split_aksharas(&word).len() > MAX_SPLIT_AKSHARAS);
+    assert!(split(&word).is_empty());
+
+    // The bound is inclusive: a word exactly at the limit is still analyzed,
+    // one akshara over it is not.
+    let len = varnavinyas_akshar::split_aksharas("सूर्योदय").len();
+    assert!(!split_with_limit("सूर्योदय", len).is_empty());
+    assert!(split_with_limit("सूर्योदय", len - 1).is_empty());
+}
+
+#[test]
+fn vowel_sandhi_carries_structured_rule() {
+    let result = apply("अति", "अधिक").unwrap();
+    assert_eq!(result.rule, Rule::Vyakaran("sandhi-yan"));
+    assert_eq!(result.rule.code(), "sandhi-yan");
+
+    let result = apply("सूर्य", "उदय").unwrap();
+    assert_eq!(result.rule.code(), "sandhi-guna");
+}
+
+// Split recovers compounds whose left or right vowel was hidden by the sandhi
+#[test]
+fn split_recovers_reconstructed_compounds() {
+    let cases = [
+        ("रवीन्द्र", "रवि", "इन्द्र"),
+        ("गुरूपदेश", "गुरु", "उपदेश"),
+        ("अत्युक्ति", "अति", "उक्ति"),
+        ("प्रत्येक", "प्रति", "एक"),
+        ("अध्याहार", "अधि", "आहार"),
+        ("पुनर्जन्म", "पुनः", "जन्म"),
+        ("अन्तर्गत", "अन्तः", "गत"),
+    ];
+    for (word, left, right) in cases {
+        let results = split(word);
+        assert!(
+            results.iter().any(|(l, r, _)| l == left && r == right),
+            "Expected to find split ({left}, {right}) for {word}: {results:?}"
+        );
+    }
+}
+
+// Intentional gap: apply lengthens निः + रस to नीरस, but split does not
+// rebuild निः from नी. That reconstruction also split native words and
+// pushed the headword census past its 15% budget.
+#[test]
+fn split_does_not_undo_lengthened_r_visarga() {
+    assert_eq!(apply("निः", "रस").unwrap().output, "नीरस");
+    assert!(
+        !split("नीरस")
+            .iter()
+            .any(|(l, r, _)| l == "निः" && r == "रस")
+    );
+}
+
+mod proptest_split_inverse {
+    use proptest::prelude::*;
+    use varnavinyas_akshar::split_aksharas;
+    use varnavinyas_kosha::kosha;
+    use varnavinyas_kosha::origin_tag::OriginTag;
+    use varnavinyas_sandhi::{apply, split};
+
+    // Vowel-final tatsam stems of two or more aksharas. One-akshara
+    // upasargas (सु, नि) are left out by the akshara guard; visarga and
+    // halanta stems are in `GAP_LEFTS` below.
+    const LEFTS: &[&str] = &[
+        "विद्या",
+        "महा",
+        "देव",
+        "सूर्य",
+        "परम",
+        "हिम",
+        "रवि",
+        "कवि",
+        "अति",
+        "प्रति",
+        "इति",
+        "गुरु",
+        "मधु",
+        "अनु",
+        "सदा",
+        "नर",
+        "गण",
+        "लोक",
+        "धर्म",
+        "राजा",
+        "नदी",
+        "लक्ष्मी",
+        "पितृ",
+        "ज्ञान",
+        "सुर",
+        "पर",
+    ];
+
+    const RIGHTS: &[&str] = &[
+        "आलय",
+        "अर्थी",
+        "इन्द्र",
+        "ईश",
+        "उदय",
+        "उपदेश",
+        "ऊर्जा",
+        "एक",
+        "ऐश्वर्य",
+        "औषधि",
+        "अधिक",
+        "आगत",
+        "अक्षर",
+        "चय",
+        "गति",
+        "देश",
+        "धन",
+        "बल",
+        "मान",
+        "कार",
+        "ईश्वर",
+        "आत्मा",
+        "आचार्य",
+        "उत्तम",
+        "ऊह",
+        "उक्त",
+        "उचित",
+        "गम",
+        "योग",
+        "वाद",
+        "अवलोकन",
+        "आनन्द",
+        "अम्बर",
+        "इच्छा",
+        "उत्सव",
+        "ओषधि",
+        "जन",
+        "तन्त्र",
+        "भय",
+        "मय",
+        "शासन",
+        "सार",
+        "आदि",
+    ];
+
+    // Stems whose joins split cannot undo yet. Each gap:
+    // - ओ-visarga: मनः + बल → मनोबल; split does not rebuild मनः from मनो.
+    // - र-visarga before a vowel: अन्तः + आलय → अन्तरालय; split does not rebuild
+    //   अन्तः from अन्तर् (it does before consonants: पुनर्जन्म).
+    // - Consonant assimilation: जगत् + बल → जगद्बल, जगत् + मय → जगन्मय.
+    // - ऋ-guna: महा + ऋषि → महर्षि; split does not rebuild ऋ from अर्.
+    // These reconstructions also split ordinary words and pushed the
+    // headword census past its 15% budget, so they stay out of split.
+    const GAP_LEFTS: &[&str] = &["मनः", "पुनः", "नमः", "तपः", "अन्तः", "जगत्"];
+    const GAP_RIGHTS: &[&str] = &["ऋषि", "ऋण"];
+
+    /// Join `left` + `right` and check that split offers the pair back.
+    fn check_split_inverse(left: &str, right: &str) -> Result<(), TestCaseError> {
+        let lex = kosha();
+        prop_assume!(lex.origin_of(left) == Some(OriginTag::Tatsam));
+        prop_assume!(lex.origin_of(right) == Some(OriginTag::Tatsam));
+        let Ok(joined) = apply(left, right) else {
+            return Ok(());
+        };
+        prop_assume!(split_aksharas(&joined.output).len() >= 3);
+
+        let results = split(&joined.output);
+        prop_assert!(
+            results.iter().any(|(l, r, _)| l == left && r == right),
+            "{} + {} → {} but split gave {:?}",
+            left,
+            right,
+            joined.output,
+            results
+        );
+        Ok(())
+    }
+
+    proptest! {
+        // Joining two known tatsam morphemes and splitting the result must
+        // offer the original pair back. Other readings may rank above it:
+        // परमानन्द is also परम + अनन्द, and महौषधि is also महा + ओषधि, so
+        // the property is membership, not first place.
+        #[test]
+        fn split_contains_applied_pair(
+            left in proptest::sample::select(LEFTS),
+            right in proptest::sample::select(RIGHTS),
+        ) {
+            check_split_inverse(left, right)?;
+        }
+    }
+
+    proptest! {
+        // Known gaps fail by design, so their shrunk cases are not written
+        // to a regressions file.
+        #![proptest_config(ProptestConfig {
+            failure_persistence: None,
+            ..ProptestConfig::default()
+        })]
+
+        // The same property over the known gaps listed above. Run with
+        // `--ignored` when working on split reconstruction.
+        #[test]
+        #[ignore = "known split gaps: visarga, halanta and ऋ-initial stems"]
+        fn split_contains_applied_pair_known_gaps(
+            (left, right) in prop_oneof![
+                (proptest::sample::select(GAP_LEFTS), proptest::sample::select(RIGHTS)),
+                (proptest::sample::select(LEFTS), proptest::sample::select(GAP_RIGHTS)),
+            ],
+        ) {
+            check_split_inverse(left, right)?;
+        }
+    }
+}
@@ -1,11 +1,13 @@
 mod morphology;
 mod origin;
+mod overrides;
 pub mod tables;
 
-pub use morphology::{Morpheme, decompose};
+pub use morphology::{Morpheme, decompose, known_prefixes, strip_prefixes};
 pub use origin::{
     Origin, OriginDecision, OriginSource, classify, classify_with_provenance, source_language,
 };
+pub use overrides::OriginOverrides;
 
 /// Error type for shabda operations.
 #[derive(Debug, thiserror::Error)]
@@ -15,4 +17,7 @@ pub enum ShabdaError {
 
     #[error("unknown word: {0}")]
     UnknownWord(String),
+
+    #[error("invalid origin overrides: {0}")]
+    InvalidOverrides(String),
 }
@@ -1,5 +1,6 @@
 use crate::origin::{Origin, classify};
 use crate::tables;
+use std::sync::LazyLock;
 use varnavinyas_kosha::kosha;
 
 /// Morphological decomposition of a word.
@@ -15,6 +16,93 @@ pub struct Morpheme {
     pub origin: Origin,
 }
 
+impl Morpheme {
+    /// Rebuild the surface form: prefixes + root + suffixes.
+    ///
+    /// Prefixes are joined with sandhi (पुनः + अवलोकन → पुनरवलोकन), innermost
+    /// first; suffixes are appended as stored. The inverse of [`decompose`],
+    /// so `decompose(w).surface() == w` is a quick check on a decomposition.
+    pub fn surface(&self) -> String {
+        let mut stem = self.root.clone();
+        for prefix in self.prefixes.iter().rev() {
+            stem = match varnavinyas_sandhi::apply_or_concat(prefix, &stem) {
+                Ok(joined) => joined.output,
+                Err(_) => format!("{prefix}{stem}"),
+            };
+        }
+        for suffix in &self.suffixes {
+            stem.push_str(suffix);
+        }
+        stem
+    }
+}
+
+/// The prefix inventory shared across crates: Sanskrit उपसर्ग and Nepali
+/// negatives, in canonical form (सम्, not सं/सङ्).
+///
+/// Built from the canonical column of [`tables::PREFIX_FORMS`] followed by
+/// [`tables::NEGATIVE_PREFIXES`], so it always matches what [`strip_prefixes`]
+/// can actually strip.
+pub fn known_prefixes() -> &'static [&'static str] {
+    static KNOWN: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+        let mut prefixes: Vec<&'static str> = Vec::new();
+        let canonical = tables::PREFIX_FORMS.iter().map(|&(prefix, _, _)| prefix);
+        for prefix in canonical.chain(tables::NEGATIVE_PREFIXES.iter().copied()) {
+            if !prefixes.contains(&prefix) {
+                prefixes.push(prefix);
+            }
+        }
+        prefixes
+    });
+    &KNOWN
+}
+
+/// Strip productive prefixes from the front of `word`.
+///
+/// Prefixes are removed outermost first for as long as the remainder is a
+/// dictionary word, so `प्रशासन` gives `(["प्र"], "शासन")`. Sandhi-ed forms are
+/// reported canonically (`संकल्प` → `सम्`). A word with no recognised prefix
+/// comes back unchanged with an empty list.
+pub fn strip_prefixes(word: &str) -> (Vec<String>, String) {
+    let mut prefixes = Vec::new();
+    let mut remaining = word;
+    while let Some((prefix, rest)) = strip_upasarga(remaining).or_else(|| strip_negative(remaining))
+    {
+        prefixes.push(prefix.to_string());
+        remaining = rest;
+    }
+    (prefixes, remaining.to_string())
+}
+
+/// Strip one upasarga (matched via its sandhi form) when the rest is a known word.
+fn strip_upasarga(word: &str) -> Option<(&'static str, &str)> {
+    let lex = kosha();
+    tables::PREFIX_FORMS
+        .iter()
+        .find_map(|&(prefix, sandhi_form, _root_prefix)| {
+            let rest = word.strip_prefix(sandhi_form)?;
+            // Short prefixes (≤1 Devanagari char, e.g., अ, आ) require longer roots
+            // to prevent over-decomposition (e.g., आगो → prefix अ + root गो).
+            let min_root = if sandhi_form.chars().count() <= 1 {
+                4
+            } else {
+                2
+            };
+            (rest.chars().count() >= min_root && lex.contains(rest)).then_some((prefix, rest))
+        })
+}
+
+/// Strip one Nepali negative prefix (बेकार → बे + कार).
+///
+/// Requires a 3+ char root so short words such as बेला or नाम stay whole.
+fn strip_negative(word: &str) -> Option<(&'static str, &str)> {
+    let lex = kosha();
+    tables::NEGATIVE_PREFIXES.iter().find_map(|&prefix| {
+        let rest = word.strip_prefix(prefix)?;
+        (rest.chars().count() >= 3 && lex.contains(rest)).then_some((prefix, rest))
+    })
+}
+
 /// Decompose a word into morphological components.
 pub fn decompose(word: &str) -> Morpheme {
     if word.is_empty() {
@@ -35,21 +123,9 @@ pub fn decompose(word: &str) -> Morpheme {
     // Strip known prefixes (including sandhi-ed forms)
     // For consonant assimilation like उत् + ल → उल्ल:
     // We strip "उल्" and the remaining starts with "ल" (the doubled consonant)
-    for &(prefix, sandhi_form, _root_prefix) in tables::PREFIX_FORMS.iter() {
-        if let Some(rest) = remaining.strip_prefix(sandhi_form) {
-            // Short prefixes (≤1 Devanagari char, e.g., अ, आ) require longer roots
-            // to prevent over-decomposition (e.g., आगो → prefix अ + root गो).
-            let min_root = if sandhi_form.chars().count() <= 1 {
-                4
-            } else {
-                2
-            };
-            if rest.chars().count() >= min_root && lex.contains(rest) {
-                prefixes.push(prefix.to_string());
-                remaining = rest.to_string();
-                break; // Only strip one prefix for now
-            }
-        }
+    if let Some((prefix, rest)) = strip_upasarga(&remaining) {
+        prefixes.push(prefix.to_string());
+        remaining = rest.to_string();
     }
 
     // Strip known suffixes.
@@ -1,3 +1,4 @@
+use crate::overrides;
 use crate::tables;
 pub use varnavinyas_types::Origin;
 
@@ -23,9 +24,11 @@ pub struct OriginDecision {
 /// नेपाली शब्दलाई उत्पत्तिका आधारमा वर्गीकृत गर्ने।
 ///
 /// त्रिस्तरीय lookup:
-/// 1. Override तालिका (dictionary/heuristic ले छुटाउन सक्ने किनाराका केस)
+/// 1. Override ([`OriginOverrides::install`] गरिएको, अनि static तालिका)
 /// 2. Kosha lookup (~26K शब्द, Brihat Shabdakosha origin tag सहित)
 /// 3. Heuristic वर्गीकरण (ध्वन्यात्मक ढाँचा)
+///
+/// [`OriginOverrides::install`]: crate::OriginOverrides::install
 pub fn classify(word: &str) -> Origin {
     classify_with_provenance(word).origin
 }
@@ -40,8 +43,10 @@ pub fn classify_with_provenance(word: &str) -> OriginDecision {
         };
     }
 
-    // 1. Override तालिका (हातैले प्रमाणीकरण गरिएका किनाराका केस)
-    if let Some(origin) = tables::lookup_origin(word) {
+    // 1. Override: install गरिएको deployment override, त्यसपछि static तालिका
+    //    (हातैले प्रमाणीकरण गरिएका किनाराका केस)
+    if let Some(origin) = overrides::installed_origin(word).or_else(|| tables::lookup_origin(word))
+    {
         return OriginDecision {
             origin,
             source: OriginSource::Override,
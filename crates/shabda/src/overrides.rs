@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+#[cfg(feature = "toml")]
+use std::path::Path;
+#[cfg(feature = "toml")]
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "toml")]
+use crate::ShabdaError;
+use crate::origin::Origin;
+
+/// `install` गरिएको deployment-स्तरको override; [`classify_with_provenance`]
+/// ले static override तालिकाभन्दा पहिले हेर्छ।
+///
+/// [`classify_with_provenance`]: crate::classify_with_provenance
+static INSTALLED: RwLock<Option<OriginOverrides>> = RwLock::new(None);
+
+/// override install भएको छ कि छैन; नभएमा हरेक classify मा lock पढ्नु पर्दैन।
+static ANY_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Runtime मा लोड गरिने शब्दउत्पत्ति override।
+///
+/// Domain-विशेष शब्दावली (जस्तै: चिकित्सा, कानुन) का लागि deployment ले
+/// आफ्नै override फाइल पठाउन सक्छ। फाइल सामान्य TOML map हो:
+///
+/// ```toml
+/// "रजिष्टर" = "Aagantuk"
+/// "मिठो" = "tadbhav"
+/// "विद्या" = "तत्सम"
+/// ```
+///
+/// मानका रूपमा enum नाम (case-insensitive) वा नेपाली नाम स्वीकार्य छन्।
+/// TOML पार्स गर्न `toml` feature चाहिन्छ।
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OriginOverrides {
+    entries: HashMap<String, Origin>,
+}
+
+impl OriginOverrides {
+    /// खाली override set।
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// TOML फाइलबाट override लोड गर्ने।
+    #[cfg(feature = "toml")]
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Self, ShabdaError> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path).map_err(|e| {
+            ShabdaError::InvalidOverrides(format!("cannot read '{}': {e}", path.display()))
+        })?;
+        parse_table(&raw)
+            .map_err(|e| ShabdaError::InvalidOverrides(format!("'{}': {e}", path.display())))
+    }
+
+    /// एउटा शब्दको उत्पत्ति तोक्ने; पहिलेको मान भए बदलिन्छ।
+    pub fn insert(&mut self, word: impl Into<String>, origin: Origin) {
+        self.entries.insert(word.into(), origin);
+    }
+
+    /// शब्दका लागि तोकिएको उत्पत्ति, भएमा।
+    pub fn get(&self, word: &str) -> Option<Origin> {
+        self.entries.get(word).copied()
+    }
+
+    /// override संख्या।
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// कुनै override छैन भने `true`।
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// यो set लाई process-wide override बनाउने, जसले [`classify`] र
+    /// त्यसमा आधारित सबै नियमलाई असर गर्छ। पहिले install गरिएको set बदलिन्छ।
+    ///
+    /// Origin निर्णय cache गर्ने caller ले (जस्तै: parikshak को `cache`
+    /// feature) जाँच सुरु गर्नुअघि नै install गर्नुपर्छ।
+    ///
+    /// [`classify`]: crate::classify
+    pub fn install(self) {
+        *INSTALLED.write().unwrap_or_else(|e| e.into_inner()) = Some(self);
+        ANY_INSTALLED.store(true, Ordering::Release);
+    }
+
+    /// Install गरिएको override हटाउने।
+    pub fn uninstall() {
+        ANY_INSTALLED.store(false, Ordering::Release);
+        *INSTALLED.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+#[cfg(feature = "toml")]
+impl FromStr for OriginOverrides {
+    type Err = ShabdaError;
+
+    /// `{ शब्द = "Origin" }` ढाँचाको TOML पार्स गर्ने। अज्ञात origin मान वा
+    /// string नभएको मानमा त्रुटि फर्काउँछ।
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_table(s).map_err(ShabdaError::InvalidOverrides)
+    }
+}
+
+#[cfg(feature = "toml")]
+fn parse_table(raw: &str) -> Result<OriginOverrides, String> {
+    let table: toml::Table = raw
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+    let mut overrides = OriginOverrides::new();
+    for (word, value) in table {
+        let origin = value.as_str().and_then(parse_origin).ok_or_else(|| {
+            format!(
+                "unknown origin {value} for '{word}' \
+                 (expected Tatsam, Tadbhav, Deshaj or Aagantuk)"
+            )
+        })?;
+        overrides.insert(word, origin);
+    }
+    Ok(overrides)
+}
+
+/// Install गरिएको override मा `word` को उत्पत्ति, भएमा।
+pub(crate) fn installed_origin(word: &str) -> Option<Origin> {
+    if !ANY_INSTALLED.load(Ordering::Acquire) {
+        return None;
+    }
+    INSTALLED
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()?
+        .get(word)
+}
+
+#[cfg(feature = "toml")]
+fn parse_origin(value: &str) -> Option<Origin> {
+    let value = value.trim();
+    [
+        Origin::Tatsam,
+        Origin::Tadbhav,
+        Origin::Deshaj,
+        Origin::Aagantuk,
+    ]
+    .into_iter()
+    .find(|origin| {
+        value.eq_ignore_ascii_case(origin.transliterated_label()) || value == origin.nepali_label()
+    })
+}
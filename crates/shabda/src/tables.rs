@@ -104,6 +104,10 @@ static ORIGIN_TABLE: &[(&str, Origin)] = &[
     ("हामी", Origin::Tadbhav),
 ];
 
+/// Nepali negative prefixes (गैरकानुनी, बेकार, नालायक).
+/// Sorted by descending byte length.
+pub static NEGATIVE_PREFIXES: &[&str] = &["गैर", "बे", "ना"];
+
 /// Prefix forms: (canonical prefix, sandhi-ed form as it appears in words, root_prefix to restore).
 /// When we strip the sandhi form from a word, we prepend root_prefix to get the original root.
 ///
@@ -124,6 +128,7 @@ pub static PREFIX_FORMS: &[(&str, &str, &str)] = &[
     // 9 bytes
     ("अभि", "अभि", ""),
     ("अधि", "अधि", ""),
+    ("अपि", "अपि", ""),
     ("दुर्", "दुः", ""),
     ("सम्", "सङ्", ""), // सम् before gutturals → सङ्
     ("उत्", "उल्", ""), // उत् + ल → उल्ल
@@ -143,6 +148,8 @@ pub static PREFIX_FORMS: &[(&str, &str, &str)] = &[
     ("अव", "अव", ""), // medium risk: see above
     ("उप", "उप", ""),
     ("वि", "वि", ""),
+    ("नि", "नि", ""),
+    ("सु", "सु", ""),
     // 3 bytes
     ("आ", "आ", ""), // short prefix: ≤1 Devanagari char, requires 4+ char root
     ("अ", "अ", ""), // short prefix: ≤1 Devanagari char, requires 4+ char root
@@ -1,5 +1,6 @@
 use varnavinyas_shabda::{
-    Origin, OriginSource, classify, classify_with_provenance, decompose, tables,
+    Morpheme, Origin, OriginSource, classify, classify_with_provenance, decompose, known_prefixes,
+    strip_prefixes, tables,
 };
 
 // S1: Classifies विज्ञान as Tatsam
@@ -127,6 +128,107 @@ fn decompose_ullikhit_no_over_decompose() {
     assert!(m.suffixes.is_empty());
 }
 
+#[test]
+fn strip_prefixes_pra_shasan() {
+    assert_eq!(
+        strip_prefixes("प्रशासन"),
+        (vec!["प्र".to_string()], "शासन".to_string())
+    );
+}
+
+#[test]
+fn strip_prefixes_upakaar() {
+    assert_eq!(
+        strip_prefixes("उपकार"),
+        (vec!["उप".to_string()], "कार".to_string())
+    );
+}
+
+#[test]
+fn strip_prefixes_leaves_plain_words() {
+    for word in ["आगो", "नाम", "बेला", "किताब"] {
+        assert_eq!(strip_prefixes(word), (Vec::new(), word.to_string()));
+    }
+}
+
+#[test]
+fn known_prefixes_lists_upasargas_and_negatives() {
+    let prefixes = known_prefixes();
+    for p in ["प्र", "परा", "अप", "सम्", "अनु", "अव", "नि", "गैर", "बे"]
+    {
+        assert!(prefixes.contains(&p), "missing prefix {p}");
+    }
+}
+
+#[test]
+fn known_prefixes_covers_all_upasargas_once() {
+    let prefixes = known_prefixes();
+    let upasargas = [
+        "प्र",
+        "परा",
+        "अप",
+        "सम्",
+        "अनु",
+        "अव",
+        "निस्",
+        "निर्",
+        "दुस्",
+        "दुर्",
+        "वि",
+        "आ",
+        "नि",
+        "अधि",
+        "अपि",
+        "अति",
+        "सु",
+        "उत्",
+        "अभि",
+        "प्रति",
+        "परि",
+        "उप",
+    ];
+    for p in upasargas {
+        assert!(prefixes.contains(&p), "missing upasarga {p}");
+    }
+    for (i, p) in prefixes.iter().enumerate() {
+        assert!(!prefixes[..i].contains(p), "duplicate prefix {p}");
+    }
+}
+
+// decompose then surface must give back the original word
+#[test]
+fn decompose_surface_roundtrip() {
+    let words = [
+        "असक्षम",
+        "अधिकार",
+        "प्रशासन",
+        "उपकार",
+        "उल्लेख",
+        "उल्लिखित",
+        "अनुभव",
+        "अभियान",
+        "प्रतिवेदन",
+        "दुर्घटना",
+        "सुन्दरता",
+        "शासन",
+    ];
+    for word in words {
+        let m = decompose(word);
+        assert_eq!(m.surface(), word, "roundtrip failed for {word}: {m:?}");
+    }
+}
+
+#[test]
+fn surface_applies_sandhi_at_prefix_boundary() {
+    let m = Morpheme {
+        root: "अवलोकन".to_string(),
+        prefixes: vec!["पुनः".to_string()],
+        suffixes: Vec::new(),
+        origin: Origin::Tatsam,
+    };
+    assert_eq!(m.surface(), "पुनरवलोकन");
+}
+
 /// PREFIX_FORMS must be sorted by descending sandhi_form byte length.
 #[test]
 fn prefix_forms_sorted_descending_by_byte_length() {
@@ -0,0 +1,53 @@
+//! Installed overrides are process-wide, so these tests live in their own
+//! binary rather than alongside the classification tests, and only one of
+//! them installs.
+
+#[cfg(feature = "toml")]
+use varnavinyas_shabda::ShabdaError;
+use varnavinyas_shabda::{Origin, OriginOverrides, OriginSource, classify_with_provenance};
+
+#[test]
+fn installed_overrides_take_precedence() {
+    let mut overrides = OriginOverrides::new();
+    overrides.insert("पुस्तक", Origin::Aagantuk);
+
+    assert_eq!(classify_with_provenance("पुस्तक").origin, Origin::Tatsam);
+    overrides.install();
+    let decision = classify_with_provenance("पुस्तक");
+    assert_eq!(decision.origin, Origin::Aagantuk);
+    assert_eq!(decision.source, OriginSource::Override);
+    OriginOverrides::uninstall();
+    assert_eq!(classify_with_provenance("पुस्तक").origin, Origin::Tatsam);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_overrides_parse_as_specified() {
+    let path = std::env::temp_dir().join(format!("origin-overrides-{}.toml", std::process::id()));
+    std::fs::write(
+        &path,
+        "\"विद्या\" = \"Aagantuk\"\n\"मिठो\" = \"tatsam\"\n\"टोपी\" = \"तद्भव\"\n",
+    )
+    .unwrap();
+    let overrides = OriginOverrides::from_toml(&path).expect("valid overrides file");
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(overrides.len(), 3);
+    assert_eq!(overrides.get("विद्या"), Some(Origin::Aagantuk));
+    assert_eq!(overrides.get("मिठो"), Some(Origin::Tatsam));
+    assert_eq!(overrides.get("टोपी"), Some(Origin::Tadbhav));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn invalid_overrides_are_rejected() {
+    let err = "\"विद्या\" = \"Sanskrit\""
+        .parse::<OriginOverrides>()
+        .unwrap_err();
+    assert!(matches!(err, ShabdaError::InvalidOverrides(_)));
+    assert!(err.to_string().contains("विद्या"), "{err}");
+
+    assert!("\"विद्या\" = 3".parse::<OriginOverrides>().is_err());
+    assert!("not toml".parse::<OriginOverrides>().is_err());
+    assert!(OriginOverrides::from_toml("/nonexistent/overrides.toml").is_err());
+}
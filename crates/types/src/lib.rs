@@ -1,3 +1,7 @@
+mod rule;
+
+pub use rule::Rule;
+
 /// शब्दउत्पत्ति वर्गीकरण।
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Origin {
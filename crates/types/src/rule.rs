@@ -0,0 +1,152 @@
+/// A rule from an authoritative source.
+/// Modeled after Vidyut's Rule enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// Nepal Academy Orthography Standard section reference.
+    /// e.g., "3(क)" for hrasva/dirgha vowel rules.
+    VarnaVinyasNiyam(&'static str),
+
+    /// Nepal Academy Grammar reference.
+    Vyakaran(&'static str),
+
+    /// Specific word table entry from Section 4.
+    ShuddhaAshuddha(&'static str),
+
+    /// Punctuation rule from Section 5.
+    ChihnaNiyam(&'static str),
+}
+
+impl Rule {
+    /// Get the rule code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Rule::VarnaVinyasNiyam(s) => s,
+            Rule::Vyakaran(s) => s,
+            Rule::ShuddhaAshuddha(s) => s,
+            Rule::ChihnaNiyam(s) => s,
+        }
+    }
+
+    /// Get the source name.
+    pub fn source_name(&self) -> &'static str {
+        match self {
+            Rule::VarnaVinyasNiyam(_) => "वर्णविन्यास नियम",
+            Rule::Vyakaran(_) => "व्याकरण",
+            Rule::ShuddhaAshuddha(_) => "शुद्ध-अशुद्ध तालिका",
+            Rule::ChihnaNiyam(_) => "चिह्न नियम",
+        }
+    }
+
+    /// Human-readable description of the rule.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Rule::VarnaVinyasNiyam(code) => match *code {
+                c if c.starts_with("3(क)") => "ह्रस्व/दीर्घ स्वर नियम",
+                c if c.starts_with("3(ख)") => "चन्द्रबिन्दु/शिरबिन्दु नियम",
+                c if c.starts_with("3(ग)") => "श/ष/स प्रयोग नियम",
+                c if c.starts_with("3(घ)") => "ऋ/कृ प्रयोग नियम",
+                c if c.starts_with("3(ङ)") => "हलन्त नियम",
+                c if c.starts_with("3(इ)") => "य/ए भेद नियम",
+                c if c.starts_with("3(उ)") => "क्ष/छ भेद नियम",
+                "3(ई)" => "शुद्ध-अशुद्ध शब्द सूची",
+                _ => "वर्णविन्यास नियम",
+            },
+            Rule::Vyakaran(_) => "व्याकरण नियम",
+            Rule::ShuddhaAshuddha(_) => "शुद्ध-अशुद्ध शब्द सूची",
+            Rule::ChihnaNiyam(_) => "विराम चिह्न नियम",
+        }
+    }
+
+    /// Full Academy section title for documentation and tooltips,
+    /// e.g. "नियम ३(ख): शिरबिन्दु र चन्द्रबिन्दु".
+    ///
+    /// Codes citing several sections resolve to the first one. Returns `None`
+    /// for grammar heuristics and codes without a section reference.
+    pub fn academy_section(&self) -> Option<&'static str> {
+        match self {
+            Rule::VarnaVinyasNiyam(code) => match *code {
+                c if c.starts_with("3(क)") => Some("नियम ३(क): ह्रस्व र दीर्घ स्वर"),
+                c if c.starts_with("3(ख)") => Some("नियम ३(ख): शिरबिन्दु र चन्द्रबिन्दु"),
+                c if c.starts_with("3(ग)") => Some("नियम ३(ग): श, ष र स"),
+                c if c.starts_with("3(घ)") => Some("नियम ३(घ): पदयोग र पदवियोग"),
+                c if c.starts_with("3(ङ)") => Some("नियम ३(ङ): हलन्त"),
+                c if c.starts_with("3(छ)-क्ष") || c.starts_with("3(छ)-ज्ञ") => {
+                    Some("नियम ३(छ): क्ष र ज्ञ")
+                }
+                c if c.starts_with("3(छ)") || c.starts_with("3(इ)") => Some("नियम ३(इ): य र ए"),
+                c if c.starts_with("3(ई)") => Some("नियम ३(ई): शुद्ध-अशुद्ध शब्द सूची"),
+                c if c.starts_with("3(उ)") => Some("नियम ३(उ): क्ष र छ"),
+                _ => None,
+            },
+            Rule::ShuddhaAshuddha(code) if code.starts_with("Section 4") => {
+                Some("खण्ड ४: शुद्ध-अशुद्ध शब्द तालिका")
+            }
+            Rule::ChihnaNiyam(code) if code.starts_with("Section 5") => Some("खण्ड ५: विराम चिह्न"),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rule;
+
+    #[test]
+    fn academy_section_titles() {
+        assert_eq!(
+            Rule::VarnaVinyasNiyam("3(ख)").academy_section(),
+            Some("नियम ३(ख): शिरबिन्दु र चन्द्रबिन्दु")
+        );
+        assert_eq!(
+            Rule::VarnaVinyasNiyam("3(क)-suffix-हरू").academy_section(),
+            Some("नियम ३(क): ह्रस्व र दीर्घ स्वर")
+        );
+        assert_eq!(
+            Rule::VarnaVinyasNiyam("3(क), 3(ङ)").academy_section(),
+            Some("नियम ३(क): ह्रस्व र दीर्घ स्वर")
+        );
+        assert_eq!(
+            Rule::ShuddhaAshuddha("Section 4").academy_section(),
+            Some("खण्ड ४: शुद्ध-अशुद्ध शब्द तालिका")
+        );
+        assert_eq!(
+            Rule::ChihnaNiyam("Section 5").academy_section(),
+            Some("खण्ड ५: विराम चिह्न")
+        );
+    }
+
+    #[test]
+    fn academy_section_distinguishes_shared_prefixes() {
+        assert_eq!(
+            Rule::VarnaVinyasNiyam("3(घ)").academy_section(),
+            Some("नियम ३(घ): पदयोग र पदवियोग")
+        );
+        assert_eq!(
+            Rule::VarnaVinyasNiyam("3(छ)-क्ष").academy_section(),
+            Some("नियम ३(छ): क्ष र ज्ञ")
+        );
+        assert_eq!(
+            Rule::VarnaVinyasNiyam("3(छ)-ज्ञ").academy_section(),
+            Some("नियम ३(छ): क्ष र ज्ञ")
+        );
+        assert_eq!(
+            Rule::VarnaVinyasNiyam("3(छ)").academy_section(),
+            Some("नियम ३(इ): य र ए")
+        );
+    }
+
+    #[test]
+    fn academy_section_absent_for_heuristics() {
+        assert_eq!(
+            Rule::Vyakaran("subject-verb-person").academy_section(),
+            None
+        );
+        assert_eq!(Rule::ShuddhaAshuddha("unknown").academy_section(), None);
+    }
+}
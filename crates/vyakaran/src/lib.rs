@@ -82,6 +82,50 @@ pub struct Features {
     pub person: Option<Person>,
 }
 
+/// Nepali gloss of the known features, e.g. "पुलिङ्ग, बहुवचन, कर्म कारक".
+///
+/// Unset features are skipped; an empty `Features` renders as "".
+impl std::fmt::Display for Features {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gender = self.gender.map(|g| match g {
+            Gender::Masculine => "पुलिङ्ग",
+            Gender::Feminine => "स्त्रीलिङ्ग",
+            Gender::Neuter => "नपुंसकलिङ्ग",
+        });
+        let number = self.number.map(|n| match n {
+            Number::Singular => "एकवचन",
+            Number::Plural => "बहुवचन",
+        });
+        let case = self.case.map(|c| match c {
+            Case::Nominative => "कर्ता कारक",
+            Case::Accusative => "कर्म कारक",
+            Case::Instrumental => "करण कारक",
+            Case::Dative => "सम्प्रदान कारक",
+            Case::Ablative => "अपादान कारक",
+            Case::Genitive => "सम्बन्ध कारक",
+            Case::Locative => "अधिकरण कारक",
+            Case::Vocative => "सम्बोधन कारक",
+        });
+        let tense = self.tense.and_then(|t| match t {
+            Tense::Present => Some("वर्तमान काल"),
+            Tense::Past => Some("भूत काल"),
+            Tense::Future => Some("भविष्यत् काल"),
+            Tense::Unknown => None,
+        });
+        let person = self.person.map(|p| match p {
+            Person::First => "प्रथम पुरुष",
+            Person::Second => "द्वितीय पुरुष",
+            Person::Third => "तृतीय पुरुष",
+        });
+
+        let parts: Vec<&str> = [gender, number, case, tense, person]
+            .into_iter()
+            .flatten()
+            .collect();
+        f.write_str(&parts.join(", "))
+    }
+}
+
 /// Morphological analysis result for a single word.
 #[derive(Debug, Clone)]
 pub struct MorphAnalysis {
@@ -164,7 +208,6 @@ pub fn transform_negative(word: &str) -> Option<String> {
     None
 }
 
-#[cfg(feature = "vyakaran-mvp")]
 const CASE_SUFFIXES: &[(&str, Case)] = &[
     ("देखि", Case::Ablative),
     ("बाट", Case::Ablative),
@@ -178,7 +221,6 @@ const CASE_SUFFIXES: &[(&str, Case)] = &[
     ("मा", Case::Locative),
 ];
 
-#[cfg(feature = "vyakaran-mvp")]
 const PLURAL_SUFFIXES: &[&str] = &["हरू", "हरु"];
 
 #[cfg(feature = "vyakaran-mvp")]
@@ -187,9 +229,9 @@ const NONFINITE_VERB_ENDINGS: &[&str] = &["उन्जेल", "दै", "द
 #[cfg(feature = "vyakaran-mvp")]
 const DERIVATIONAL_SUFFIXES: &[&str] = &["उन्जेल", "आत", "अट"];
 
-#[cfg(feature = "vyakaran-mvp")]
 const PRESENT_PERSON_ENDINGS: &[(&str, Person)] = &[
     ("छन्", Person::Third),
+    ("छौँ", Person::First),
     ("छौं", Person::First),
     ("छु", Person::First),
     ("छौ", Person::Second),
@@ -214,22 +256,256 @@ const PRESENT_NEGATIVE_ENDINGS: &[(&str, Person)] = &[
     ("दैन", Person::Third),
 ];
 
-#[cfg(feature = "vyakaran-mvp")]
 const FUTURE_PERSON_ENDINGS: &[(&str, Person)] = &[
     ("नेछन्", Person::Third),
+    ("नेछौँ", Person::First),
     ("नेछौं", Person::First),
     ("नेछु", Person::First),
     ("नेछौ", Person::Second),
     ("नेछ", Person::Third),
 ];
 
-#[cfg(feature = "vyakaran-mvp")]
 const PAST_POSITIVE_ENDINGS: &[(&str, Person)] = &[
+    ("यौँ", Person::First),
     ("यौ", Person::Second),
     ("एँ", Person::First),
+    ("ेँ", Person::First),
     ("यो", Person::Third),
 ];
 
+/// Personal pronoun bases (including oblique stems such as मै-, उस-).
+const PERSONAL_PRONOUNS: &[(&str, Person, Number)] = &[
+    ("म", Person::First, Number::Singular),
+    ("मै", Person::First, Number::Singular),
+    ("हामी", Person::First, Number::Plural),
+    ("तँ", Person::Second, Number::Singular),
+    ("तैं", Person::Second, Number::Singular),
+    ("तिमी", Person::Second, Number::Singular),
+    ("तपाईं", Person::Second, Number::Singular),
+    ("तपाईँ", Person::Second, Number::Singular),
+    ("हजुर", Person::Second, Number::Singular),
+    ("ऊ", Person::Third, Number::Singular),
+    ("उस", Person::Third, Number::Singular),
+    ("उनी", Person::Third, Number::Singular),
+    ("उहाँ", Person::Third, Number::Singular),
+    ("यो", Person::Third, Number::Singular),
+    ("त्यो", Person::Third, Number::Singular),
+];
+
+/// Nominal inflection markers peeled off a word by [`split_inflection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inflection<'a> {
+    /// What remains once the markers are removed.
+    pub stem: &'a str,
+    /// Plural marker (हरू/हरु), if present.
+    pub plural: Option<&'static str>,
+    /// Case marker and the case it signals, if present.
+    pub case: Option<(&'static str, Case)>,
+}
+
+/// Split the case marker and then the plural marker off a nominal:
+/// `केटाहरूलाई` → stem `केटा`, plural `हरू`, case `लाई` (Dative).
+///
+/// A case marker is only detached from a stem of two or more letters, or from
+/// a pronoun, so आमा keeps its final मा.
+pub fn split_inflection(word: &str) -> Inflection<'_> {
+    let mut inflection = Inflection {
+        stem: word,
+        plural: None,
+        case: None,
+    };
+
+    for &(sfx, case) in CASE_SUFFIXES {
+        if let Some(rest) = inflection.stem.strip_suffix(sfx) {
+            if letter_count(rest) >= 2 || pronoun_base(rest).is_some() {
+                inflection.stem = rest;
+                inflection.case = Some((sfx, case));
+            }
+            break;
+        }
+    }
+
+    for &pl in PLURAL_SUFFIXES {
+        if let Some(rest) = inflection.stem.strip_suffix(pl) {
+            if !rest.is_empty() {
+                inflection.stem = rest;
+                inflection.plural = Some(pl);
+            }
+            break;
+        }
+    }
+
+    inflection
+}
+
+/// Read case, number, person and (for finite verbs) tense from inflectional
+/// markers alone.
+///
+/// No lexicon is consulted, so this is cheap enough for tokenization. To keep
+/// word endings such as आमा or सीमा from reading as locative, a case marker is
+/// only detached from a stem of two or more letters, or from a pronoun.
+pub fn surface_features(word: &str) -> Features {
+    let mut features = Features::default();
+    let inflection = split_inflection(word);
+    let stem = inflection.stem;
+    features.case = inflection.case.map(|(_, case)| case);
+    if inflection.plural.is_some() {
+        features.number = Some(Number::Plural);
+    }
+
+    if let Some((person, number)) = pronoun_base(stem) {
+        features.person = Some(person);
+        features.number.get_or_insert(number);
+        return features;
+    }
+
+    if stem != word {
+        return features;
+    }
+
+    // Finite verb endings: future before present (नेछ ⊃ छ), then past.
+    let finite = [
+        (FUTURE_PERSON_ENDINGS, Tense::Future),
+        (PRESENT_PERSON_ENDINGS, Tense::Present),
+        (PAST_POSITIVE_ENDINGS, Tense::Past),
+    ]
+    .into_iter()
+    .find_map(|(table, tense)| {
+        table
+            .iter()
+            .find(|(ending, _)| {
+                word.strip_suffix(ending).is_some_and(|stem| {
+                    !matches!(tense, Tense::Present) || is_present_verb_stem(stem)
+                })
+            })
+            .map(|&(_, person)| (person, tense))
+    });
+    if let Some((person, tense)) = finite {
+        features.person = Some(person);
+        features.tense = Some(tense);
+    }
+
+    features
+}
+
+/// Re-inflect a present or future finite verb for another person and number.
+///
+/// `जान्छ` → `जान्छु` (First, Singular); `जानेछ` → `जानेछौँ` (First, Plural).
+/// Returns `None` when `verb` has no recognized finite ending or already
+/// carries the requested one. Past forms are left alone: their stems change
+/// (गर्यो → गरेँ) in ways a suffix swap cannot model.
+pub fn reinflect_person(verb: &str, person: Person, number: Number) -> Option<String> {
+    let target = match (person, number) {
+        (Person::First, Number::Singular) => "छु",
+        (Person::First, Number::Plural) => "छौँ",
+        (Person::Second, _) => "छौ",
+        (Person::Third, Number::Singular) => "छ",
+        (Person::Third, Number::Plural) => "छन्",
+    };
+
+    for (table, prefix) in [(FUTURE_PERSON_ENDINGS, "ने"), (PRESENT_PERSON_ENDINGS, "")] {
+        if let Some(&(ending, _)) = table.iter().find(|(e, _)| verb.ends_with(e)) {
+            let stem = &verb[..verb.len() - ending.len()];
+            if stem.is_empty() || (prefix.is_empty() && !is_present_verb_stem(stem)) {
+                return None;
+            }
+            let inflected = format!("{stem}{prefix}{target}");
+            return (inflected != verb).then_some(inflected);
+        }
+    }
+    None
+}
+
+/// Whether `stem`, the part before a present-tense ending, can be a verb
+/// stem: empty (the copula छ), consonant-final (गर्|छ, जान्|छ), nasalized
+/// (आउँ|छ) or the mirative -ए (गरे|छ).
+///
+/// A च् after a short vowel is the tatsam cluster च्छ of adjectives and nouns
+/// (तुच्छ, स्वच्छ, पुच्छ), while common च्-stem verbs have a long vowel
+/// (सोच्|छ, नाच्|छ, बाँच्|छ).
+fn is_present_verb_stem(stem: &str) -> bool {
+    let mut chars = stem.chars().rev();
+    match chars.next() {
+        None | Some('ँ' | 'ं' | 'े' | 'ए') => true,
+        Some('्') => {
+            chars.next() != Some('च')
+                || chars
+                    .next()
+                    .is_some_and(|c| matches!(c, 'ा' | 'ी' | 'ू' | 'े' | 'ै' | 'ो' | 'ौ' | 'ँ' | 'ं'))
+        }
+        _ => false,
+    }
+}
+
+/// Plural form of a nominal lemma: `केटा` → `केटाहरू`.
+///
+/// Masculine -ओ nouns take the oblique -आ before the plural marker
+/// (`केटो` → `केटाहरू`). Words already carrying -हरू/-हरु are returned with
+/// the standard हरू spelling.
+pub fn pluralize(lemma: &str) -> String {
+    if let Some(stem) = singularize(lemma) {
+        return format!("{stem}हरू");
+    }
+    format!("{}हरू", oblique_stem(lemma))
+}
+
+/// Strip the plural marker: `केटाहरू` → `केटा`. Returns `None` for words
+/// without -हरू/-हरु.
+pub fn singularize(word: &str) -> Option<String> {
+    PLURAL_SUFFIXES
+        .iter()
+        .find_map(|pl| word.strip_suffix(pl))
+        .filter(|stem| !stem.is_empty())
+        .map(str::to_string)
+}
+
+/// Inflect a nominal lemma for case and number: the inverse of nominal analysis.
+///
+/// Masculine -ओ nouns switch to the oblique -आ before any marker
+/// (`केटो` → `केटालाई`); genitive uses the default `को` form.
+pub fn decline(lemma: &str, case: Case, number: Number) -> String {
+    let marker = case_marker(case);
+    let stem = match number {
+        Number::Plural => return format!("{}{marker}", pluralize(lemma)),
+        Number::Singular if marker.is_empty() => return lemma.to_string(),
+        Number::Singular => oblique_stem(lemma),
+    };
+    format!("{stem}{marker}")
+}
+
+fn case_marker(case: Case) -> &'static str {
+    match case {
+        Case::Nominative | Case::Vocative => "",
+        Case::Accusative | Case::Dative => "लाई",
+        Case::Instrumental => "ले",
+        Case::Ablative => "बाट",
+        Case::Genitive => "को",
+        Case::Locative => "मा",
+    }
+}
+
+/// -ओ ending masculine stems take -आ in oblique position (केटो → केटा).
+fn oblique_stem(lemma: &str) -> String {
+    match lemma.strip_suffix('ो') {
+        Some(base) if !base.is_empty() => format!("{base}ा"),
+        _ => lemma.to_string(),
+    }
+}
+
+fn pronoun_base(stem: &str) -> Option<(Person, Number)> {
+    PERSONAL_PRONOUNS
+        .iter()
+        .find(|(p, _, _)| *p == stem)
+        .map(|&(_, person, number)| (person, number))
+}
+
+/// Letters (vowels and consonants), ignoring matras, halanta and nasal signs.
+fn letter_count(s: &str) -> usize {
+    s.chars()
+        .filter(|c| !matches!(c, '\u{0900}'..='\u{0903}' | '\u{093A}'..='\u{094F}'))
+        .count()
+}
+
 #[cfg(feature = "vyakaran-mvp")]
 fn analyze_nominal(word: &str) -> Option<MorphAnalysis> {
     let mut stem = word;
@@ -537,6 +813,102 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn surface_features_read_markers() {
+        let f = surface_features("केटाहरूलाई");
+        assert_eq!(f.case, Some(Case::Dative));
+        assert_eq!(f.number, Some(Number::Plural));
+        assert_eq!(f.person, None);
+
+        let f = surface_features("मैले");
+        assert_eq!(f.case, Some(Case::Instrumental));
+        assert_eq!(f.person, Some(Person::First));
+
+        assert_eq!(surface_features("जान्छु").person, Some(Person::First));
+        assert_eq!(surface_features("जानेछन्").person, Some(Person::Third));
+        assert_eq!(surface_features("गरेँ").tense, Some(Tense::Past));
+        assert_eq!(surface_features("जान्छौँ").person, Some(Person::First));
+        assert_eq!(surface_features("नेपाल").person, None);
+        assert_eq!(surface_features("आउँछ").person, Some(Person::Third));
+        assert_eq!(surface_features("सोच्छ").person, Some(Person::Third));
+        assert_eq!(surface_features("तुच्छ").person, None);
+        assert_eq!(surface_features("स्वच्छ").person, None);
+        assert_eq!(surface_features("आमा").case, None);
+        assert_eq!(surface_features("घरमा").case, Some(Case::Locative));
+    }
+
+    #[test]
+    fn plural_generation_roundtrips() {
+        assert_eq!(pluralize("केटा"), "केटाहरू");
+        assert_eq!(pluralize("केटो"), "केटाहरू");
+        assert_eq!(pluralize("घर"), "घरहरू");
+        assert_eq!(pluralize("घरहरु"), "घरहरू");
+        assert_eq!(singularize("केटाहरू").as_deref(), Some("केटा"));
+        assert_eq!(singularize("किताबहरु").as_deref(), Some("किताब"));
+        assert_eq!(singularize("केटा"), None);
+        assert_eq!(singularize("हरू"), None);
+    }
+
+    #[test]
+    fn features_display_full_gloss() {
+        let features = Features {
+            gender: Some(Gender::Masculine),
+            number: Some(Number::Plural),
+            case: Some(Case::Accusative),
+            tense: Some(Tense::Present),
+            person: Some(Person::Third),
+        };
+        assert_eq!(
+            features.to_string(),
+            "पुलिङ्ग, बहुवचन, कर्म कारक, वर्तमान काल, तृतीय पुरुष"
+        );
+    }
+
+    #[test]
+    fn features_display_partial_gloss() {
+        let features = Features {
+            number: Some(Number::Singular),
+            case: Some(Case::Dative),
+            tense: Some(Tense::Unknown),
+            ..Default::default()
+        };
+        assert_eq!(features.to_string(), "एकवचन, सम्प्रदान कारक");
+        assert_eq!(Features::default().to_string(), "");
+    }
+
+    #[test]
+    fn decline_attaches_markers_to_oblique_stem() {
+        assert_eq!(decline("केटो", Case::Dative, Number::Singular), "केटालाई");
+        assert_eq!(decline("केटो", Case::Dative, Number::Plural), "केटाहरूलाई");
+        assert_eq!(decline("केटो", Case::Nominative, Number::Singular), "केटो");
+        assert_eq!(decline("घर", Case::Locative, Number::Singular), "घरमा");
+        assert_eq!(decline("राम", Case::Instrumental, Number::Singular), "रामले");
+    }
+
+    #[test]
+    fn reinflect_person_swaps_finite_ending() {
+        assert_eq!(
+            reinflect_person("जान्छ", Person::First, Number::Singular).as_deref(),
+            Some("जान्छु")
+        );
+        assert_eq!(
+            reinflect_person("गर्नेछ", Person::First, Number::Plural).as_deref(),
+            Some("गर्नेछौँ")
+        );
+        assert_eq!(
+            reinflect_person("जान्छु", Person::First, Number::Singular),
+            None
+        );
+        assert_eq!(
+            reinflect_person("गयो", Person::First, Number::Singular),
+            None
+        );
+        assert_eq!(
+            reinflect_person("तुच्छ", Person::First, Number::Singular),
+            None
+        );
+    }
+
     #[cfg(feature = "vyakaran-mvp")]
     #[test]
     fn nominal_case_and_plural_detected() {
@@ -32,6 +32,7 @@ export const CATEGORY_COLORS = {
   KshaChhya: 'var(--cat-ksha-chhya)',
   Sandhi: 'var(--cat-sandhi)',
   Punctuation: 'var(--cat-punctuation)',
+  Loanword: 'var(--cat-loanword)',
 };
 
 /**
@@ -58,4 +59,5 @@ export const CATEGORY_LABELS = {
   KshaChhya: 'क्ष/छ',
   Sandhi: 'सन्धि',
   Punctuation: 'चिह्न',
+  Loanword: 'आगन्तुक',
 };
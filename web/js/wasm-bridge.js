@@ -4,8 +4,8 @@
  */
 import init, {
   check_text,
-  check_text_with_options,
-  check_text_value,
+  check_text_with_options2,
+  check_text_value2,
   check_word,
   check_word_value,
   transliterate as wasmTransliterate,
@@ -57,14 +57,14 @@ export function byteOffsetToCharIndex(text, byteOffset) {
  * Returns an array of diagnostics with char-index spans.
  */
 export function checkText(text, options = {}) {
-  const { grammar = false } = options;
+  const { grammar = false, promoteVariantsToErrors = false } = options;
 
   let raw;
   try {
-    raw = check_text_value(text, grammar);
+    raw = check_text_value2(text, grammar, promoteVariantsToErrors);
   } catch (_err) {
-    raw = grammar
-      ? JSON.parse(check_text_with_options(text, grammar))
+    raw = grammar || promoteVariantsToErrors
+      ? JSON.parse(check_text_with_options2(text, grammar, promoteVariantsToErrors))
       : JSON.parse(check_text(text));
   }
 
@@ -99,7 +99,7 @@ export function checkWord(word) {
  * Transliterate text between scripts.
  * @param {string} input
  * @param {string} from - "Devanagari" or "Iast"
- * @param {string} to - "Devanagari" or "Iast"
+ * @param {string} to - "Devanagari", "Iast" or "NepaliPhonetic" (from Devanagari only)
  * @returns {string}
  */
 export function transliterate(input, from, to) {
@@ -120,7 +120,7 @@ export function deriveWord(word) {
 
 /**
  * Analyze a word: get origin, correction, and explanatory rule notes.
- * Returns { word, origin, is_correct, correction, rule_notes: [{rule, explanation}] }
+ * Returns { word, origin, is_correct, correction, rule_notes: [{rule, explanation}], samasa? }
  */
 export function analyzeWord(word) {
   try {
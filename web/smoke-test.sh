@@ -42,7 +42,7 @@ fi
 # --- 2. WASM JS exports the expected functions ---
 echo "[2] WASM JS exports"
 CORE_EXPORTS="check_text check_word transliterate derive"
-TYPED_EXPORTS="check_text_value check_word_value derive_value analyze_word_value decompose_word_value sandhi_apply_value sandhi_split_value"
+TYPED_EXPORTS="check_text_value check_text_value2 check_word_value derive_value analyze_word_value decompose_word_value sandhi_apply_value sandhi_split_value"
 
 missing_exports() {
   local exports="$1"